@@ -0,0 +1,49 @@
+//! JSON vs 바이너리(bincode) 직렬화 크기·속도 비교
+//!
+//! 실행: cargo bench --bench binary_serialization
+
+use kec_calculator::{calculate_core, deserialize_result_binary, serialize_result_binary, CableData, CalculationResult};
+use std::hint::black_box;
+use std::time::Instant;
+
+const ITERATIONS: u32 = 100_000;
+
+fn measure(label: &str, mut f: impl FnMut()) {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    let elapsed = start.elapsed();
+    println!("{:<12} {:>10.1} ns/회", label, elapsed.as_nanos() as f64 / ITERATIONS as f64);
+}
+
+fn main() {
+    let data = CableData {
+        cable_type: "CV".to_string(),
+        cores: "3C".to_string(),
+        size: "95".to_string(),
+        quantity: 3,
+        system: "3Φ".to_string(),
+        ground_wire: "HFIX".to_string(),
+        install_method: "B2".to_string(),
+        ..Default::default()
+    };
+    let result = calculate_core(&data).expect("계산 실패");
+
+    let json = serde_json::to_vec(&result).expect("JSON 직렬화 실패");
+    let binary = serialize_result_binary(&result);
+    println!("크기: JSON {} bytes, 바이너리 {} bytes", json.len(), binary.len());
+
+    measure("JSON 인코딩", || {
+        black_box(serde_json::to_vec(black_box(&result)).unwrap());
+    });
+    measure("바이너리 인코딩", || {
+        black_box(serialize_result_binary(black_box(&result)));
+    });
+    measure("JSON 디코딩", || {
+        black_box(serde_json::from_slice::<CalculationResult>(black_box(&json)).unwrap());
+    });
+    measure("바이너리 디코딩", || {
+        black_box(deserialize_result_binary(black_box(&binary), "ko").unwrap());
+    });
+}
@@ -0,0 +1,51 @@
+//! 배치 계산 처리량 (전선관 추천 포함)
+//!
+//! 전선관 규격 면적은 최초 조회 시 한 번만 구성되므로
+//! 반복 계산에서는 테이블 조회만 남음 (이전 커밋과 ns/회 비교)
+//!
+//! 실행: cargo bench --bench conduit_recommendation
+
+use kec_calculator::{calculate_core, distribute_cables_to_conduits, CableData, STANDARD_SIZES};
+use std::hint::black_box;
+use std::time::Instant;
+
+const ITERATIONS: u32 = 200;
+
+fn measure(label: &str, per_iteration: usize, mut f: impl FnMut()) {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    let elapsed = start.elapsed();
+    let count = ITERATIONS as f64 * per_iteration as f64;
+    println!("{:<16} {:>10.1} ns/회", label, elapsed.as_nanos() as f64 / count);
+}
+
+fn main() {
+    let cables: Vec<CableData> = STANDARD_SIZES
+        .iter()
+        .flat_map(|size| {
+            (1..=4).map(move |quantity| CableData {
+                cable_type: "CV".to_string(),
+                cores: "3C".to_string(),
+                size: size.to_string(),
+                quantity,
+                system: "3Φ".to_string(),
+                ground_wire: "HFIX".to_string(),
+                install_method: "B2".to_string(),
+                ..Default::default()
+            })
+        })
+        .filter(|data| calculate_core(data).is_ok())
+        .collect();
+    println!("입력 {}건", cables.len());
+
+    measure("배치 계산", cables.len(), || {
+        for data in &cables {
+            black_box(calculate_core(black_box(data)).ok());
+        }
+    });
+    measure("전선관 분배", cables.len(), || {
+        black_box(distribute_cables_to_conduits(black_box(&cables), 0.33, true).ok());
+    });
+}
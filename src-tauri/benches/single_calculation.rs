@@ -0,0 +1,42 @@
+//! 단일 계산·역산 루프 지연 시간
+//!
+//! 허용전류·외경 테이블과 데이터 체크섬은 최초 조회 시 한 번만 구성되므로
+//! 반복 호출에서는 테이블 재생성 비용이 없음 (이전 커밋과 ns/회 비교)
+//!
+//! 실행: cargo bench --bench single_calculation
+
+use kec_calculator::{calculate_core, recommend_cable_size, CableData};
+use std::hint::black_box;
+use std::time::Instant;
+
+const ITERATIONS: u32 = 2000;
+
+fn measure(label: &str, mut f: impl FnMut()) {
+    // 첫 호출(테이블 구성)은 측정에서 제외
+    f();
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    println!("{:<16} {:>10.1} ns/회", label, start.elapsed().as_nanos() as f64 / ITERATIONS as f64);
+}
+
+fn main() {
+    let data = CableData {
+        cable_type: "CV".to_string(),
+        cores: "3C".to_string(),
+        size: "16".to_string(),
+        quantity: 1,
+        system: "3Φ".to_string(),
+        ground_wire: "HFIX".to_string(),
+        install_method: "B2".to_string(),
+        ..Default::default()
+    };
+
+    measure("단일 계산", || {
+        black_box(calculate_core(black_box(&data)).ok());
+    });
+    measure("규격 역산", || {
+        black_box(recommend_cable_size(black_box(100.0), "CV", "3C", "3Φ", "B2", 30, "ko").ok());
+    });
+}
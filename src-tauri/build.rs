@@ -1,3 +1,7 @@
 fn main() {
-    tauri_build::build()
+    // Tauri 설정 생성은 GUI 빌드에서만 (WASM·CLI 전용 빌드는 계산 엔진만 대상)
+    #[cfg(feature = "gui")]
+    if std::env::var("CARGO_CFG_TARGET_ARCH").as_deref() != Ok("wasm32") {
+        tauri_build::build()
+    }
 }
@@ -0,0 +1,459 @@
+//! KEC 전선관/허용전류 산출 CLI
+//!
+//! 예) kec-cli --cable-type CV --size 16 --cores 3C --system 3Φ --method B2
+//!     kec-cli --input-csv circuits.csv --format table
+//!     cat circuits.json | kec-cli --input-json - --format csv
+//!
+//! 종료코드: 0 모두 성공, 1 계산 실패 포함, 2 입력 파일 오류
+
+use clap::{Parser, ValueEnum};
+use csv::{ReaderBuilder, StringRecord, Trim};
+use kec_calculator::{calculate_core, export_results_csv, CableData, CalculationResult};
+use std::process::ExitCode;
+use std::str::FromStr;
+
+/// 출력 형식
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum OutputFormat {
+    Json,
+    Table,
+    Csv,
+}
+
+/// 명령행 인자
+#[derive(Debug, Parser)]
+#[command(name = "kec-cli", version, about = "KEC 전선관/허용전류 산출 (CLI)")]
+struct Args {
+    /// 전선 종류 (HFIX, TFR-CV, CV, FR-CV, TFR-8)
+    #[arg(long, required_unless_present_any = ["input_csv", "input_json"])]
+    cable_type: Option<String>,
+
+    /// 규격 (mm²), 예: 2.5, 16, 240
+    #[arg(long, required_unless_present_any = ["input_csv", "input_json"])]
+    size: Option<String>,
+
+    /// 가닥수 (1C, 2C, 3C, 4C)
+    #[arg(long, required_unless_present_any = ["input_csv", "input_json"])]
+    cores: Option<String>,
+
+    /// 전압 방식 (1Φ, 3Φ, DC / 1, 3, dc 도 허용)
+    #[arg(long, default_value = "3Φ")]
+    system: String,
+
+    /// 공사방법 (A1, A2, B1, B2, C, D1, D2, E, F). 생략 시 B1/B2
+    #[arg(long, default_value = "")]
+    method: String,
+
+    /// 수량
+    #[arg(long, default_value_t = 1)]
+    quantity: u32,
+
+    /// 접지선 (없음, HFIX)
+    #[arg(long, default_value = "없음")]
+    ground_wire: String,
+
+    /// 배치 입력 CSV (헤더: cable_type,cores,size,quantity,system,ground_wire,install_method,load_current,length,regulation,manufacturer,model,usage,termination_temp,ambient_temp,conductor_material,fault_current,clearing_time,burial_depth_m,soil_resistivity,third_harmonic_percent,parallel_runs,custom_outer_diameter,neutral_loaded)
+    #[arg(long, value_name = "PATH", conflicts_with = "input_json")]
+    input_csv: Option<String>,
+
+    /// 배치 입력 JSON (CableData 배열, "-"이면 stdin에서 읽음)
+    #[arg(long, value_name = "PATH")]
+    input_json: Option<String>,
+
+    /// 설계 부하전류 (A), 과부하 판정용
+    #[arg(long)]
+    load_current: Option<f64>,
+
+    /// 회로 길이 (m), 총 포설 길이 집계용
+    #[arg(long, default_value_t = 0.0)]
+    length: f64,
+
+    /// 단위 체계 (metric, imperial). imperial이면 --size를 AWG로 입력
+    #[arg(long, default_value = "metric")]
+    unit_system: String,
+
+    /// 용도 (동력 간선, 동력 분기, 전등 간선, 전등 분기, 제어), 지정 시 최소 규격 검증
+    #[arg(long)]
+    usage: Option<String>,
+
+    /// 단자 온도 등급 (°C), 케이블 등급보다 낮으면 허용전류 제한
+    #[arg(long)]
+    termination_temp: Option<u32>,
+
+    /// 주변온도 (°C), 미지정 시 기준온도 (공기 30°C / 지중 20°C)
+    #[arg(long, allow_hyphen_values = true)]
+    ambient_temp: Option<i32>,
+
+    /// 도체 재질 (Cu, Al), 미지정 시 Cu
+    #[arg(long)]
+    conductor_material: Option<String>,
+
+    /// 예상 단락전류 (kA), 지정 시 단락 열적 허용 검증
+    #[arg(long)]
+    fault_current: Option<f64>,
+
+    /// 보호장치 동작 시간 (초), 미지정 시 1초
+    #[arg(long)]
+    clearing_time: Option<f64>,
+
+    /// 지중 매설 깊이 (m), D1/D2 설명에 표시만 (허용전류 보정 없음)
+    #[arg(long)]
+    burial_depth: Option<f64>,
+
+    /// 토양 열저항률 (K·m/W), D1/D2만 적용, 미지정 시 2.5
+    #[arg(long)]
+    soil_resistivity: Option<f64>,
+
+    /// 3고조파 함유율 (%), 4C 3상 회로만 적용
+    #[arg(long)]
+    third_harmonic: Option<f64>,
+
+    /// 케이블 외경 (mm), 지정 시 내장 외경 테이블 대신 사용
+    #[arg(long)]
+    outer_diameter: Option<f64>,
+
+    /// 중성선 부하 고려 (불평형·단상 다회로, 4C만 허용전류 × 0.86)
+    #[arg(long)]
+    neutral_loaded: bool,
+
+    /// 병렬 런 수 (같은 규격·길이의 케이블 n벌 병렬 포설)
+    #[arg(long, default_value_t = 1)]
+    parallel_runs: u32,
+
+    /// 적용 규정 (KEC, IEC, NEC)
+    #[arg(long, default_value = "KEC")]
+    regulation: String,
+
+    /// 에러 메시지 언어 (ko, en)
+    #[arg(long, default_value = "ko")]
+    lang: String,
+
+    /// 출력 형식
+    #[arg(long, value_enum, default_value_t = OutputFormat::Json)]
+    format: OutputFormat,
+}
+
+/// 셸에서 입력하기 어려운 'Φ' 대신 1/3, 1P/3P 표기 허용
+fn normalize_system(system: &str) -> String {
+    match system.trim() {
+        "1" | "1P" | "1p" => "1Φ".to_string(),
+        "3" | "3P" | "3p" => "3Φ".to_string(),
+        "dc" => "DC".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// CSV 행의 열 값 (열이 없거나 행이 짧으면 빈 문자열)
+fn field(row: &StringRecord, col: Option<usize>) -> &str {
+    col.and_then(|c| row.get(c)).unwrap_or("")
+}
+
+/// 빈 칸은 None, 그 외는 T로 해석 (실패 시 행 번호와 열 이름을 담은 오류)
+fn parse_optional<T: FromStr>(row: &StringRecord, col: Option<usize>, name: &str) -> Result<Option<T>, String> {
+    match field(row, col) {
+        "" => Ok(None),
+        value => value.parse().map(Some).map_err(|_| {
+            // 헤더가 0번 레코드이므로 데이터 행 번호와 같음
+            let line = row.position().map_or(0, |p| p.record());
+            format!("{}행: {} '{}'을(를) 해석할 수 없습니다.", line, name, value)
+        }),
+    }
+}
+
+/// CSV 배치 입력 읽기 (RFC 4180, 헤더 이름으로 열 매핑)
+fn read_csv_inputs(path: &str, lang: &str) -> Result<Vec<CableData>, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("CSV 파일을 읽을 수 없습니다: {} ({})", path, e))?;
+    let mut reader = ReaderBuilder::new()
+        .trim(Trim::All)
+        .flexible(true)
+        .from_reader(content.trim_start_matches('\u{feff}').as_bytes());
+
+    let header: Vec<String> = reader
+        .headers()
+        .map_err(|e| format!("CSV 헤더를 해석할 수 없습니다: {}", e))?
+        .iter()
+        .map(str::to_string)
+        .collect();
+    if header.iter().all(String::is_empty) {
+        return Err("CSV 파일이 비어 있습니다.".to_string());
+    }
+    let column = |name: &str| header.iter().position(|h| h == name);
+    let required = |name: &str| column(name).ok_or(format!("CSV 헤더에 '{}' 열이 없습니다.", name));
+
+    let cable_type_col = required("cable_type")?;
+    let cores_col = required("cores")?;
+    let size_col = required("size")?;
+    let quantity_col = column("quantity");
+    let system_col = column("system");
+    let ground_wire_col = column("ground_wire");
+    let method_col = column("install_method");
+    let load_current_col = column("load_current");
+    let length_col = column("length");
+    let regulation_col = column("regulation");
+    let manufacturer_col = column("manufacturer");
+    let model_col = column("model");
+    let usage_col = column("usage");
+    let termination_temp_col = column("termination_temp");
+    let ambient_temp_col = column("ambient_temp");
+    let conductor_material_col = column("conductor_material");
+    let fault_current_col = column("fault_current");
+    let clearing_time_col = column("clearing_time");
+    let burial_depth_col = column("burial_depth_m");
+    let soil_resistivity_col = column("soil_resistivity");
+    let third_harmonic_col = column("third_harmonic_percent");
+    let parallel_runs_col = column("parallel_runs");
+    let outer_diameter_col = column("custom_outer_diameter");
+    let neutral_loaded_col = column("neutral_loaded");
+    let optional = |value: &str| (!value.is_empty()).then(|| value.to_string());
+
+    let mut inputs = Vec::new();
+    for (i, row) in reader.records().enumerate() {
+        let row = row.map_err(|e| format!("{}행: CSV 형식 오류 ({})", i + 1, e))?;
+        let neutral_loaded = match field(&row, neutral_loaded_col) {
+            "" | "false" | "0" => false,
+            "true" | "1" => true,
+            n => return Err(format!("{}행: 중성선 부하 여부 '{}'을(를) 해석할 수 없습니다.", i + 1, n)),
+        };
+        inputs.push(CableData {
+            cable_type: field(&row, Some(cable_type_col)).to_string(),
+            cores: field(&row, Some(cores_col)).to_string(),
+            size: field(&row, Some(size_col)).to_string(),
+            quantity: parse_optional(&row, quantity_col, "수량")?.unwrap_or(1),
+            system: normalize_system(match field(&row, system_col) {
+                "" => "3Φ",
+                s => s,
+            }),
+            ground_wire: match field(&row, ground_wire_col) {
+                "" => "없음",
+                g => g,
+            }
+            .to_string(),
+            install_method: field(&row, method_col).to_string(),
+            lang: Some(lang.to_string()),
+            load_current: parse_optional(&row, load_current_col, "부하전류")?,
+            unit_system: None,
+            length: parse_optional(&row, length_col, "길이")?.unwrap_or(0.0),
+            regulation: optional(field(&row, regulation_col)),
+            circuit_count_policy: None,
+            manufacturer: optional(field(&row, manufacturer_col)),
+            model: optional(field(&row, model_col)),
+            usage: optional(field(&row, usage_col)),
+            termination_temp: parse_optional(&row, termination_temp_col, "단자 온도 등급")?,
+            ambient_temp: parse_optional(&row, ambient_temp_col, "주변온도")?,
+            conductor_material: optional(field(&row, conductor_material_col)),
+            fault_current: parse_optional(&row, fault_current_col, "단락전류")?,
+            clearing_time: parse_optional(&row, clearing_time_col, "동작 시간")?,
+            arrangement: None,
+            conduit_type: None,
+            burial_depth_m: parse_optional(&row, burial_depth_col, "매설 깊이")?,
+            soil_resistivity: parse_optional(&row, soil_resistivity_col, "토양 열저항률")?,
+            third_harmonic_percent: parse_optional(&row, third_harmonic_col, "3고조파 함유율")?,
+            parallel_runs: parse_optional(&row, parallel_runs_col, "병렬 런 수")?.unwrap_or(1),
+            custom_outer_diameter: parse_optional(&row, outer_diameter_col, "케이블 외경")?,
+            neutral_loaded,
+        });
+    }
+    Ok(inputs)
+}
+
+/// JSON 배치 입력 읽기 (CableData 배열), 언어 미지정 항목은 --lang 적용
+fn read_json_inputs(path: &str, lang: &str) -> Result<Vec<CableData>, String> {
+    let content = if path == "-" {
+        std::io::read_to_string(std::io::stdin()).map_err(|e| format!("stdin을 읽을 수 없습니다: {}", e))?
+    } else {
+        std::fs::read_to_string(path).map_err(|e| format!("JSON 파일을 읽을 수 없습니다: {} ({})", path, e))?
+    };
+    let mut inputs: Vec<CableData> = serde_json::from_str(content.trim_start_matches('\u{feff}'))
+        .map_err(|e| format!("JSON 입력을 해석할 수 없습니다: {}", e))?;
+    for data in &mut inputs {
+        data.system = normalize_system(&data.system);
+        data.lang.get_or_insert_with(|| lang.to_string());
+    }
+    Ok(inputs)
+}
+
+/// 표 형식 출력
+fn print_table(rows: &[(&CableData, CalculationResult)]) {
+    println!(
+        "{:<8} {:>6} {:>4} {:>4} {:>6} {:>10} {:>14} {:>8}",
+        "종류", "규격", "가닥", "방식", "공사", "허용전류A", "추천전선관", "점유율%"
+    );
+    for (data, result) in rows {
+        println!(
+            "{:<8} {:>6} {:>4} {:>4} {:>6} {:>10.1} {:>14} {:>8.1}",
+            data.cable_type,
+            data.size,
+            data.cores,
+            data.system,
+            data.install_method,
+            result.allowable_current,
+            result.recommended_raceway.name,
+            result.fill_rate
+        );
+    }
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let batch_inputs = match (&args.input_csv, &args.input_json) {
+        (Some(path), _) => Some(read_csv_inputs(path, &args.lang)),
+        (None, Some(path)) => Some(read_json_inputs(path, &args.lang)),
+        (None, None) => None,
+    };
+    let inputs = match batch_inputs {
+        Some(Ok(inputs)) => inputs,
+        Some(Err(e)) => {
+            eprintln!("{}", e);
+            return ExitCode::from(2);
+        }
+        None => vec![CableData {
+            cable_type: args.cable_type.clone().unwrap_or_default(),
+            cores: args.cores.clone().unwrap_or_default(),
+            size: args.size.clone().unwrap_or_default(),
+            quantity: args.quantity,
+            system: normalize_system(&args.system),
+            ground_wire: args.ground_wire.clone(),
+            install_method: args.method.clone(),
+            lang: Some(args.lang.clone()),
+            load_current: args.load_current,
+            unit_system: Some(args.unit_system.clone()),
+            length: args.length,
+            regulation: Some(args.regulation.clone()),
+            circuit_count_policy: None,
+            manufacturer: None,
+            model: None,
+            usage: args.usage.clone(),
+            termination_temp: args.termination_temp,
+            ambient_temp: args.ambient_temp,
+            conductor_material: args.conductor_material.clone(),
+            fault_current: args.fault_current,
+            clearing_time: args.clearing_time,
+            arrangement: None,
+            conduit_type: None,
+            burial_depth_m: args.burial_depth,
+            soil_resistivity: args.soil_resistivity,
+            third_harmonic_percent: args.third_harmonic,
+            parallel_runs: args.parallel_runs,
+            custom_outer_diameter: args.outer_diameter,
+            neutral_loaded: args.neutral_loaded,
+        }],
+    };
+
+    let batch = args.input_csv.is_some() || args.input_json.is_some();
+    let item_label = if args.input_csv.is_some() { "행" } else { "번째 항목" };
+    let outcomes: Vec<(&CableData, Result<CalculationResult, String>)> =
+        inputs.iter().map(|data| (data, calculate_core(data))).collect();
+    let mut failed = false;
+    for (i, (_, outcome)) in outcomes.iter().enumerate() {
+        if let Err(e) = outcome {
+            failed = true;
+            if batch {
+                eprintln!("{}{}: {}", i + 1, item_label, e);
+            } else {
+                eprintln!("{}", e);
+            }
+        }
+    }
+
+    match args.format {
+        OutputFormat::Json => {
+            let json = if batch {
+                // 입력 순서대로 항목당 1개 (실패 항목도 index와 사유로 남김)
+                let entries: Vec<serde_json::Value> = outcomes
+                    .iter()
+                    .enumerate()
+                    .map(|(i, (_, outcome))| match outcome {
+                        Ok(result) => serde_json::json!({ "index": i, "result": result }),
+                        Err(e) => serde_json::json!({ "index": i, "error": e }),
+                    })
+                    .collect();
+                serde_json::to_string_pretty(&entries)
+            } else if let Some((_, Ok(result))) = outcomes.first() {
+                serde_json::to_string_pretty(result)
+            } else {
+                Ok(String::new())
+            };
+            match json {
+                Ok(json) if !json.is_empty() => println!("{}", json),
+                Ok(_) => {}
+                Err(e) => {
+                    eprintln!("JSON 직렬화 실패: {}", e);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        OutputFormat::Table => {
+            let rows: Vec<(&CableData, CalculationResult)> = outcomes
+                .into_iter()
+                .filter_map(|(data, outcome)| outcome.ok().map(|result| (data, result)))
+                .collect();
+            if !rows.is_empty() {
+                print_table(&rows);
+            }
+        }
+        OutputFormat::Csv => {
+            let (items, results): (Vec<CableData>, Vec<CalculationResult>) =
+                outcomes
+                    .into_iter()
+                    .filter_map(|(data, outcome)| outcome.ok().map(|result| (data.clone(), result)))
+                    .unzip();
+            match export_results_csv(&items, &results) {
+                // 파이프 처리용이므로 엑셀용 BOM은 제거
+                Ok(csv) => print!("{}", csv.trim_start_matches('\u{feff}')),
+                Err(e) => {
+                    eprintln!("{}", e);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_temp_csv(name: &str, content: &str) -> String {
+        let path = std::env::temp_dir().join(format!("kec-cli-{}-{}.csv", name, std::process::id()));
+        std::fs::write(&path, content).unwrap();
+        path.to_string_lossy().into_owned()
+    }
+
+    #[test]
+    fn csv_inputs_accept_quoted_fields_and_bom() {
+        let path = write_temp_csv(
+            "quoted",
+            "\u{feff}cable_type,cores,size,install_method,manufacturer,model,load_current\n\
+             CV,3C,16,B2,\"LS전선, 안양\",\"0.6/1kV \"\"CV\"\"\",42.5\n\
+             \n\
+             HFIX,1C,2.5,,,,\n",
+        );
+        let inputs = read_csv_inputs(&path, "ko").unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(inputs.len(), 2);
+        assert_eq!(inputs[0].manufacturer.as_deref(), Some("LS전선, 안양"));
+        assert_eq!(inputs[0].model.as_deref(), Some("0.6/1kV \"CV\""));
+        assert_eq!(inputs[0].load_current, Some(42.5));
+        assert_eq!(inputs[1].cable_type, "HFIX");
+        assert_eq!(inputs[1].quantity, 1);
+        assert_eq!(inputs[1].load_current, None);
+    }
+
+    #[test]
+    fn csv_inputs_report_row_and_column_on_parse_error() {
+        let path = write_temp_csv("invalid", "cable_type,cores,size,ambient_temp\nCV,3C,16,30\nCV,3C,16,abc\n");
+        let err = read_csv_inputs(&path, "ko").unwrap_err();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(err, "2행: 주변온도 'abc'을(를) 해석할 수 없습니다.");
+    }
+}
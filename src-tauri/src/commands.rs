@@ -0,0 +1,84 @@
+//! 무거운 계산의 비동기 Tauri 커맨드 (GUI 빌드 전용)
+//!
+//! 계산을 블로킹 스레드 풀에서 실행하는 래퍼를 lib에 두어 main.rs는 등록만 하고,
+//! 통합 테스트(tests/command_results.rs)가 실제 커맨드를 그대로 호출할 수 있게 함
+
+use crate::{BatchCalculation, CableData, CalculationResult, ConduitGroup, CoverageReport, TrayLayoutPlan};
+use tauri::{Emitter, Window};
+
+/// 무거운 계산을 블로킹 스레드 풀에서 실행 (UI 스레드 블로킹 방지)
+/// 순수 계산은 동기 함수 그대로, 에러 메시지도 그대로 전달
+pub async fn run_blocking<T, F>(compute: F) -> Result<T, String>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T, String> + Send + 'static,
+{
+    tauri::async_runtime::spawn_blocking(compute)
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// 주변온도(행) × 회로 수(열) 허용전류 민감도 매트릭스 (히트맵용)
+#[tauri::command]
+pub async fn sensitivity_analysis(data: CableData, temps: Vec<u32>, circuits: Vec<u32>) -> Result<Vec<Vec<f64>>, String> {
+    run_blocking(move || crate::sensitivity_analysis(&data, &temps, &circuits)).await
+}
+
+/// 복수 케이블 트레이 배치 최적화 (충진율·집합계수 만족, 단수·폭 최소화)
+#[tauri::command]
+pub async fn optimize_tray_layout(cables: Vec<CableData>, tray_widths: Vec<f64>) -> Result<TrayLayoutPlan, String> {
+    run_blocking(move || crate::optimize_tray_layout(&cables, &tray_widths)).await
+}
+
+/// 역률별 필요 전류·추천 규격 (역률, 전류 A, 규격)
+#[tauri::command]
+pub async fn size_vs_power_factor(p_kw: f64, pf_values: Vec<f64>, system: String, voltage: f64, cable_type: String, method: String, lang: Option<String>) -> Result<Vec<(f64, f64, String)>, String> {
+    run_blocking(move || {
+        crate::size_vs_power_factor(p_kw, &pf_values, &system, voltage, &cable_type, &method, lang.as_deref().unwrap_or("ko"))
+    })
+    .await
+}
+
+/// 점유율 한계 이하로 케이블을 여러 전선관에 분배 (keep_circuits_together: 회로 단위로 묶기, 기본 false)
+#[tauri::command]
+pub async fn distribute_cables_to_conduits(cables: Vec<CableData>, fill_limit: f64, keep_circuits_together: Option<bool>) -> Result<Vec<ConduitGroup>, String> {
+    run_blocking(move || {
+        crate::distribute_cables_to_conduits(&cables, fill_limit, keep_circuits_together.unwrap_or(false))
+    })
+    .await
+}
+
+/// 종류·규격·가닥수 조합별 외경·허용전류 데이터 존재 여부 진단
+#[tauri::command]
+pub async fn data_coverage_report() -> Result<CoverageReport, String> {
+    run_blocking(|| Ok(crate::data_coverage_report())).await
+}
+
+/// 대량 배치 계산을 청크 단위로 스트리밍
+/// 청크마다 `calc-chunk`(BatchChunk), 완료 시 `calc-complete`(BatchComplete, 에러 목록 포함) 이벤트 방출
+#[tauri::command]
+pub async fn calculate_batch_streaming(window: Window, cables: Vec<CableData>, chunk_size: usize) -> Result<(), String> {
+    run_blocking(move || {
+        let complete = crate::calculate_batch_chunked(&cables, chunk_size, |chunk| window.emit("calc-chunk", chunk))?;
+        window.emit("calc-complete", complete).map_err(|e| e.to_string())
+    })
+    .await
+}
+
+/// 여러 회로 일괄 계산 (입력 순서 유지, 실패 항목은 번호·항목이 담긴 에러)
+#[tauri::command]
+pub async fn calculate_batch(items: Vec<CableData>) -> Result<Vec<Result<CalculationResult, String>>, String> {
+    run_blocking(move || Ok(crate::calculate_batch(&items))).await
+}
+
+/// 일괄 계산 + 요약 (회로 수·도체 단면적 합계, 분전반 집계용)
+#[tauri::command]
+pub async fn calculate_batch_with_summary(items: Vec<CableData>) -> Result<BatchCalculation, String> {
+    run_blocking(move || Ok(crate::calculate_batch_with_summary(&items))).await
+}
+
+/// 입력 종류·가닥수의 모든 표준 규격 계산 결과 (규격 오름차순, 비교 표용)
+#[tauri::command]
+pub async fn calculate_candidates(data: CableData) -> Result<Vec<CalculationResult>, String> {
+    run_blocking(move || Ok(crate::calculate_candidates(data))).await
+}
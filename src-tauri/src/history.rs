@@ -0,0 +1,123 @@
+//! 계산 이력 저장소 (SQLite, 데스크톱 전용)
+//!
+//! 프로젝트별로 과거 계산의 입력·결과를 JSON으로 보관하고 다시 조회하기 위한 로컬 DB.
+//! DB 파일은 app data 디렉터리 아래에 생성하며, 경로 미설정 시 저장·조회하지 않음
+
+use crate::{format_utc_timestamp, unix_now, CableData, CalculationResult};
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+/// 계산 이력 DB 파일명 (app data 디렉터리 아래)
+pub const HISTORY_DB_FILE: &str = "history.sqlite3";
+
+static HISTORY_DB_PATH: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// 저장된 계산 1건
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalculationRecord {
+    pub id: i64,
+    pub timestamp: String,  // UTC, ISO 8601 (예: 2024-05-01T09:30:00Z)
+    pub project: String,    // 프로젝트명
+    pub input: CableData,
+    pub result: CalculationResult,
+}
+
+/// 계산 이력 DB 경로 설정 (None이면 저장·조회 중지)
+pub fn set_history_db_path(path: Option<PathBuf>) {
+    *HISTORY_DB_PATH.write().unwrap_or_else(|e| e.into_inner()) = path;
+}
+
+/// DB 열기 (디렉터리·테이블이 없으면 생성)
+fn open(path: &Path) -> rusqlite::Result<Connection> {
+    if let Some(dir) = path.parent() {
+        // 디렉터리 생성 실패는 아래 open에서 에러로 드러남
+        let _ = std::fs::create_dir_all(dir);
+    }
+    let conn = Connection::open(path)?;
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS calculations (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            timestamp TEXT NOT NULL,
+            project TEXT NOT NULL,
+            input_json TEXT NOT NULL,
+            result_json TEXT NOT NULL
+        );
+        CREATE INDEX IF NOT EXISTS idx_calculations_project ON calculations (project);",
+    )?;
+    Ok(conn)
+}
+
+/// 설정된 DB 열기 (경로 미설정 시 에러 메시지)
+fn open_configured() -> Result<Connection, String> {
+    let path = HISTORY_DB_PATH
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+        .ok_or_else(|| "계산 이력 DB 경로가 설정되지 않았습니다".to_string())?;
+    open(&path).map_err(|e| format!("계산 이력 DB를 열 수 없습니다: {} ({})", path.display(), e))
+}
+
+/// 계산 1건 저장, 새 레코드 ID 반환
+/// 프로젝트명은 앞뒤 공백 제거 후 저장하며 비어 있으면 에러
+pub fn save_calculation(project: &str, data: &CableData, result: &CalculationResult) -> Result<i64, String> {
+    let project = project.trim();
+    if project.is_empty() {
+        return Err("프로젝트명을 입력해야 합니다".to_string());
+    }
+    let input_json = serde_json::to_string(data).map_err(|e| e.to_string())?;
+    let result_json = serde_json::to_string(result).map_err(|e| e.to_string())?;
+    let conn = open_configured()?;
+    conn.execute(
+        "INSERT INTO calculations (timestamp, project, input_json, result_json) VALUES (?1, ?2, ?3, ?4)",
+        params![format_utc_timestamp(unix_now().as_secs()), project, input_json, result_json],
+    )
+    .map_err(|e| format!("계산 이력을 저장할 수 없습니다: {}", e))?;
+    Ok(conn.last_insert_rowid())
+}
+
+/// 저장된 계산 조회 (최신순), project 지정 시 해당 프로젝트만
+/// DB 경로 미설정·DB 오류 시 빈 목록, JSON이 손상된 레코드는 건너뜀
+pub fn list_calculations(project: Option<&str>) -> Vec<CalculationRecord> {
+    let Ok(conn) = open_configured() else {
+        return Vec::new();
+    };
+    let project = project.map(str::trim).filter(|p| !p.is_empty());
+    let query = || -> rusqlite::Result<Vec<CalculationRecord>> {
+        let mut stmt = conn.prepare(
+            "SELECT id, timestamp, project, input_json, result_json FROM calculations
+             WHERE ?1 IS NULL OR project = ?1 ORDER BY id DESC",
+        )?;
+        let rows = stmt.query_map(params![project], |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+                row.get::<_, String>(4)?,
+            ))
+        })?;
+        Ok(rows
+            .filter_map(Result::ok)
+            .filter_map(|(id, timestamp, project, input_json, result_json)| {
+                Some(CalculationRecord {
+                    id,
+                    timestamp,
+                    project,
+                    input: serde_json::from_str(&input_json).ok()?,
+                    result: serde_json::from_str(&result_json).ok()?,
+                })
+            })
+            .collect())
+    };
+    query().unwrap_or_default()
+}
+
+/// 저장된 계산 1건 삭제, 해당 ID가 없으면 false
+pub fn delete_calculation(id: i64) -> Result<bool, String> {
+    let conn = open_configured()?;
+    conn.execute("DELETE FROM calculations WHERE id = ?1", params![id])
+        .map(|deleted| deleted > 0)
+        .map_err(|e| format!("계산 이력을 삭제할 수 없습니다: {}", e))
+}
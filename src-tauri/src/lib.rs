@@ -0,0 +1,9767 @@
+//! KEC 전선관/허용전류 계산 라이브러리
+//!
+//! Tauri GUI(`main.rs`)와 CLI(`bin/kec-cli.rs`)가 공유하는 순수 계산 로직
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{mpsc, Arc, Mutex, OnceLock, RwLock};
+
+#[cfg(all(feature = "gui", not(target_arch = "wasm32")))]
+pub mod commands;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod history;
+#[cfg(target_arch = "wasm32")]
+pub mod wasm;
+
+/// 전선 데이터 구조체
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CableData {
+    pub cable_type: String,      // 전선 종류
+    pub cores: String,           // 가닥수 (1C, 2C, 3C, 4C)
+    pub size: String,            // 규격 (mm²)
+    pub quantity: u32,           // 수량
+    pub system: String,          // 전압 방식 (1Φ, 3Φ)
+    pub ground_wire: String,     // 접지선 (없음, HFIX)
+    pub install_method: String,  // 공사방법 (A1, A2, B1, B2, C, D1, E, F)
+    #[serde(default)]
+    pub lang: Option<String>,    // 에러 메시지 언어 (ko, en), 미지정 시 ko
+    #[serde(default)]
+    pub load_current: Option<f64>,  // 설계 부하전류 (A), 과부하 판정용
+    #[serde(default)]
+    pub unit_system: Option<String>,  // 단위 체계 (metric, imperial), 미지정 시 metric
+    #[serde(default)]
+    pub length: f64,             // 회로 길이 (m, imperial이면 ft), 0이면 물량 집계 생략
+    #[serde(default)]
+    pub regulation: Option<String>,  // 적용 규정 (KEC, IEC, NEC), 미지정 시 KEC
+    #[serde(default)]
+    pub circuit_count_policy: Option<CircuitCountPolicy>,  // 단심 회로 수 산정 정책, 미지정 시 CeilUp
+    #[serde(default)]
+    pub manufacturer: Option<String>,  // 제조사 (조달 관리용, 계산에는 영향 없음)
+    #[serde(default)]
+    pub model: Option<String>,         // 모델명 (조달 관리용, 계산에는 영향 없음)
+    #[serde(default)]
+    pub usage: Option<String>,         // 용도 (MINIMUM_SIZES), 미지정 시 최소 규격 검증 생략
+    #[serde(default)]
+    pub termination_temp: Option<u32>, // 단자(접속부) 온도 등급 (°C), 미지정 시 케이블 등급 그대로
+    #[serde(default)]
+    pub ambient_temp: Option<i32>,     // 주변온도 (°C), 미지정 시 기준온도 (공기 30°C / 지중 20°C)
+    #[serde(default)]
+    pub conductor_material: Option<String>, // 도체 재질 (Cu, Al), 미지정 시 Cu (과대 허용전류 방지)
+    #[serde(default)]
+    pub fault_current: Option<f64>,    // 예상 단락전류 (kA), 지정 시 단락 열적 허용 검증
+    #[serde(default)]
+    pub clearing_time: Option<f64>,    // 보호장치 동작(차단) 시간 (초), 미지정 시 1초
+    #[serde(default)]
+    pub arrangement: Option<Arrangement>, // 케이블 배치 형태 (집합 보정계수 표 선택), 미지정 시 Conduit
+    #[serde(default)]
+    pub burial_depth_m: Option<f64>,   // 지중 매설 깊이 (m, D1/D2 설명에 표시만, 허용전류 보정 없음)
+    #[serde(default)]
+    pub soil_resistivity: Option<f64>, // 토양 열저항률 (K·m/W, D1/D2만 적용), 미지정 시 기준 2.5
+    #[serde(default)]
+    pub third_harmonic_percent: Option<f64>, // 상전류 중 3고조파 함유율 (%, 4C 3상만 적용), 미지정 시 보정 없음
+    #[serde(default = "default_parallel_runs")]
+    pub parallel_runs: u32,            // 병렬 런 수 (quantity 구성을 n벌 병렬 포설), 기본 1
+    #[serde(default)]
+    pub conduit_type: Option<ConduitType>, // 전선관 종류 (관종별 내경 테이블), 미지정 시 후강전선관
+    #[serde(default)]
+    pub custom_outer_diameter: Option<f64>, // 케이블 외경 (mm, 제조사 규격서 값), 지정 시 내장 외경 테이블 대신 사용
+    #[serde(default)]
+    pub neutral_loaded: bool,          // 중성선 부하 여부 (불평형·단상 다회로, 4C만 적용), 기본 false
+}
+
+fn default_parallel_runs() -> u32 {
+    1
+}
+
+impl Default for CableData {
+    fn default() -> Self {
+        CableData {
+            cable_type: String::new(),
+            cores: String::new(),
+            size: String::new(),
+            quantity: 0,
+            system: String::new(),
+            ground_wire: String::new(),
+            install_method: String::new(),
+            lang: None,
+            load_current: None,
+            unit_system: None,
+            length: 0.0,
+            regulation: None,
+            circuit_count_policy: None,
+            manufacturer: None,
+            model: None,
+            usage: None,
+            termination_temp: None,
+            ambient_temp: None,
+            conductor_material: None,
+            fault_current: None,
+            clearing_time: None,
+            arrangement: None,
+            burial_depth_m: None,
+            soil_resistivity: None,
+            third_harmonic_percent: None,
+            parallel_runs: default_parallel_runs(),
+            conduit_type: None,
+            custom_outer_diameter: None,
+            neutral_loaded: false,
+        }
+    }
+}
+
+/// 케이블 배치 형태 (집합 보정계수 표 선택)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Arrangement {
+    #[default]
+    Conduit,          // 전선관·덕트 묶음 / 밀집 포설 (Table B.52.17 항목 1)
+    SingleLayerTray,  // 천공형 트레이 단일 층 밀착 (Table B.52.17 항목 4)
+    MultiLayerTray,   // 천공형 트레이 다층 밀착 (Table B.52.20, 3단 기준)
+    Spaced,           // 케이블 외경 이상 간격 유지 (Table B.52.21, 트레이 1단 기준)
+}
+
+impl Arrangement {
+    /// 집합 보정계수 출처 표
+    pub fn table_reference(self) -> &'static str {
+        match self {
+            Arrangement::Conduit | Arrangement::SingleLayerTray => "Table B.52.17",
+            Arrangement::MultiLayerTray => "Table B.52.20",
+            Arrangement::Spaced => "Table B.52.21",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Arrangement::Conduit => "전선관 묶음",
+            Arrangement::SingleLayerTray => "트레이 단일 층",
+            Arrangement::MultiLayerTray => "트레이 다층",
+            Arrangement::Spaced => "간격 유지",
+        }
+    }
+}
+
+/// 단심 회로 수 산정 정책 (가닥수가 회로당 가닥수로 나누어떨어지지 않을 때)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CircuitCountPolicy {
+    #[default]
+    CeilUp,        // 올림: 남는 가닥도 1회로로 간주
+    RequireExact,  // 나누어떨어지지 않으면 에러
+    FloorDown,     // 내림: 남는 가닥 무시
+}
+
+impl CircuitCountPolicy {
+    pub fn label(self) -> &'static str {
+        match self {
+            CircuitCountPolicy::CeilUp => "올림",
+            CircuitCountPolicy::RequireExact => "정확히 나눔",
+            CircuitCountPolicy::FloorDown => "내림",
+        }
+    }
+}
+
+/// 계산 결과 구조체
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalculationResult {
+    pub total_area: f64,              // 총 단면적 (mm², 외경 원 면적 단순 합, 전선관 선정 기준)
+    pub effective_area: f64,          // 실효 점유 면적 (mm², 패킹 공극 반영, 참고값)
+    pub conductor_area: f64,          // 도체 단면적 (mm²)
+    pub allowable_current: f64,       // 허용전류 (A)
+    #[serde(alias = "recommended_conduit")]
+    pub recommended_raceway: RacewayRecommendation,  // 추천 전선관·트레이
+    pub fill_rate: f64,               // 점유율 (%)
+    pub install_method_desc: String,  // 공사 방법 설명
+    pub size_label: String,           // 적용 규격 표시 (예: "16 mm²", "4 AWG (16 mm² 적용)")
+    pub conductor_max_temp: u32,      // 적용 도체 최고허용온도 (°C, PVC 70 / XLPE 90)
+    pub total_cable_length: f64,      // 총 케이블 포설 길이 (length × 수량)
+    pub ground_wire_length: f64,      // 접지선 길이 (병렬 런마다 1가닥)
+    pub regulation: String,           // 적용 규정 (KEC, IEC, NEC)
+    pub minimum_size: Option<String>, // 적용된 최소 규격 제약 (용도 미지정 시 None)
+    #[serde(default)]
+    pub applied_temp_rating: u32,     // 허용전류 산정에 적용한 온도 등급 (°C, 단자 등급 제한 반영)
+    #[serde(default)]
+    pub data_provenance: DataProvenance, // 계산에 사용된 데이터 출처 (재현성 확인용)
+    #[serde(default)]
+    pub alternatives: Vec<SizeAlternative>, // 바로 아래·위 규격 비교 (데이터 없는 규격 제외)
+    #[serde(default)]
+    pub formulas: Vec<String>,        // 계산 근거 수식 (대입값 포함, 예: "허용전류 = 119A × 0.80(집합) = 95.2A")
+    #[serde(default)]
+    pub ground_wire_size: String,     // 선정된 접지선(보호도체) 규격 (mm², 접지선 없으면 빈 문자열)
+    #[serde(default)]
+    pub short_circuit_ok: Option<bool>,      // 단락 열적 허용 만족 여부 (I²t ≤ k²S², 단락전류 미지정 시 None)
+    #[serde(default)]
+    pub short_circuit_min_size: Option<f64>, // 단락 열적 허용 최소 단면적 (mm², 단락전류 미지정 시 None)
+    #[serde(default)]
+    pub size: String,                 // 계산에 적용한 표준 규격 (mm², imperial 입력도 환산 규격)
+    #[serde(default)]
+    pub load_margin_percent: Option<f64>, // 부하전류 대비 허용전류 여유율 (%, 음수면 부족, 부하전류 미지정 시 None)
+    #[serde(default)]
+    pub recommended_breaker_a: Option<u32>, // 보호 협조를 만족하는 최소 차단기 정격 (A, 부하전류 미지정 또는 협조 불가 시 None)
+    #[serde(default)]
+    pub reference: String,            // 허용전류 출처 표 (예: "IEC 60364-5-52 Table B.52.5 / KEC 232")
+    #[serde(default)]
+    pub annual_loss_kwh: Option<f64>, // 연간 도체 전력손실 (kWh, 연속 운전 기준, 길이·부하전류 미지정 시 None)
+    #[serde(default)]
+    pub min_bending_radius_mm: f64,   // 최소 굽힘 반경 (mm, 케이블 외경 × 종류별 배수)
+}
+
+/// 선정 규격 대비 인접 규격 대안
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SizeAlternative {
+    pub size: String,                 // 규격 (mm²)
+    pub allowable_current: f64,       // 보정 후 허용전류 (A)
+    #[serde(alias = "recommended_conduit")]
+    pub recommended_raceway: String,  // 추천 전선관·트레이 표시 문자열
+    pub fill_rate: f64,               // 점유율 (%)
+    pub suitable: bool,               // 적합 여부 (점유율 정상, 과부하 아님, 최소 규격 이상)
+    pub overloaded: Option<bool>,     // 과부하 여부 (부하전류 미지정 시 None)
+}
+
+/// 계산 결과의 데이터 출처
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DataProvenance {
+    pub table_version: String,  // 허용전류 테이블 버전 (DATA_VERSION, 사용자 테이블 로드 시 "custom")
+    pub regulation: String,     // 적용 규정 (KEC, IEC, NEC)
+    pub data_checksum: String,  // 계산 데이터 체크섬 (get_data_checksum)
+    pub calculated_at: String,  // 계산 시각 (UTC ISO 8601)
+}
+
+/// CalculationResult 필드별 단위 (중첩 필드는 "상위.하위", 단위 없는 필드는 "")
+/// 결과 필드를 추가하면 여기에도 추가해야 함 (테스트로 강제)
+const RESULT_FIELD_UNITS: &[(&str, &str)] = &[
+    ("total_area", "mm²"),
+    ("effective_area", "mm²"),
+    ("conductor_area", "mm²"),
+    ("allowable_current", "A"),
+    ("recommended_raceway", ""),
+    ("recommended_raceway.nominal", "mm"),
+    ("recommended_raceway.name", ""),
+    ("recommended_raceway.kind", ""),
+    ("recommended_raceway.inner_diameter", "mm"),
+    ("recommended_raceway.fill_rate", "%"),
+    ("recommended_raceway.fill_limit", "%"),
+    ("recommended_raceway.status", ""),
+    ("recommended_raceway.geometry_note", ""),
+    ("formulas", ""),
+    ("ground_wire_size", "mm²"),
+    ("short_circuit_ok", ""),
+    ("short_circuit_min_size", "mm²"),
+    ("size", "mm²"),
+    ("load_margin_percent", "%"),
+    ("recommended_breaker_a", "A"),
+    ("reference", ""),
+    ("annual_loss_kwh", "kWh"),
+    ("min_bending_radius_mm", "mm"),
+    ("fill_rate", "%"),
+    ("install_method_desc", ""),
+    ("size_label", ""),
+    ("conductor_max_temp", "°C"),
+    ("total_cable_length", "m"),
+    ("ground_wire_length", "m"),
+    ("regulation", ""),
+    ("minimum_size", ""),
+    ("applied_temp_rating", "°C"),
+    ("data_provenance", ""),
+    ("data_provenance.table_version", ""),
+    ("data_provenance.regulation", ""),
+    ("data_provenance.data_checksum", ""),
+    ("data_provenance.calculated_at", ""),
+    ("alternatives", ""),
+];
+
+/// 계산 결과 필드별 단위 (API 소비자 자동 라벨링용)
+pub fn result_field_units() -> HashMap<String, String> {
+    RESULT_FIELD_UNITS
+        .iter()
+        .map(|(field, unit)| (field.to_string(), unit.to_string()))
+        .collect()
+}
+
+/// 전선 타입 정보
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CableTypeInfo {
+    pub code: String,
+    pub name: String,
+    pub description: String,
+    pub max_temp: u32,  // 최고 허용 온도 (°C)
+    pub insulation: String,  // 절연체 종류
+    pub fire_rating: String,  // 난연/내화 등급 (일반, 난연, 내열, 내화)
+}
+
+/// 계산 에러 메시지 키
+pub const ERR_2C_IN_THREE_PHASE: &str = "cores_2c_in_three_phase";
+pub const ERR_4C_IN_SINGLE_PHASE: &str = "cores_4c_in_single_phase";
+pub const ERR_UNSUPPORTED_SIZE: &str = "unsupported_size";
+pub const ERR_CURRENT_DATA_NOT_FOUND: &str = "current_data_not_found";
+pub const ERR_INVALID_VERTICAL_LENGTH: &str = "invalid_vertical_length";
+pub const ERR_INVALID_SIZE: &str = "invalid_size";
+pub const ERR_INVALID_INSULATION: &str = "invalid_insulation";
+pub const ERR_INVALID_INSTALL_METHOD: &str = "invalid_install_method";
+pub const ERR_INVALID_LOADED_CONDUCTORS: &str = "invalid_loaded_conductors";
+pub const ERR_INVALID_UNIT_SYSTEM: &str = "invalid_unit_system";
+pub const ERR_INVALID_AWG: &str = "invalid_awg";
+pub const ERR_INVALID_CURRENT_TABLE: &str = "invalid_current_table";
+pub const ERR_UNKNOWN_DATA_VERSION: &str = "unknown_data_version";
+pub const ERR_INVALID_TRAY_WIDTH: &str = "invalid_tray_width";
+pub const ERR_CABLE_EXCEEDS_TRAY: &str = "cable_exceeds_tray";
+pub const ERR_INVALID_VOLTAGE_DROP_INPUT: &str = "invalid_voltage_drop_input";
+pub const ERR_INVALID_TRANSFORMER: &str = "invalid_transformer";
+pub const ERR_UNKNOWN_CABLE_TYPE: &str = "unknown_cable_type";
+pub const ERR_BINARY_VERSION_MISMATCH: &str = "binary_version_mismatch";
+pub const ERR_BINARY_DECODE: &str = "binary_decode";
+pub const ERR_PROJECT_CORRUPT: &str = "project_corrupt";
+pub const ERR_PROJECT_VERSION_UNSUPPORTED: &str = "project_version_unsupported";
+pub const ERR_INVALID_LOAD_POWER: &str = "invalid_load_power";
+pub const ERR_UNKNOWN_ZONE: &str = "unknown_zone";
+pub const ERR_INVALID_BRANCH_LOAD: &str = "invalid_branch_load";
+pub const ERR_UNKNOWN_REGULATION: &str = "unknown_regulation";
+pub const ERR_INVALID_FILL_LIMIT: &str = "invalid_fill_limit";
+pub const ERR_CABLE_EXCEEDS_CONDUIT: &str = "cable_exceeds_conduit";
+pub const ERR_CIRCUIT_COUNT_NOT_EXACT: &str = "circuit_count_not_exact";
+pub const ERR_INVALID_MATERIAL: &str = "invalid_material";
+pub const ERR_INVALID_DURATION: &str = "invalid_duration";
+pub const ERR_UNKNOWN_INSTALLATION_TYPE: &str = "unknown_installation_type";
+pub const ERR_INVALID_CHUNK_SIZE: &str = "invalid_chunk_size";
+pub const ERR_UNKNOWN_USAGE: &str = "unknown_usage";
+pub const ERR_INVALID_TERMINATION_TEMP: &str = "invalid_termination_temp";
+pub const ERR_BELOW_MINIMUM_SIZE: &str = "below_minimum_size";
+pub const ERR_INVALID_ENTRY_TYPE: &str = "invalid_entry_type";
+pub const ERR_EMPTY_CABLE_LIST: &str = "empty_cable_list";
+pub const ERR_AMBIENT_TEMP_OUT_OF_RANGE: &str = "ambient_temp_out_of_range";
+pub const ERR_INVALID_LOAD_CURRENT: &str = "invalid_load_current";
+pub const ERR_PARALLEL_CONDUCTORS_REQUIRED: &str = "parallel_conductors_required";
+pub const ERR_INVALID_FAULT_CURRENT: &str = "invalid_fault_current";
+pub const ERR_EXPORT_LENGTH_MISMATCH: &str = "export_length_mismatch";
+pub const ERR_INVALID_BURIAL_DEPTH: &str = "invalid_burial_depth";
+pub const ERR_INVALID_SOIL_RESISTIVITY: &str = "invalid_soil_resistivity";
+pub const ERR_INVALID_HARMONIC_PERCENT: &str = "invalid_harmonic_percent";
+pub const ERR_INVALID_PARALLEL_RUNS: &str = "invalid_parallel_runs";
+pub const ERR_INVALID_QUANTITY: &str = "invalid_quantity";
+pub const ERR_INVALID_CORES: &str = "invalid_cores";
+pub const ERR_INSTALL_METHOD_CORES_MISMATCH: &str = "install_method_cores_mismatch";
+pub const ERR_CABLE_UNDERSIZED_FOR_BREAKER: &str = "cable_undersized_for_breaker";
+pub const ERR_INVALID_OUTER_DIAMETER: &str = "invalid_outer_diameter";
+pub const ERR_CORES_IN_DC: &str = "cores_in_dc";
+pub const ERR_TRANSFORMER_IN_DC: &str = "transformer_in_dc";
+pub const ERR_UNKNOWN_CONDUIT: &str = "unknown_conduit";
+pub const ERR_INVALID_PULL_SECTION: &str = "invalid_pull_section";
+
+/// 에러 메시지의 규격 목록 자리 (localize_error가 STANDARD_SIZES로 채움)
+const SIZES_PLACEHOLDER: &str = "{sizes}";
+
+/// 에러 메시지 테이블: (키, 한국어, 영어)
+const ERROR_MESSAGES: &[(&str, &str, &str)] = &[
+    (
+        ERR_2C_IN_THREE_PHASE,
+        "2C 케이블은 3상(3Φ) 회로에 사용할 수 없습니다.",
+        "2C cables cannot be used on three-phase (3Φ) circuits.",
+    ),
+    (
+        ERR_4C_IN_SINGLE_PHASE,
+        "4C 케이블은 단상(1Φ) 회로에 사용할 수 없습니다.",
+        "4C cables cannot be used on single-phase (1Φ) circuits.",
+    ),
+    (
+        ERR_UNSUPPORTED_SIZE,
+        "지원하지 않는 전선 규격입니다.",
+        "Unsupported cable size.",
+    ),
+    (
+        ERR_CURRENT_DATA_NOT_FOUND,
+        "허용전류 데이터를 찾을 수 없습니다.",
+        "No current-carrying capacity data for this combination.",
+    ),
+    (
+        ERR_INVALID_VERTICAL_LENGTH,
+        "수직 구간 길이는 0 이상이어야 합니다.",
+        "Vertical run length must be zero or greater.",
+    ),
+    (
+        ERR_INVALID_SIZE,
+        "지원하지 않는 규격입니다. (가능: {sizes})",
+        "Unknown size. (valid: {sizes})",
+    ),
+    (
+        ERR_INVALID_INSULATION,
+        "지원하지 않는 절연체입니다. (가능: PVC, XLPE)",
+        "Unknown insulation. (valid: PVC, XLPE)",
+    ),
+    (
+        ERR_INVALID_INSTALL_METHOD,
+        "지원하지 않는 공사방법입니다. (가능: A1, A2, B1, B2, C, D1, D2, E, F)",
+        "Unknown installation method. (valid: A1, A2, B1, B2, C, D1, D2, E, F)",
+    ),
+    (
+        ERR_INVALID_LOADED_CONDUCTORS,
+        "부하 도체 수는 2 또는 3이어야 합니다.",
+        "Number of loaded conductors must be 2 or 3.",
+    ),
+    (
+        ERR_INVALID_UNIT_SYSTEM,
+        "지원하지 않는 단위 체계입니다. (가능: metric, imperial)",
+        "Unknown unit system. (valid: metric, imperial)",
+    ),
+    (
+        ERR_INVALID_AWG,
+        "지원하지 않는 AWG 규격입니다. (가능: 14~1, 1/0~4/0 AWG, 250~1000 kcmil)",
+        "Unsupported AWG size. (valid: 14-1, 1/0-4/0 AWG, 250-1000 kcmil)",
+    ),
+    (
+        ERR_INVALID_CURRENT_TABLE,
+        "사용자 허용전류 테이블 형식이 올바르지 않습니다.",
+        "Custom current table is malformed.",
+    ),
+    (
+        ERR_UNKNOWN_DATA_VERSION,
+        "지원하지 않는 데이터 버전입니다. (가능: 2023)",
+        "Unsupported data version. (valid: 2023)",
+    ),
+    (
+        ERR_INVALID_TRAY_WIDTH,
+        "트레이 폭은 0보다 큰 값으로 1개 이상 입력해야 합니다.",
+        "At least one tray width greater than 0 is required.",
+    ),
+    (
+        ERR_CABLE_EXCEEDS_TRAY,
+        "가장 넓은 트레이에도 수용할 수 없는 케이블입니다.",
+        "Cable does not fit even the widest tray.",
+    ),
+    (
+        ERR_INVALID_VOLTAGE_DROP_INPUT,
+        "전압강하 계산 입력(길이·전류·전압은 0 초과, 역률은 0~1)이 올바르지 않습니다.",
+        "Invalid voltage drop input (length, current and voltage must be > 0, power factor 0-1).",
+    ),
+    (
+        ERR_INVALID_TRANSFORMER,
+        "변압기 용량(kVA)과 임피던스(%)는 0보다 커야 합니다.",
+        "Transformer rating (kVA) and impedance (%) must be greater than 0.",
+    ),
+    (
+        ERR_UNKNOWN_CABLE_TYPE,
+        "지원하지 않는 전선 종류입니다.",
+        "Unsupported cable type.",
+    ),
+    (
+        ERR_BINARY_VERSION_MISMATCH,
+        "바이너리 데이터의 스키마 버전이 현재 버전과 다릅니다.",
+        "Binary data schema version does not match the current version.",
+    ),
+    (
+        ERR_BINARY_DECODE,
+        "바이너리 데이터를 해석할 수 없습니다.",
+        "Failed to decode binary data.",
+    ),
+    (
+        ERR_PROJECT_CORRUPT,
+        "프로젝트 파일이 손상되었거나 형식이 올바르지 않습니다.",
+        "Project file is corrupted or malformed.",
+    ),
+    (
+        ERR_PROJECT_VERSION_UNSUPPORTED,
+        "지원하지 않는 프로젝트 파일 버전입니다.",
+        "Unsupported project file version.",
+    ),
+    (
+        ERR_INVALID_LOAD_POWER,
+        "부하 전력(kW)과 전압(V)은 0보다 커야 합니다.",
+        "Load power (kW) and voltage (V) must be greater than 0.",
+    ),
+    (
+        ERR_UNKNOWN_ZONE,
+        "알 수 없는 설치 구역입니다. (가능: 일반, 방재, 피난, 비상전원)",
+        "Unknown installation zone. (valid: 일반, 방재, 피난, 비상전원)",
+    ),
+    (
+        ERR_INVALID_BRANCH_LOAD,
+        "분기회로 부하전류는 0 이상이어야 합니다.",
+        "Branch load currents must be 0 or greater.",
+    ),
+    (
+        ERR_UNKNOWN_REGULATION,
+        "알 수 없는 적용 규정입니다. (가능: KEC, IEC, NEC)",
+        "Unknown regulation. (valid: KEC, IEC, NEC)",
+    ),
+    (
+        ERR_INVALID_FILL_LIMIT,
+        "점유율 한계는 0 초과 1 이하의 비율이어야 합니다.",
+        "Fill limit must be a ratio greater than 0 and at most 1.",
+    ),
+    (
+        ERR_CABLE_EXCEEDS_CONDUIT,
+        "점유율 한계 내에서 최대 전선관에도 들어가지 않는 케이블입니다.",
+        "Cable does not fit even the largest conduit within the fill limit.",
+    ),
+    (
+        ERR_CIRCUIT_COUNT_NOT_EXACT,
+        "단심 가닥수가 회로당 가닥수(단상 2, 3상 3)로 나누어떨어지지 않습니다.",
+        "Single-core quantity is not a multiple of conductors per circuit (2 for 1Φ, 3 for 3Φ).",
+    ),
+    (
+        ERR_INVALID_MATERIAL,
+        "알 수 없는 도체 재질입니다. (가능: Cu, Al)",
+        "Unknown conductor material. (valid: Cu, Al)",
+    ),
+    (
+        ERR_INVALID_DURATION,
+        "단락 지속시간은 0초 초과 5초 이하여야 합니다.",
+        "Short-circuit duration must be greater than 0 and at most 5 seconds.",
+    ),
+    (
+        ERR_UNKNOWN_INSTALLATION_TYPE,
+        "알 수 없는 설비 유형입니다. (가능: 저압수전 조명, 저압수전 기타, 고압수전 조명, 고압수전 기타)",
+        "Unknown installation type. (valid: 저압수전 조명, 저압수전 기타, 고압수전 조명, 고압수전 기타)",
+    ),
+    (
+        ERR_INVALID_CHUNK_SIZE,
+        "청크 크기는 1 이상이어야 합니다.",
+        "Chunk size must be at least 1.",
+    ),
+    (
+        ERR_UNKNOWN_USAGE,
+        "알 수 없는 용도입니다. (가능: 동력 간선, 동력 분기, 전등 간선, 전등 분기, 제어)",
+        "Unknown usage. (valid: 동력 간선, 동력 분기, 전등 간선, 전등 분기, 제어)",
+    ),
+    (
+        ERR_BELOW_MINIMUM_SIZE,
+        "용도별 최소 규격보다 작은 규격입니다.",
+        "Size is below the minimum allowed for this usage.",
+    ),
+    (
+        ERR_INVALID_TERMINATION_TEMP,
+        "단자 온도 등급은 60°C 이상이어야 합니다.",
+        "Termination temperature rating must be at least 60°C.",
+    ),
+    (
+        ERR_INVALID_ENTRY_TYPE,
+        "알 수 없는 인입 방향입니다. (가능: 직선, 직각)",
+        "Unknown entry type. (valid: straight, angle)",
+    ),
+    (
+        ERR_EMPTY_CABLE_LIST,
+        "케이블 목록이 비어 있습니다.",
+        "Cable list is empty.",
+    ),
+    (
+        ERR_AMBIENT_TEMP_OUT_OF_RANGE,
+        "주변온도가 온도 보정계수 표의 범위를 벗어났습니다.",
+        "Ambient temperature is outside the correction factor table range.",
+    ),
+    (
+        ERR_INVALID_LOAD_CURRENT,
+        "부하전류는 0보다 커야 합니다.",
+        "Load current must be greater than 0.",
+    ),
+    (
+        ERR_PARALLEL_CONDUCTORS_REQUIRED,
+        "최대 규격으로도 부하전류를 감당할 수 없어 병렬 도체가 필요합니다.",
+        "Parallel conductors are required: no single size can carry the load current.",
+    ),
+    (
+        ERR_INVALID_FAULT_CURRENT,
+        "단락전류는 0보다 커야 합니다.",
+        "Fault current must be greater than 0.",
+    ),
+    (
+        ERR_EXPORT_LENGTH_MISMATCH,
+        "입력 항목 수와 계산 결과 수가 다릅니다.",
+        "The number of inputs does not match the number of results.",
+    ),
+    (
+        ERR_INVALID_BURIAL_DEPTH,
+        "매설 깊이는 0보다 큰 값이어야 합니다.",
+        "Burial depth must be greater than zero.",
+    ),
+    (
+        ERR_INVALID_SOIL_RESISTIVITY,
+        "토양 열저항률은 0보다 큰 값이어야 합니다.",
+        "Soil thermal resistivity must be greater than zero.",
+    ),
+    (
+        ERR_INVALID_HARMONIC_PERCENT,
+        "3고조파 함유율은 0~100% 범위여야 합니다.",
+        "Third harmonic content must be between 0 and 100%.",
+    ),
+    (
+        ERR_INVALID_PARALLEL_RUNS,
+        "병렬 런 수는 1 이상이어야 합니다.",
+        "Parallel runs must be at least 1.",
+    ),
+    (
+        ERR_INVALID_QUANTITY,
+        "수량은 1 이상이어야 합니다.",
+        "Quantity must be at least 1.",
+    ),
+    (
+        ERR_INVALID_CORES,
+        "지원하지 않는 가닥수입니다. (가능: 1C, 2C, 3C, 4C)",
+        "Unknown core count. (valid: 1C, 2C, 3C, 4C)",
+    ),
+    (
+        ERR_INSTALL_METHOD_CORES_MISMATCH,
+        "가닥수에 맞지 않는 공사방법입니다. (단심: A1, B1, C, D1, E / 다심: A2, B2, C, D1, D2, F)",
+        "Installation method does not match the core count. (single-core: A1, B1, C, D1, E / multi-core: A2, B2, C, D1, D2, F)",
+    ),
+    (
+        ERR_CABLE_UNDERSIZED_FOR_BREAKER,
+        "전선 규격 상향 필요",
+        "Cable size must be increased",
+    ),
+    (
+        ERR_INVALID_OUTER_DIAMETER,
+        "케이블 외경은 0보다 커야 합니다.",
+        "Cable outer diameter must be greater than zero.",
+    ),
+    (
+        ERR_CORES_IN_DC,
+        "직류(DC) 회로에는 1C 또는 2C 케이블만 사용할 수 있습니다.",
+        "Only 1C or 2C cables can be used in DC circuits.",
+    ),
+    (
+        ERR_TRANSFORMER_IN_DC,
+        "직류(DC) 회로에는 변압기 전압강하를 적용할 수 없습니다.",
+        "A transformer voltage drop cannot be applied to a DC circuit.",
+    ),
+    (
+        ERR_UNKNOWN_CONDUIT,
+        "알 수 없는 전선관 규격입니다.",
+        "Unknown conduit size.",
+    ),
+    (
+        ERR_INVALID_PULL_SECTION,
+        "포설 경로 구간이 올바르지 않습니다. (길이 0 이상, 굴곡 0~360°, 마찰계수 0 초과)",
+        "Invalid pulling route section. (length >= 0, bend 0-360°, friction > 0)",
+    ),
+];
+
+/// 에러 키를 언어별 메시지로 변환 (ko/en, 그 외 언어는 영어로 폴백)
+/// 등록되지 않은 키는 키 문자열을 그대로 반환
+pub fn localize_error(error_key: &str, lang: &str) -> String {
+    let message = match ERROR_MESSAGES.iter().find(|(key, _, _)| *key == error_key) {
+        Some((_, ko, _)) if lang == "ko" => ko,
+        Some((_, _, en)) => en,
+        None => return error_key.to_string(),
+    };
+    message.replace(SIZES_PLACEHOLDER, &STANDARD_SIZES.join(", "))
+}
+
+/// 에러 코드 enum 정의: 변형 ↔ 에러 키 1:1 대응, 직렬화 값은 에러 키 문자열
+macro_rules! calc_error_codes {
+    ($($variant:ident => $key:ident,)*) => {
+        /// 계산 에러 코드 (프론트엔드 분기용, JSON 값은 에러 키 문자열 예: "unsupported_size")
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum CalcErrorCode {
+            $($variant,)*
+            Unknown, // 등록되지 않은 키
+        }
+
+        impl CalcErrorCode {
+            /// 에러 키 문자열
+            pub fn key(self) -> &'static str {
+                match self {
+                    $(CalcErrorCode::$variant => $key,)*
+                    CalcErrorCode::Unknown => "unknown",
+                }
+            }
+
+            /// 에러 키에 대응하는 코드 (등록되지 않은 키는 Unknown)
+            pub fn from_key(key: &str) -> Self {
+                match key {
+                    $(k if k == $key => CalcErrorCode::$variant,)*
+                    _ => CalcErrorCode::Unknown,
+                }
+            }
+        }
+    };
+}
+
+calc_error_codes! {
+    Cores2CInThreePhase => ERR_2C_IN_THREE_PHASE,
+    Cores4CInSinglePhase => ERR_4C_IN_SINGLE_PHASE,
+    UnsupportedSize => ERR_UNSUPPORTED_SIZE,
+    CurrentDataNotFound => ERR_CURRENT_DATA_NOT_FOUND,
+    InvalidVerticalLength => ERR_INVALID_VERTICAL_LENGTH,
+    InvalidSize => ERR_INVALID_SIZE,
+    InvalidInsulation => ERR_INVALID_INSULATION,
+    InvalidInstallMethod => ERR_INVALID_INSTALL_METHOD,
+    InvalidLoadedConductors => ERR_INVALID_LOADED_CONDUCTORS,
+    InvalidUnitSystem => ERR_INVALID_UNIT_SYSTEM,
+    InvalidAwg => ERR_INVALID_AWG,
+    InvalidCurrentTable => ERR_INVALID_CURRENT_TABLE,
+    UnknownDataVersion => ERR_UNKNOWN_DATA_VERSION,
+    InvalidTrayWidth => ERR_INVALID_TRAY_WIDTH,
+    CableExceedsTray => ERR_CABLE_EXCEEDS_TRAY,
+    InvalidVoltageDropInput => ERR_INVALID_VOLTAGE_DROP_INPUT,
+    InvalidTransformer => ERR_INVALID_TRANSFORMER,
+    UnknownCableType => ERR_UNKNOWN_CABLE_TYPE,
+    BinaryVersionMismatch => ERR_BINARY_VERSION_MISMATCH,
+    BinaryDecode => ERR_BINARY_DECODE,
+    ProjectCorrupt => ERR_PROJECT_CORRUPT,
+    ProjectVersionUnsupported => ERR_PROJECT_VERSION_UNSUPPORTED,
+    InvalidLoadPower => ERR_INVALID_LOAD_POWER,
+    UnknownZone => ERR_UNKNOWN_ZONE,
+    InvalidBranchLoad => ERR_INVALID_BRANCH_LOAD,
+    UnknownRegulation => ERR_UNKNOWN_REGULATION,
+    InvalidFillLimit => ERR_INVALID_FILL_LIMIT,
+    CableExceedsConduit => ERR_CABLE_EXCEEDS_CONDUIT,
+    CircuitCountNotExact => ERR_CIRCUIT_COUNT_NOT_EXACT,
+    InvalidMaterial => ERR_INVALID_MATERIAL,
+    InvalidDuration => ERR_INVALID_DURATION,
+    UnknownInstallationType => ERR_UNKNOWN_INSTALLATION_TYPE,
+    InvalidChunkSize => ERR_INVALID_CHUNK_SIZE,
+    UnknownUsage => ERR_UNKNOWN_USAGE,
+    InvalidTerminationTemp => ERR_INVALID_TERMINATION_TEMP,
+    BelowMinimumSize => ERR_BELOW_MINIMUM_SIZE,
+    InvalidEntryType => ERR_INVALID_ENTRY_TYPE,
+    EmptyCableList => ERR_EMPTY_CABLE_LIST,
+    AmbientTempOutOfRange => ERR_AMBIENT_TEMP_OUT_OF_RANGE,
+    InvalidLoadCurrent => ERR_INVALID_LOAD_CURRENT,
+    ParallelConductorsRequired => ERR_PARALLEL_CONDUCTORS_REQUIRED,
+    InvalidFaultCurrent => ERR_INVALID_FAULT_CURRENT,
+    ExportLengthMismatch => ERR_EXPORT_LENGTH_MISMATCH,
+    InvalidBurialDepth => ERR_INVALID_BURIAL_DEPTH,
+    InvalidSoilResistivity => ERR_INVALID_SOIL_RESISTIVITY,
+    InvalidHarmonicPercent => ERR_INVALID_HARMONIC_PERCENT,
+    InvalidParallelRuns => ERR_INVALID_PARALLEL_RUNS,
+    InvalidQuantity => ERR_INVALID_QUANTITY,
+    InvalidCores => ERR_INVALID_CORES,
+    InstallMethodCoresMismatch => ERR_INSTALL_METHOD_CORES_MISMATCH,
+    CableUndersizedForBreaker => ERR_CABLE_UNDERSIZED_FOR_BREAKER,
+    InvalidOuterDiameter => ERR_INVALID_OUTER_DIAMETER,
+    CoresInDc => ERR_CORES_IN_DC,
+    TransformerInDc => ERR_TRANSFORMER_IN_DC,
+    UnknownConduit => ERR_UNKNOWN_CONDUIT,
+    InvalidPullSection => ERR_INVALID_PULL_SECTION,
+}
+
+impl Serialize for CalcErrorCode {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.key())
+    }
+}
+
+impl<'de> Deserialize<'de> for CalcErrorCode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(CalcErrorCode::from_key(&String::deserialize(deserializer)?))
+    }
+}
+
+/// 구조화된 계산 에러: JSON 형태 {code, message}
+/// message는 입력 언어로 지역화된 메시지 (기존 String 에러와 같은 문구)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CalcError {
+    pub code: CalcErrorCode,
+    pub message: String,
+}
+
+impl CalcError {
+    /// 에러 키와 언어로 생성
+    pub fn new(key: &str, lang: &str) -> Self {
+        CalcError { code: CalcErrorCode::from_key(key), message: localize_error(key, lang) }
+    }
+}
+
+impl std::fmt::Display for CalcError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for CalcError {}
+
+/// 입력의 에러 메시지 언어 (미지정 시 한국어)
+fn error_lang(data: &CableData) -> &str {
+    data.lang.as_deref().unwrap_or("ko")
+}
+
+/// 여러 회로 입력 전체에 대한 에러 메시지 언어 (첫 항목 기준, 비어 있으면 한국어)
+fn batch_lang(items: &[CableData]) -> &str {
+    items.first().map_or("ko", error_lang)
+}
+
+/// 표시 라벨 언어 (ko, en), 지원하지 않는 값은 ko로 폴백
+fn label_lang(lang: &str) -> &'static str {
+    match lang.trim() {
+        "en" => "en",
+        _ => "ko",
+    }
+}
+
+/// 단위 newtype 정의: 같은 단위끼리 덧셈·뺄셈, 무차원 배율 곱·나눗셈,
+/// 같은 단위끼리 나누면 무차원 비율(f64)
+macro_rules! unit_newtype {
+    ($(#[$meta:meta])* $name:ident, $unit:literal) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+        pub struct $name(pub f64);
+
+        impl $name {
+            /// 단위 없는 값 (Tauri·직렬화 경계용)
+            pub fn value(self) -> f64 {
+                self.0
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{} {}", self.0, $unit)
+            }
+        }
+
+        impl std::ops::Add for $name {
+            type Output = $name;
+            fn add(self, rhs: $name) -> $name {
+                $name(self.0 + rhs.0)
+            }
+        }
+
+        impl std::ops::Sub for $name {
+            type Output = $name;
+            fn sub(self, rhs: $name) -> $name {
+                $name(self.0 - rhs.0)
+            }
+        }
+
+        impl std::ops::AddAssign for $name {
+            fn add_assign(&mut self, rhs: $name) {
+                self.0 += rhs.0;
+            }
+        }
+
+        impl std::ops::Mul<f64> for $name {
+            type Output = $name;
+            fn mul(self, factor: f64) -> $name {
+                $name(self.0 * factor)
+            }
+        }
+
+        impl std::ops::Div<f64> for $name {
+            type Output = $name;
+            fn div(self, divisor: f64) -> $name {
+                $name(self.0 / divisor)
+            }
+        }
+
+        impl std::ops::Div for $name {
+            type Output = f64;
+            fn div(self, rhs: $name) -> f64 {
+                self.0 / rhs.0
+            }
+        }
+
+        impl std::iter::Sum for $name {
+            fn sum<I: Iterator<Item = $name>>(iter: I) -> $name {
+                $name(iter.map(|v| v.0).sum())
+            }
+        }
+    };
+}
+
+unit_newtype!(
+    /// 단면적 (mm²)
+    Millimeters2, "mm²"
+);
+unit_newtype!(
+    /// 전류 (A)
+    ///
+    /// 차원이 다른 값끼리는 더할 수 없음:
+    /// ```compile_fail
+    /// use kec_calculator::{Amperes, Meters};
+    /// let _ = Amperes(10.0) + Meters(5.0);
+    /// ```
+    Amperes, "A"
+);
+unit_newtype!(
+    /// 길이 (m)
+    Meters, "m"
+);
+unit_newtype!(
+    /// 선로 길이당 저항·리액턴스 (Ω/km)
+    OhmsPerKm, "Ω/km"
+);
+unit_newtype!(
+    /// 저항·리액턴스 (Ω)
+    Ohms, "Ω"
+);
+unit_newtype!(
+    /// 전압 (V)
+    Volts, "V"
+);
+
+impl Millimeters2 {
+    /// 지름(mm) 원의 면적
+    pub fn circle(diameter_mm: f64) -> Millimeters2 {
+        Millimeters2(std::f64::consts::PI * (diameter_mm / 2.0).powi(2))
+    }
+}
+
+impl Meters {
+    pub fn from_feet(feet: f64) -> Meters {
+        Meters(feet * FEET_TO_METERS)
+    }
+
+    pub fn from_km(km: f64) -> Meters {
+        Meters(km * 1000.0)
+    }
+
+    pub fn km(self) -> f64 {
+        self.0 / 1000.0
+    }
+}
+
+/// Ω/km × m -> Ω (km↔m 변환은 여기서만)
+impl std::ops::Mul<Meters> for OhmsPerKm {
+    type Output = Ohms;
+    fn mul(self, length: Meters) -> Ohms {
+        Ohms(self.0 * length.km())
+    }
+}
+
+/// Ω × A -> V
+impl std::ops::Mul<Amperes> for Ohms {
+    type Output = Volts;
+    fn mul(self, current: Amperes) -> Volts {
+        Volts(self.0 * current.0)
+    }
+}
+
+/// 표준 전선 규격 (mm²)
+pub const STANDARD_SIZES: &[&str] = &[
+    "1.5", "2.5", "4", "6", "10", "16", "25", "35",
+    "50", "70", "95", "120", "150", "185", "240", "300", "400", "500",
+    "630", "800", "1000",
+];
+
+/// 검증된 표준 규격 (STANDARD_SIZES 중 하나)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Size(&'static str);
+
+impl Size {
+    pub fn as_str(self) -> &'static str {
+        self.0
+    }
+
+    /// 단면적 (mm²)
+    pub fn mm2(self) -> f64 {
+        self.0.parse().unwrap_or(0.0)
+    }
+}
+
+impl TryFrom<&str> for Size {
+    type Error = &'static str;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        STANDARD_SIZES
+            .iter()
+            .find(|s| **s == value)
+            .map(|s| Size(s))
+            .ok_or(ERR_INVALID_SIZE)
+    }
+}
+
+/// 단면적(mm²) 이상인 가장 작은 표준 규격 (최대 규격 초과 시 None)
+fn standard_size_at_least(area: f64) -> Option<Size> {
+    STANDARD_SIZES
+        .iter()
+        .find(|s| s.parse::<f64>().is_ok_and(|mm2| mm2 >= area))
+        .map(|s| Size(s))
+}
+
+/// 임의의 단면적(mm²)을 표준 규격으로 올림 (계산된 최소 단면적·보간 결과 표준화용)
+/// 표준 규격과 정확히 일치하면 그 규격, 최대 규격(1000mm²) 초과 시 None
+pub fn round_up_to_standard_size(area: f64) -> Option<String> {
+    standard_size_at_least(area).map(|size| size.as_str().to_string())
+}
+
+/// 절연체 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Insulation {
+    Pvc,   // 비닐 (70°C)
+    Xlpe,  // 가교 폴리에틸렌 (90°C)
+}
+
+impl Insulation {
+    pub const ALL: [Insulation; 2] = [Insulation::Pvc, Insulation::Xlpe];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Insulation::Pvc => "PVC",
+            Insulation::Xlpe => "XLPE",
+        }
+    }
+}
+
+impl TryFrom<&str> for Insulation {
+    type Error = &'static str;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Insulation::ALL
+            .into_iter()
+            .find(|i| i.as_str() == value)
+            .ok_or(ERR_INVALID_INSULATION)
+    }
+}
+
+/// 도체 재질
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Material {
+    Copper,     // 동
+    Aluminium,  // 알루미늄
+}
+
+impl Material {
+    pub const ALL: [Material; 2] = [Material::Copper, Material::Aluminium];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Material::Copper => "Cu",
+            Material::Aluminium => "Al",
+        }
+    }
+}
+
+impl TryFrom<&str> for Material {
+    type Error = &'static str;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "동" => return Ok(Material::Copper),
+            "알루미늄" => return Ok(Material::Aluminium),
+            _ => {}
+        }
+        Material::ALL
+            .into_iter()
+            .find(|m| m.as_str().eq_ignore_ascii_case(value))
+            .ok_or(ERR_INVALID_MATERIAL)
+    }
+}
+
+/// 공사방법 (KEC / IEC 60364-5-52 Table B.52.1)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InstallMethod {
+    A1,
+    A2,
+    B1,
+    B2,
+    C,
+    D1,
+    D2,
+    E,
+    F,
+}
+
+impl InstallMethod {
+    pub const ALL: [InstallMethod; 9] = [
+        InstallMethod::A1,
+        InstallMethod::A2,
+        InstallMethod::B1,
+        InstallMethod::B2,
+        InstallMethod::C,
+        InstallMethod::D1,
+        InstallMethod::D2,
+        InstallMethod::E,
+        InstallMethod::F,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            InstallMethod::A1 => "A1",
+            InstallMethod::A2 => "A2",
+            InstallMethod::B1 => "B1",
+            InstallMethod::B2 => "B2",
+            InstallMethod::C => "C",
+            InstallMethod::D1 => "D1",
+            InstallMethod::D2 => "D2",
+            InstallMethod::E => "E",
+            InstallMethod::F => "F",
+        }
+    }
+
+    /// 선택 목록 표시명 (예: "A1: 단열벽 속 전선관 (단심)"), 코드는 언어 무관
+    pub fn label(self, lang: &str) -> String {
+        let name = match (self, label_lang(lang)) {
+            (InstallMethod::A1, "en") => "Conduit in thermally insulated wall (single-core)",
+            (InstallMethod::A2, "en") => "Conduit in thermally insulated wall (multi-core)",
+            (InstallMethod::B1, "en") => "Conduit on wall (single-core)",
+            (InstallMethod::B2, "en") => "Conduit on wall (multi-core)",
+            (InstallMethod::C, "en") => "Clipped direct to wall/ceiling",
+            (InstallMethod::D1, "en") => "Underground duct",
+            (InstallMethod::D2, "en") => "Direct buried",
+            (InstallMethod::E, "en") => "Cable tray (single-core)",
+            (InstallMethod::F, "en") => "Cable tray (multi-core)",
+            (InstallMethod::A1, _) => "단열벽 속 전선관 (단심)",
+            (InstallMethod::A2, _) => "단열벽 속 전선관 (다심)",
+            (InstallMethod::B1, _) => "벽면 고정 전선관 (단심)",
+            (InstallMethod::B2, _) => "벽면 고정 전선관 (다심)",
+            (InstallMethod::C, _) => "벽면/천정 직접 고정",
+            (InstallMethod::D1, _) => "지중 매설 덕트",
+            (InstallMethod::D2, _) => "지중 매설 직매",
+            (InstallMethod::E, _) => "케이블 트레이 (단심)",
+            (InstallMethod::F, _) => "케이블 트레이 (다심)",
+        };
+        format!("{}: {}", self.as_str(), name)
+    }
+
+    /// 지중 공사방법 여부 (D1/D2, 기준온도 20°C)
+    pub fn is_buried(self) -> bool {
+        matches!(self, InstallMethod::D1 | InstallMethod::D2)
+    }
+
+    /// 가닥수와 맞는 공사방법인지 (install_methods_for_cores 목록 기준)
+    pub fn supports_cores(self, cores: &str) -> bool {
+        install_methods_for_cores(cores).contains(&self)
+    }
+}
+
+impl TryFrom<&str> for InstallMethod {
+    type Error = &'static str;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        InstallMethod::ALL
+            .into_iter()
+            .find(|m| m.as_str() == value)
+            .ok_or(ERR_INVALID_INSTALL_METHOD)
+    }
+}
+
+/// 적용 규정
+/// KEC와 IEC는 같은 IEC 60364-5-52 허용전류 테이블을 쓰고 점유율·접지선 규칙만 다름.
+/// NEC는 AWG 기준 ampacity 테이블(310.16)을 별도 경로로 조회
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Regulation {
+    Kec,
+    Iec,
+    Nec,
+}
+
+impl Regulation {
+    pub const ALL: [Regulation; 3] = [Regulation::Kec, Regulation::Iec, Regulation::Nec];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Regulation::Kec => "KEC",
+            Regulation::Iec => "IEC",
+            Regulation::Nec => "NEC",
+        }
+    }
+
+    /// 전선관 점유율 상한 (접지선을 포함한 관 내 전선 본수 기준)
+    fn conduit_fill_limit(self, cable_count: u32) -> f64 {
+        match self {
+            // KEC(내선규정)와 NEC Chapter 9 Table 1 모두 본수별 상한 적용
+            Regulation::Kec | Regulation::Nec => conduit_fill_limit_by_count(cable_count),
+            // IEC 60364는 점유율 상한을 규정하지 않아 실무 통용값 40% 적용
+            Regulation::Iec => 0.4,
+        }
+    }
+}
+
+impl TryFrom<&str> for Regulation {
+    type Error = &'static str;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Regulation::ALL
+            .into_iter()
+            .find(|r| r.as_str().eq_ignore_ascii_case(value))
+            .ok_or(ERR_UNKNOWN_REGULATION)
+    }
+}
+
+/// 입력의 적용 규정 (미지정 시 KEC)
+fn resolve_regulation(data: &CableData) -> Result<Regulation, &'static str> {
+    match data.regulation.as_deref().map(str::trim) {
+        None | Some("") => Ok(Regulation::Kec),
+        Some(value) => Regulation::try_from(value),
+    }
+}
+
+/// 알루미늄 상도체에 동 보호도체를 쓸 때의 단면적 환산 계수 k1/k2 (Al 94 / Cu 143, XLPE 기준)
+const ALUMINIUM_TO_COPPER_PE_RATIO: f64 = 94.0 / 143.0;
+
+/// KEC 542.3 (IEC 60364-5-54 Table 54.2) 보호도체(접지선) 최소 단면적 (표준 mm² 규격)
+/// 상도체 S ≤ 16 -> S, 16 < S ≤ 35 -> 16, S > 35 -> S/2, 표준 규격이 아니면 올림.
+/// 보호도체는 동(HFIX)이므로 알루미늄 상도체는 k1/k2를 곱해 환산.
+/// NEC 250.122는 과전류 차단기 정격 기준이라 입력만으로 정할 수 없어 모든 규정에 이 표를 적용.
+/// 규격·재질을 해석할 수 없으면 빈 문자열
+pub fn get_protective_conductor_size(phase_size: &str, material: &str) -> &'static str {
+    let (Ok(phase), Ok(material)) = (phase_size.trim().parse::<f64>(), Material::try_from(material.trim())) else {
+        return "";
+    };
+    let required = match phase {
+        mm2 if mm2 <= 16.0 => mm2,
+        mm2 if mm2 <= 35.0 => 16.0,
+        mm2 => mm2 / 2.0,
+    };
+    let required = match material {
+        Material::Copper => required,
+        Material::Aluminium => required * ALUMINIUM_TO_COPPER_PE_RATIO,
+    };
+    standard_size_at_least(required).map_or("", Size::as_str)
+}
+
+/// 입력의 접지선 규격 (HFIX 접지 시, 그 외 None)
+fn protective_conductor_size(data: &CableData) -> Result<Option<&'static str>, &'static str> {
+    if data.ground_wire != "HFIX" {
+        return Ok(None);
+    }
+    Ok(Some(get_protective_conductor_size(&data.size, resolve_material(data)?.as_str())))
+}
+
+/// 입력의 도체 재질 (미지정·빈 값이면 동)
+fn resolve_material(data: &CableData) -> Result<Material, &'static str> {
+    material_or_copper(data.conductor_material.as_deref())
+}
+
+/// 도체 재질 문자열 해석 (미지정·빈 값이면 동)
+fn material_or_copper(value: Option<&str>) -> Result<Material, &'static str> {
+    match value.map(str::trim) {
+        None | Some("") => Ok(Material::Copper),
+        Some(value) => Material::try_from(value),
+    }
+}
+
+/// KEC IEC 60364-5-52 기준 허용전류 데이터 (2부하/3부하 도체)
+/// Table B.52.2/B.52.4 (PVC 70°C, 2부하/3부하), Table B.52.3/B.52.5 (XLPE 90°C, 2부하/3부하)
+/// Table B.52.10/B.52.12 (PVC/XLPE 동 도체, E/F 자유 공기 중)
+/// Return: (2 loaded current, 3 loaded current)
+/// 최초 조회 시 한 번만 구성 (반복 계산·역산 루프에서 재생성 비용 제거)
+fn get_allowable_current_table() -> &'static CurrentTable {
+    static TABLE: OnceLock<CurrentTable> = OnceLock::new();
+    TABLE.get_or_init(build_allowable_current_table)
+}
+
+/// 절연체·공사방법·부하 도체 수 조합의 허용전류 출처 표 (검측·감리 제출용 근거)
+/// A1~D2는 부하 도체 수별 표(2부하 B.52.2/B.52.3, 3부하 B.52.4/B.52.5),
+/// E/F는 절연체별 동 도체 표(B.52.10 PVC, B.52.12 XLPE)에 2·3부하 열이 함께 수록
+pub fn get_table_reference(insulation: Insulation, method: InstallMethod, loaded_conductors: u8) -> &'static str {
+    let free_air = matches!(method, InstallMethod::E | InstallMethod::F);
+    match (insulation, free_air, loaded_conductors) {
+        (Insulation::Pvc, true, _) => "IEC 60364-5-52 Table B.52.10 / KEC 232",
+        (Insulation::Xlpe, true, _) => "IEC 60364-5-52 Table B.52.12 / KEC 232",
+        (Insulation::Pvc, false, 3) => "IEC 60364-5-52 Table B.52.4 / KEC 232",
+        (Insulation::Xlpe, false, 3) => "IEC 60364-5-52 Table B.52.5 / KEC 232",
+        (Insulation::Pvc, false, _) => "IEC 60364-5-52 Table B.52.2 / KEC 232",
+        (Insulation::Xlpe, false, _) => "IEC 60364-5-52 Table B.52.3 / KEC 232",
+    }
+}
+
+/// 알루미늄 도체 허용전류 출처 표
+/// A1~D2는 동과 같은 표의 알루미늄 열, E/F는 별도 표(B.52.11 PVC, B.52.13 XLPE)
+fn aluminium_table_reference(insulation: Insulation, method: InstallMethod, loaded_conductors: u8) -> &'static str {
+    match (insulation, method) {
+        (Insulation::Pvc, InstallMethod::E | InstallMethod::F) => "IEC 60364-5-52 Table B.52.11 / KEC 232",
+        (Insulation::Xlpe, InstallMethod::E | InstallMethod::F) => "IEC 60364-5-52 Table B.52.13 / KEC 232",
+        _ => get_table_reference(insulation, method, loaded_conductors),
+    }
+}
+
+/// 적용 규정의 허용전류 출처 표 (NEC는 310.16)
+/// 사용자 정의 항목(CUSTOM_CURRENT_TABLE)은 출처를 구분해 표시, 알루미늄은 알루미늄 열·표로 표시
+fn result_reference(data: &CableData, install_method: InstallMethod, regulation: Regulation) -> String {
+    let insulation = get_insulation_type(&data.cable_type);
+    let loaded_conductors = determine_loaded_conductors(&data.cores, &data.system);
+    let mut reference = match regulation {
+        Regulation::Nec => "NEC 310.16".to_string(),
+        _ => get_table_reference(insulation, install_method, loaded_conductors).to_string(),
+    };
+    let en = label_lang(error_lang(data)) == "en";
+    let material = resolve_material(data);
+    let custom = regulation != Regulation::Nec
+        && material == Ok(Material::Copper)
+        && Size::try_from(data.size.as_str()).is_ok_and(|size| is_custom_current_entry(size, insulation, install_method));
+    if custom {
+        reference = if en {
+            format!("Custom ampacity table (replaces {})", reference)
+        } else {
+            format!("사용자 정의 허용전류 테이블 ({} 대체)", reference)
+        };
+    }
+    if material == Ok(Material::Aluminium) && regulation != Regulation::Nec {
+        reference = aluminium_table_reference(insulation, install_method, loaded_conductors).to_string();
+        reference.push_str(if en { " (aluminium)" } else { " (알루미늄)" });
+    }
+    reference
+}
+
+/// 내장 허용전류 테이블 구성
+/// 630/800/1000mm²는 IEC가 단심 자유 공기 중(Table B.52.10/B.52.12 단심 열)만 수록하므로
+/// 단심 트레이(E)에만 수록 — 그 외 공사방법·다심 조합은 표에 없어 미지원
+fn build_allowable_current_table() -> CurrentTable {
+    let mut table = HashMap::new();
+    
+    // ============================================================
+    // PVC 절연 (70°C) - IEC 60364-5-52 Table B.52.2 (2부하) / B.52.4 (3부하)
+    // 주변온도 30°C (공기) / 20°C (지중) 기준
+    // ============================================================
+    
+    // A1: 단열벽 속 전선관 (단심) - Table B.52.4 Column 2/3
+    let pvc_a1 = [
+        ("1.5", 14.5, 13.5), ("2.5", 19.5, 18.0), ("4", 26.0, 24.0), ("6", 34.0, 31.0),
+        ("10", 46.0, 42.0), ("16", 61.0, 56.0), ("25", 80.0, 73.0), ("35", 99.0, 89.0),
+        ("50", 119.0, 108.0), ("70", 151.0, 136.0), ("95", 182.0, 164.0), ("120", 210.0, 188.0),
+        ("150", 240.0, 216.0), ("185", 273.0, 245.0), ("240", 321.0, 286.0), ("300", 367.0, 328.0),
+        ("400", 424.0, 379.0), ("500", 488.0, 436.0),
+    ];
+    for (size, c2, c3) in pvc_a1.iter() {
+        table.insert((Size(size), Insulation::Pvc, InstallMethod::A1), (*c2, *c3));
+    }
+    
+    // A2: 단열벽 속 전선관 (다심) - Table B.52.4 Column 4/5
+    let pvc_a2 = [
+        ("1.5", 14.0, 13.0), ("2.5", 18.5, 17.5), ("4", 25.0, 23.0), ("6", 32.0, 29.0),
+        ("10", 43.0, 39.0), ("16", 57.0, 52.0), ("25", 75.0, 68.0), ("35", 92.0, 83.0),
+        ("50", 110.0, 99.0), ("70", 139.0, 125.0), ("95", 167.0, 150.0), ("120", 192.0, 172.0),
+        ("150", 219.0, 196.0), ("185", 248.0, 223.0), ("240", 291.0, 261.0), ("300", 334.0, 298.0),
+        ("400", 386.0, 345.0), ("500", 444.0, 397.0),
+    ];
+    for (size, c2, c3) in pvc_a2.iter() {
+        table.insert((Size(size), Insulation::Pvc, InstallMethod::A2), (*c2, *c3));
+    }
+
+    // B1: 벽면 고정 전선관 (단심) - Table B.52.4 Column 6/7
+    let pvc_b1 = [
+        ("1.5", 17.5, 15.5), ("2.5", 24.0, 21.0), ("4", 32.0, 28.0), ("6", 41.0, 36.0),
+        ("10", 57.0, 50.0), ("16", 76.0, 68.0), ("25", 101.0, 89.0), ("35", 125.0, 110.0),
+        ("50", 151.0, 134.0), ("70", 192.0, 171.0), ("95", 232.0, 207.0), ("120", 269.0, 239.0),
+        ("150", 309.0, 275.0), ("185", 353.0, 314.0), ("240", 415.0, 369.0), ("300", 477.0, 423.0),
+        ("400", 555.0, 490.0), ("500", 642.0, 565.0),
+    ];
+    for (size, c2, c3) in pvc_b1.iter() {
+        table.insert((Size(size), Insulation::Pvc, InstallMethod::B1), (*c2, *c3));
+    }
+
+    // B2: 벽면 고정 전선관 (다심) - Table B.52.4 Column 8/9
+    let pvc_b2 = [
+        ("1.5", 16.5, 15.0), ("2.5", 23.0, 20.0), ("4", 30.0, 27.0), ("6", 38.0, 34.0),
+        ("10", 52.0, 46.0), ("16", 69.0, 62.0), ("25", 90.0, 80.0), ("35", 111.0, 99.0),
+        ("50", 133.0, 118.0), ("70", 168.0, 149.0), ("95", 201.0, 179.0), ("120", 232.0, 206.0),
+        ("150", 265.0, 236.0), ("185", 300.0, 268.0), ("240", 351.0, 313.0), ("300", 401.0, 358.0),
+        ("400", 464.0, 414.0), ("500", 533.0, 476.0),
+    ];
+    for (size, c2, c3) in pvc_b2.iter() {
+        table.insert((Size(size), Insulation::Pvc, InstallMethod::B2), (*c2, *c3));
+    }
+
+    // C: 벽면 직접 고정 - Table B.52.4 Column 10/11
+    let pvc_c = [
+        ("1.5", 19.5, 17.5), ("2.5", 27.0, 24.0), ("4", 36.0, 32.0), ("6", 46.0, 41.0),
+        ("10", 63.0, 57.0), ("16", 85.0, 76.0), ("25", 112.0, 96.0), ("35", 138.0, 119.0),
+        ("50", 168.0, 144.0), ("70", 213.0, 184.0), ("95", 258.0, 223.0), ("120", 299.0, 259.0),
+        ("150", 344.0, 299.0), ("185", 392.0, 341.0), ("240", 461.0, 403.0), ("300", 530.0, 464.0),
+        ("400", 614.0, 545.0), ("500", 707.0, 638.0),
+    ];
+    for (size, c2, c3) in pvc_c.iter() {
+        table.insert((Size(size), Insulation::Pvc, InstallMethod::C), (*c2, *c3));
+    }
+
+    // D1: 지중 덕트 - Table B.52.4 Column 12/13
+    let pvc_d1 = [
+        ("1.5", 22.0, 18.0), ("2.5", 29.0, 24.0), ("4", 37.0, 30.0), ("6", 46.0, 38.0),
+        ("10", 61.0, 50.0), ("16", 79.0, 64.0), ("25", 101.0, 82.0), ("35", 122.0, 98.0),
+        ("50", 144.0, 116.0), ("70", 178.0, 143.0), ("95", 211.0, 169.0), ("120", 240.0, 192.0),
+        ("150", 271.0, 217.0), ("185", 304.0, 243.0), ("240", 351.0, 280.0), ("300", 396.0, 316.0),
+        ("400", 454.0, 363.0), ("500", 513.0, 410.0),
+    ];
+    for (size, c2, c3) in pvc_d1.iter() {
+        table.insert((Size(size), Insulation::Pvc, InstallMethod::D1), (*c2, *c3));
+    }
+
+    // D2: 지중 직매 - Table B.52.4 Column 14/15
+    let pvc_d2 = [
+        ("1.5", 24.0, 19.0), ("2.5", 32.0, 24.0), ("4", 41.0, 33.0), ("6", 51.0, 41.0),
+        ("10", 67.0, 54.0), ("16", 87.0, 70.0), ("25", 112.0, 92.0), ("35", 136.0, 110.0),
+        ("50", 161.0, 130.0), ("70", 200.0, 162.0), ("95", 239.0, 193.0), ("120", 273.0, 220.0),
+        ("150", 310.0, 246.0), ("185", 349.0, 278.0), ("240", 404.0, 320.0), ("300", 458.0, 359.0),
+        ("400", 524.0, 414.0), ("500", 590.0, 467.0),
+    ];
+    for (size, c2, c3) in pvc_d2.iter() {
+        table.insert((Size(size), Insulation::Pvc, InstallMethod::D2), (*c2, *c3));
+    }
+
+    // E: 케이블 트레이 다심 (자유 공기 중) - Table B.52.10
+    let pvc_e = [
+        ("1.5", 22.0, 18.5), ("2.5", 30.0, 25.0), ("4", 40.0, 34.0), ("6", 51.0, 43.0),
+        ("10", 70.0, 60.0), ("16", 94.0, 80.0), ("25", 119.0, 101.0), ("35", 148.0, 126.0),
+        ("50", 180.0, 153.0), ("70", 232.0, 196.0), ("95", 282.0, 238.0), ("120", 328.0, 276.0),
+        ("150", 379.0, 319.0), ("185", 434.0, 364.0), ("240", 514.0, 430.0), ("300", 593.0, 497.0),
+        ("400", 694.0, 592.0), ("500", 806.0, 706.0),
+    ];
+    for (size, c2, c3) in pvc_e.iter() {
+        table.insert((Size(size), Insulation::Pvc, InstallMethod::E), (*c2, *c3));
+    }
+    // E 단심 630~1000mm² - Table B.52.10 단심 (2부하: 접촉 배치, 3부하: 삼각 배치)
+    let pvc_e_single_core = [
+        ("630", 1005.0, 855.0), ("800", 1169.0, 971.0), ("1000", 1346.0, 1079.0),
+    ];
+    for (size, c2, c3) in pvc_e_single_core.iter() {
+        table.insert((Size(size), Insulation::Pvc, InstallMethod::E), (*c2, *c3));
+    }
+    
+    // F: 케이블 트레이 단심 (접촉 배치) - Table B.52.10 (단심 Flat/Touching)
+    // 단심 케이블은 다심보다 10-15% 높은 허용전류
+    let pvc_f = [
+        ("1.5", 25.0, 21.0), ("2.5", 34.0, 28.0), ("4", 45.0, 38.0), ("6", 58.0, 48.0),
+        ("10", 79.0, 67.0), ("16", 105.0, 89.0), ("25", 133.0, 113.0), ("35", 166.0, 141.0),
+        ("50", 201.0, 171.0), ("70", 259.0, 219.0), ("95", 315.0, 266.0), ("120", 367.0, 309.0),
+        ("150", 424.0, 357.0), ("185", 486.0, 408.0), ("240", 575.0, 482.0), ("300", 664.0, 557.0),
+        ("400", 777.0, 664.0), ("500", 903.0, 791.0),
+    ];
+    for (size, c2, c3) in pvc_f.iter() {
+        table.insert((Size(size), Insulation::Pvc, InstallMethod::F), (*c2, *c3));
+    }
+
+    // ============================================================
+    // XLPE 절연 (90°C) - IEC 60364-5-52 Table B.52.3 (2부하) / B.52.5 (3부하)
+    // 주변온도 30°C (공기) / 20°C (지중) 기준
+    // ============================================================
+
+    // A1: 단열벽 속 전선관 (단심) - Table B.52.5 Column 2/3
+    let xlpe_a1 = [
+        ("1.5", 19.5, 17.0), ("2.5", 26.0, 23.0), ("4", 35.0, 31.0), ("6", 45.0, 40.0),
+        ("10", 61.0, 54.0), ("16", 81.0, 73.0), ("25", 106.0, 95.0), ("35", 131.0, 117.0),
+        ("50", 158.0, 141.0), ("70", 200.0, 179.0), ("95", 241.0, 216.0), ("120", 278.0, 249.0),
+        ("150", 318.0, 285.0), ("185", 362.0, 324.0), ("240", 424.0, 380.0), ("300", 486.0, 435.0),
+        ("400", 561.0, 503.0), ("500", 645.0, 578.0),
+    ];
+    for (size, c2, c3) in xlpe_a1.iter() {
+        table.insert((Size(size), Insulation::Xlpe, InstallMethod::A1), (*c2, *c3));
+    }
+    
+    // A2: 단열벽 속 전선관 (다심) - Table B.52.5 Column 4/5
+    let xlpe_a2 = [
+        ("1.5", 18.5, 16.5), ("2.5", 25.0, 22.0), ("4", 33.0, 30.0), ("6", 42.0, 38.0),
+        ("10", 57.0, 51.0), ("16", 76.0, 68.0), ("25", 99.0, 89.0), ("35", 121.0, 109.0),
+        ("50", 145.0, 130.0), ("70", 183.0, 164.0), ("95", 220.0, 197.0), ("120", 253.0, 227.0),
+        ("150", 290.0, 259.0), ("185", 329.0, 295.0), ("240", 386.0, 346.0), ("300", 442.0, 396.0),
+        ("400", 511.0, 458.0), ("500", 587.0, 526.0),
+    ];
+    for (size, c2, c3) in xlpe_a2.iter() {
+        table.insert((Size(size), Insulation::Xlpe, InstallMethod::A2), (*c2, *c3));
+    }
+
+    // B1: 벽면 고정 전선관 (단심) - Table B.52.5 Column 6/7
+    let xlpe_b1 = [
+        ("1.5", 23.0, 20.0), ("2.5", 31.0, 28.0), ("4", 42.0, 37.0), ("6", 54.0, 48.0),
+        ("10", 75.0, 66.0), ("16", 100.0, 88.0), ("25", 133.0, 117.0), ("35", 164.0, 144.0),
+        ("50", 198.0, 175.0), ("70", 253.0, 222.0), ("95", 306.0, 269.0), ("120", 354.0, 312.0),
+        ("150", 407.0, 358.0), ("185", 464.0, 408.0), ("240", 546.0, 481.0), ("300", 628.0, 553.0),
+        ("400", 732.0, 644.0), ("500", 846.0, 745.0),
+    ];
+    for (size, c2, c3) in xlpe_b1.iter() {
+        table.insert((Size(size), Insulation::Xlpe, InstallMethod::B1), (*c2, *c3));
+    }
+
+    // B2: 벽면 고정 전선관 (다심) - Table B.52.5 Column 8/9
+    let xlpe_b2 = [
+        ("1.5", 22.0, 19.5), ("2.5", 30.0, 27.0), ("4", 40.0, 35.0), ("6", 51.0, 45.0),
+        ("10", 69.0, 62.0), ("16", 91.0, 82.0), ("25", 119.0, 107.0), ("35", 146.0, 131.0),
+        ("50", 175.0, 158.0), ("70", 221.0, 200.0), ("95", 265.0, 240.0), ("120", 305.0, 276.0),
+        ("150", 349.0, 316.0), ("185", 395.0, 358.0), ("240", 462.0, 419.0), ("300", 528.0, 479.0),
+        ("400", 609.0, 553.0), ("500", 698.0, 635.0),
+    ];
+    for (size, c2, c3) in xlpe_b2.iter() {
+        table.insert((Size(size), Insulation::Xlpe, InstallMethod::B2), (*c2, *c3));
+    }
+
+    // C: 벽면 직접 고정 - Table B.52.5 Column 10/11
+    let xlpe_c = [
+        ("1.5", 24.0, 22.0), ("2.5", 33.0, 30.0), ("4", 45.0, 40.0), ("6", 58.0, 52.0),
+        ("10", 80.0, 71.0), ("16", 107.0, 96.0), ("25", 138.0, 119.0), ("35", 171.0, 147.0),
+        ("50", 209.0, 179.0), ("70", 269.0, 229.0), ("95", 328.0, 278.0), ("120", 382.0, 322.0),
+        ("150", 441.0, 371.0), ("185", 506.0, 424.0), ("240", 599.0, 500.0), ("300", 693.0, 576.0),
+        ("400", 812.0, 673.0), ("500", 942.0, 778.0),
+    ];
+    for (size, c2, c3) in xlpe_c.iter() {
+        table.insert((Size(size), Insulation::Xlpe, InstallMethod::C), (*c2, *c3));
+    }
+
+    // D1: 지중 덕트 - Table B.52.5 Column 12/13
+    let xlpe_d1 = [
+        ("1.5", 28.0, 22.0), ("2.5", 36.0, 29.0), ("4", 46.0, 37.0), ("6", 57.0, 46.0),
+        ("10", 75.0, 60.0), ("16", 97.0, 77.0), ("25", 123.0, 99.0), ("35", 149.0, 119.0),
+        ("50", 176.0, 140.0), ("70", 218.0, 173.0), ("95", 259.0, 204.0), ("120", 295.0, 233.0),
+        ("150", 334.0, 263.0), ("185", 376.0, 295.0), ("240", 434.0, 340.0), ("300", 492.0, 384.0),
+        ("400", 565.0, 441.0), ("500", 641.0, 499.0),
+    ];
+    for (size, c2, c3) in xlpe_d1.iter() {
+        table.insert((Size(size), Insulation::Xlpe, InstallMethod::D1), (*c2, *c3));
+    }
+
+    // D2: 지중 직매 - Table B.52.5 Column 14/15
+    let xlpe_d2 = [
+        ("1.5", 31.0, 24.0), ("2.5", 41.0, 31.0), ("4", 52.0, 40.0), ("6", 65.0, 50.0),
+        ("10", 85.0, 66.0), ("16", 110.0, 85.0), ("25", 141.0, 109.0), ("35", 170.0, 132.0),
+        ("50", 202.0, 156.0), ("70", 251.0, 193.0), ("95", 300.0, 229.0), ("120", 343.0, 261.0),
+        ("150", 390.0, 296.0), ("185", 440.0, 333.0), ("240", 510.0, 385.0), ("300", 578.0, 436.0),
+        ("400", 664.0, 500.0), ("500", 753.0, 566.0),
+    ];
+    for (size, c2, c3) in xlpe_d2.iter() {
+        table.insert((Size(size), Insulation::Xlpe, InstallMethod::D2), (*c2, *c3));
+    }
+
+    // E: 케이블 트레이 다심 (자유 공기 중) - Table B.52.12
+    let xlpe_e = [
+        ("1.5", 26.0, 23.0), ("2.5", 36.0, 32.0), ("4", 49.0, 42.0), ("6", 63.0, 54.0),
+        ("10", 86.0, 75.0), ("16", 115.0, 100.0), ("25", 149.0, 127.0), ("35", 185.0, 158.0),
+        ("50", 225.0, 192.0), ("70", 289.0, 246.0), ("95", 352.0, 298.0), ("120", 410.0, 346.0),
+        ("150", 473.0, 399.0), ("185", 542.0, 456.0), ("240", 641.0, 538.0), ("300", 741.0, 621.0),
+        ("400", 868.0, 742.0), ("500", 1008.0, 887.0),
+    ];
+    for (size, c2, c3) in xlpe_e.iter() {
+        table.insert((Size(size), Insulation::Xlpe, InstallMethod::E), (*c2, *c3));
+    }
+    // E 단심 630~1000mm² - Table B.52.12 단심 (2부하: 접촉 배치, 3부하: 삼각 배치)
+    let xlpe_e_single_core = [
+        ("630", 1254.0, 1088.0), ("800", 1460.0, 1214.0), ("1000", 1683.0, 1349.0),
+    ];
+    for (size, c2, c3) in xlpe_e_single_core.iter() {
+        table.insert((Size(size), Insulation::Xlpe, InstallMethod::E), (*c2, *c3));
+    }
+    
+    // F: 케이블 트레이 단심 (접촉 배치) - Table B.52.12 (단심 Touching/Trefoil)
+    let xlpe_f = [
+        ("1.5", 29.0, 25.0), ("2.5", 40.0, 35.0), ("4", 55.0, 47.0), ("6", 71.0, 60.0),
+        ("10", 96.0, 83.0), ("16", 128.0, 111.0), ("25", 166.0, 141.0), ("35", 206.0, 176.0),
+        ("50", 251.0, 214.0), ("70", 323.0, 274.0), ("95", 393.0, 332.0), ("120", 458.0, 386.0),
+        ("150", 529.0, 445.0), ("185", 606.0, 509.0), ("240", 717.0, 601.0), ("300", 829.0, 694.0),
+        ("400", 971.0, 828.0), ("500", 1127.0, 990.0),
+    ];
+    for (size, c2, c3) in xlpe_f.iter() {
+        table.insert((Size(size), Insulation::Xlpe, InstallMethod::F), (*c2, *c3));
+    }
+
+    table
+}
+
+type CurrentKey = (Size, Insulation, InstallMethod);
+type CurrentEntry = (CurrentKey, (f64, f64));
+type CurrentTable = HashMap<CurrentKey, (f64, f64)>;
+
+/// 내장 데이터셋 식별자 (프로젝트 파일·결과 출처에 기록, 허용전류·외경·전선관 테이블 전체 공통)
+/// 버전 전환은 지원하지 않음 — 과거 기준 데이터가 필요하면 사용자 허용전류 테이블로 로드
+pub const DATA_VERSION: &str = "2023";
+
+/// 알루미늄 도체 허용전류 테이블 (보정 전, 키는 동 테이블과 동일)
+/// 최초 조회 시 한 번만 구성
+fn get_allowable_current_table_al() -> &'static CurrentTable {
+    static TABLE: OnceLock<CurrentTable> = OnceLock::new();
+    TABLE.get_or_init(build_allowable_current_table_al)
+}
+
+/// 알루미늄 표 수록 규격 (16~300mm², F 단심은 25mm²부터)
+const ALUMINIUM_SIZES: [&str; 11] = ["16", "25", "35", "50", "70", "95", "120", "150", "185", "240", "300"];
+
+/// 내장 알루미늄 허용전류 테이블 구성
+/// A1~D2: IEC 60364-5-52 Table B.52.2~B.52.5 알루미늄 열 (동 열과 같은 표)
+/// E/F: Table B.52.11 (PVC) / B.52.13 (XLPE) 알루미늄 자유 공기 중
+/// 각 열은 ALUMINIUM_SIZES 순서의 (2부하, 3부하), F는 25mm²부터 (16mm²는 표에 없음)
+/// 400mm² 이상은 알루미늄 열이 없어 미지원
+fn build_allowable_current_table_al() -> CurrentTable {
+    type Column = (Insulation, InstallMethod, &'static [(f64, f64)]);
+    let columns: [Column; 18] = [
+        // PVC 70°C - Table B.52.2 (2부하) / B.52.4 (3부하)
+        (Insulation::Pvc, InstallMethod::A1, &[
+            (48.0, 43.0), (63.0, 57.0), (77.0, 70.0), (93.0, 84.0), (118.0, 107.0), (142.0, 129.0),
+            (164.0, 149.0), (189.0, 170.0), (215.0, 194.0), (252.0, 227.0), (289.0, 261.0),
+        ]),
+        (Insulation::Pvc, InstallMethod::A2, &[
+            (44.0, 41.0), (58.0, 53.0), (71.0, 65.0), (86.0, 78.0), (108.0, 98.0), (130.0, 118.0),
+            (150.0, 135.0), (172.0, 155.0), (195.0, 176.0), (229.0, 207.0), (263.0, 237.0),
+        ]),
+        (Insulation::Pvc, InstallMethod::B1, &[
+            (60.0, 53.0), (79.0, 70.0), (97.0, 86.0), (118.0, 104.0), (150.0, 133.0), (181.0, 161.0),
+            (210.0, 186.0), (234.0, 204.0), (266.0, 230.0), (312.0, 269.0), (358.0, 306.0),
+        ]),
+        (Insulation::Pvc, InstallMethod::B2, &[
+            (54.0, 48.0), (71.0, 62.0), (86.0, 77.0), (104.0, 92.0), (131.0, 116.0), (157.0, 139.0),
+            (181.0, 160.0), (201.0, 176.0), (230.0, 199.0), (269.0, 232.0), (308.0, 265.0),
+        ]),
+        (Insulation::Pvc, InstallMethod::C, &[
+            (66.0, 59.0), (83.0, 73.0), (103.0, 90.0), (125.0, 110.0), (160.0, 140.0), (195.0, 170.0),
+            (226.0, 197.0), (261.0, 227.0), (298.0, 259.0), (352.0, 305.0), (406.0, 351.0),
+        ]),
+        (Insulation::Pvc, InstallMethod::D1, &[
+            (61.0, 50.0), (77.0, 64.0), (93.0, 77.0), (109.0, 91.0), (135.0, 112.0), (159.0, 132.0),
+            (180.0, 150.0), (204.0, 169.0), (228.0, 190.0), (262.0, 218.0), (296.0, 247.0),
+        ]),
+        (Insulation::Pvc, InstallMethod::D2, &[
+            (62.0, 53.0), (84.0, 69.0), (101.0, 83.0), (121.0, 99.0), (149.0, 122.0), (179.0, 148.0),
+            (203.0, 169.0), (229.0, 189.0), (259.0, 214.0), (298.0, 250.0), (334.0, 282.0),
+        ]),
+        // XLPE 90°C - Table B.52.3 (2부하) / B.52.5 (3부하)
+        (Insulation::Xlpe, InstallMethod::A1, &[
+            (64.0, 58.0), (84.0, 76.0), (103.0, 94.0), (125.0, 113.0), (158.0, 142.0), (191.0, 171.0),
+            (220.0, 197.0), (253.0, 226.0), (288.0, 256.0), (338.0, 300.0), (387.0, 344.0),
+        ]),
+        (Insulation::Xlpe, InstallMethod::A2, &[
+            (60.0, 55.0), (78.0, 71.0), (96.0, 87.0), (115.0, 104.0), (145.0, 131.0), (175.0, 157.0),
+            (201.0, 180.0), (230.0, 206.0), (262.0, 233.0), (307.0, 273.0), (352.0, 313.0),
+        ]),
+        (Insulation::Xlpe, InstallMethod::B1, &[
+            (79.0, 71.0), (105.0, 93.0), (130.0, 116.0), (157.0, 140.0), (200.0, 179.0), (242.0, 217.0),
+            (281.0, 251.0), (323.0, 267.0), (368.0, 300.0), (433.0, 351.0), (499.0, 402.0),
+        ]),
+        (Insulation::Xlpe, InstallMethod::B2, &[
+            (72.0, 64.0), (94.0, 84.0), (115.0, 103.0), (138.0, 124.0), (175.0, 156.0), (210.0, 188.0),
+            (242.0, 216.0), (277.0, 240.0), (314.0, 272.0), (368.0, 318.0), (421.0, 364.0),
+        ]),
+        (Insulation::Xlpe, InstallMethod::C, &[
+            (84.0, 76.0), (101.0, 90.0), (126.0, 112.0), (154.0, 136.0), (198.0, 174.0), (241.0, 211.0),
+            (280.0, 245.0), (324.0, 283.0), (371.0, 323.0), (439.0, 382.0), (508.0, 440.0),
+        ]),
+        (Insulation::Xlpe, InstallMethod::D1, &[
+            (71.0, 62.0), (90.0, 78.0), (108.0, 94.0), (128.0, 112.0), (158.0, 138.0), (186.0, 162.0),
+            (211.0, 184.0), (238.0, 206.0), (267.0, 230.0), (307.0, 265.0), (346.0, 300.0),
+        ]),
+        (Insulation::Xlpe, InstallMethod::D2, &[
+            (76.0, 64.0), (98.0, 82.0), (117.0, 98.0), (139.0, 116.0), (170.0, 143.0), (204.0, 169.0),
+            (233.0, 192.0), (261.0, 217.0), (296.0, 243.0), (343.0, 280.0), (386.0, 316.0),
+        ]),
+        // E (다심 2부하/3부하), F (단심 접촉 2부하/삼각 3부하, 16mm² 없음)
+        // PVC - Table B.52.11, XLPE - Table B.52.13
+        (Insulation::Pvc, InstallMethod::E, &[
+            (73.0, 61.0), (84.0, 73.0), (103.0, 89.0), (125.0, 108.0), (160.0, 136.0), (195.0, 166.0),
+            (226.0, 192.0), (261.0, 221.0), (298.0, 253.0), (352.0, 298.0), (406.0, 344.0),
+        ]),
+        (Insulation::Pvc, InstallMethod::F, &[
+            (98.0, 84.0), (122.0, 105.0), (149.0, 128.0), (192.0, 166.0), (235.0, 203.0),
+            (273.0, 237.0), (316.0, 274.0), (363.0, 315.0), (430.0, 375.0), (497.0, 434.0),
+        ]),
+        (Insulation::Xlpe, InstallMethod::E, &[
+            (91.0, 77.0), (108.0, 90.0), (135.0, 112.0), (164.0, 136.0), (211.0, 174.0), (257.0, 211.0),
+            (300.0, 245.0), (346.0, 283.0), (397.0, 324.0), (470.0, 384.0), (543.0, 444.0),
+        ]),
+        (Insulation::Xlpe, InstallMethod::F, &[
+            (121.0, 103.0), (150.0, 129.0), (184.0, 159.0), (237.0, 206.0), (289.0, 253.0),
+            (337.0, 296.0), (389.0, 343.0), (447.0, 395.0), (530.0, 471.0), (613.0, 547.0),
+        ]),
+    ];
+
+    let mut table = HashMap::new();
+    for (insulation, method, column) in columns.iter() {
+        // 열 길이가 짧으면 작은 규격부터 빠진 것 (F는 25mm²부터)
+        let sizes = &ALUMINIUM_SIZES[ALUMINIUM_SIZES.len() - column.len()..];
+        for (size, &current) in sizes.iter().zip(column.iter()) {
+            table.insert((Size(size), *insulation, *method), current);
+        }
+    }
+    table
+}
+
+/// 사용자 정의 허용전류 (내장 테이블 위에 덮어씀, 비어 있으면 내장 테이블만 사용)
+static CUSTOM_CURRENT_TABLE: RwLock<Vec<CurrentEntry>> = RwLock::new(Vec::new());
+
+/// 사용자 정의 허용전류가 내장 값을 대체하는 조합인지
+fn is_custom_current_entry(size: Size, insulation: Insulation, method: InstallMethod) -> bool {
+    CUSTOM_CURRENT_TABLE
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .iter()
+        .any(|(key, _)| *key == (size, insulation, method))
+}
+
+/// 사용자 허용전류 테이블 항목 (JSON 입력)
+#[derive(Debug, Clone, Deserialize)]
+struct CustomCurrentEntry {
+    size: String,
+    insulation: String,
+    method: String,
+    loaded_2: f64,  // 2부하 도체 허용전류 (A)
+    loaded_3: f64,  // 3부하 도체 허용전류 (A)
+}
+
+/// 사용자 정의를 반영한 허용전류 테이블과 체크섬
+struct ActiveCurrentTables {
+    copper: Arc<CurrentTable>,      // 내장 + 사용자 정의
+    aluminium: Arc<CurrentTable>,   // 내장 알루미늄 테이블
+    checksum: String,               // get_data_checksum 값
+}
+
+/// 활성 테이블 캐시 (사용자 테이블 로드/해제 시 비움)
+static ACTIVE_CURRENT_TABLES: RwLock<Option<ActiveCurrentTables>> = RwLock::new(None);
+
+/// 활성 테이블 구성
+fn build_active_current_tables() -> ActiveCurrentTables {
+    let mut copper = get_allowable_current_table().clone();
+    copper.extend(CUSTOM_CURRENT_TABLE.read().unwrap_or_else(|e| e.into_inner()).iter().copied());
+    let aluminium = get_allowable_current_table_al().clone();
+    ActiveCurrentTables {
+        checksum: data_checksum(&copper),
+        copper: Arc::new(copper),
+        aluminium: Arc::new(aluminium),
+    }
+}
+
+/// 캐시된 활성 테이블에서 값 선택 (캐시가 비어 있으면 구성)
+fn with_active_current_tables<T>(select: impl Fn(&ActiveCurrentTables) -> T) -> T {
+    if let Some(tables) = ACTIVE_CURRENT_TABLES.read().unwrap_or_else(|e| e.into_inner()).as_ref() {
+        return select(tables);
+    }
+    let mut cache = ACTIVE_CURRENT_TABLES.write().unwrap_or_else(|e| e.into_inner());
+    select(cache.get_or_insert_with(build_active_current_tables))
+}
+
+/// 활성 테이블 캐시 비우기
+fn invalidate_current_tables() {
+    ACTIVE_CURRENT_TABLES.write().unwrap_or_else(|e| e.into_inner()).take();
+}
+
+/// 현재 계산에 쓰이는 허용전류 테이블 (내장 + 사용자 정의)
+fn active_current_table() -> Arc<CurrentTable> {
+    with_active_current_tables(|tables| Arc::clone(&tables.copper))
+}
+
+/// 사용자 허용전류 테이블 로드 (JSON 배열, 기존 사용자 항목 대체)
+/// 예) [{"size":"16","insulation":"XLPE","method":"B2","loaded_2":95,"loaded_3":85}]
+/// 반환: 적용된 항목 수. 로드 시 계산 결과 캐시를 비움 (lang: ko/en)
+pub fn load_custom_current_table(json: &str, lang: &str) -> Result<usize, String> {
+    let parse = || -> Result<Vec<CurrentEntry>, &'static str> {
+        let entries: Vec<CustomCurrentEntry> = serde_json::from_str(json).map_err(|_| ERR_INVALID_CURRENT_TABLE)?;
+        entries
+            .iter()
+            .map(|e| {
+                if !(e.loaded_2 > 0.0 && e.loaded_3 > 0.0) {
+                    return Err(ERR_INVALID_CURRENT_TABLE);
+                }
+                let key = (
+                    Size::try_from(e.size.as_str())?,
+                    Insulation::try_from(e.insulation.as_str())?,
+                    InstallMethod::try_from(e.method.as_str())?,
+                );
+                Ok((key, (e.loaded_2, e.loaded_3)))
+            })
+            .collect()
+    };
+    let entries = parse().map_err(|key| localize_error(key, lang))?;
+    let count = entries.len();
+    *CUSTOM_CURRENT_TABLE.write().unwrap_or_else(|e| e.into_inner()) = entries;
+    invalidate_current_tables();
+    invalidate_cache();
+    Ok(count)
+}
+
+/// 사용자 허용전류 테이블 해제 (내장 테이블로 복귀), 계산 결과 캐시를 비움
+pub fn reset_current_table() {
+    CUSTOM_CURRENT_TABLE.write().unwrap_or_else(|e| e.into_inner()).clear();
+    invalidate_current_tables();
+    invalidate_cache();
+}
+
+/// 계산 데이터(허용전류·전선관 테이블) 체크섬 (FNV-1a 64bit, 16진수)
+/// 데이터가 바뀌면 값이 달라지므로 캐시 키·결과 추적에 사용
+pub fn get_data_checksum() -> String {
+    with_active_current_tables(|tables| tables.checksum.clone())
+}
+
+/// 허용전류 테이블 + 전선관 데이터 체크섬 계산
+fn data_checksum(current_table: &CurrentTable) -> String {
+    let mut entries: Vec<String> = current_table
+        .iter()
+        .map(|((size, insulation, method), (c2, c3))| {
+            format!("{}|{}|{}|{}|{}", size.as_str(), insulation.as_str(), method.as_str(), c2, c3)
+        })
+        .collect();
+    entries.sort();
+    entries.extend(
+        ConduitType::ALL
+            .iter()
+            .flat_map(|conduit_type| conduit_type.data())
+            .map(|(name, d)| format!("{}|{}", name, d)),
+    );
+
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in entries.join("\n").bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    format!("{:016x}", hash)
+}
+
+/// 현재 활성 테이블 기준 데이터 출처 (calculated_at: 계산 시각, Unix epoch 초)
+/// 캐시된 결과는 최초 계산 시각을 유지
+fn current_data_provenance(regulation: Regulation, calculated_at: u64) -> DataProvenance {
+    let custom = !CUSTOM_CURRENT_TABLE.read().unwrap_or_else(|e| e.into_inner()).is_empty();
+    DataProvenance {
+        table_version: if custom { "custom".to_string() } else { DATA_VERSION.to_string() },
+        regulation: regulation.as_str().to_string(),
+        data_checksum: get_data_checksum(),
+        calculated_at: format_utc_timestamp(calculated_at),
+    }
+}
+
+/// 계산 결과 캐시 (키: 데이터 체크섬 + 입력값)
+static RESULT_CACHE: Mutex<Option<HashMap<String, CalculationResult>>> = Mutex::new(None);
+
+/// 캐시를 거치는 계산 (같은 데이터·입력이면 저장된 결과 반환)
+/// 키에 데이터 체크섬이 포함되어 테이블이 바뀌면 이전 결과는 자연히 미스됨
+pub fn calculate_cached(data: &CableData) -> Result<CalculationResult, String> {
+    let key = format!("{}:{:?}", get_data_checksum(), data);
+    if let Some(result) = RESULT_CACHE
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .as_ref()
+        .and_then(|cache| cache.get(&key))
+    {
+        return Ok(result.clone());
+    }
+    let result = calculate_core(data)?;
+    RESULT_CACHE
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get_or_insert_with(HashMap::new)
+        .insert(key, result.clone());
+    Ok(result)
+}
+
+/// 계산 결과 캐시 비우기, 반환: 삭제된 항목 수
+pub fn invalidate_cache() -> usize {
+    RESULT_CACHE
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .take()
+        .map_or(0, |cache| cache.len())
+}
+
+/// 전선 종류별 외경 테이블 (mm, 다심 테이블이 없는 다심은 단심/2심 값의 배율로 근사)
+struct OuterDiameterTables {
+    tfr_cv_1c: HashMap<&'static str, f64>,
+    tfr_cv_2c: HashMap<&'static str, f64>,
+    hfix_1c: HashMap<&'static str, f64>,
+    cv_1c: HashMap<&'static str, f64>,
+    cv_2c: HashMap<&'static str, f64>,
+    fr_cv_1c: HashMap<&'static str, f64>,
+    fr_cv_2c: HashMap<&'static str, f64>,
+    fr_cv_3c: HashMap<&'static str, f64>,
+    fr_cv_4c: HashMap<&'static str, f64>,
+    tfr_8_1c: HashMap<&'static str, f64>,
+    tfr_8_2c: HashMap<&'static str, f64>,
+    tfr_8_3c: HashMap<&'static str, f64>,
+    tfr_8_4c: HashMap<&'static str, f64>,
+}
+
+/// 외경 테이블 (최초 조회 시 한 번만 구성)
+fn outer_diameter_tables() -> &'static OuterDiameterTables {
+    static TABLES: OnceLock<OuterDiameterTables> = OnceLock::new();
+    TABLES.get_or_init(|| {
+        // TFR-CV 케이블 외경 (dcord.com 기준)
+        let tfr_cv_1c: HashMap<&'static str, f64> = [
+            ("1.5", 6.3), ("2.5", 6.7), ("4", 7.2), ("6", 7.8),
+            ("10", 9.4), ("16", 10.0), ("25", 12.0), ("35", 13.0),
+            ("50", 14.5), ("70", 16.0), ("95", 18.5), ("120", 20.0),
+            ("150", 22.0), ("185", 24.0), ("240", 27.0), ("300", 30.0),
+            ("400", 34.0), ("500", 37.0),
+        ].iter().cloned().collect();
+
+        let tfr_cv_2c: HashMap<&'static str, f64> = [
+            ("1.5", 11.0), ("2.5", 12.0), ("4", 13.0), ("6", 14.0),
+            ("10", 18.0), ("16", 21.0), ("25", 25.0), ("35", 29.0),
+            ("50", 34.0), ("70", 39.0), ("95", 44.0), ("120", 50.0),
+            ("150", 55.0), ("185", 61.0), ("240", 67.0), ("300", 75.0),
+        ].iter().cloned().collect();
+
+        // 3C 외경은 2C의 약 1.15배
+        // 4C 외경은 2C의 약 1.25배
+
+        // HFIX 전선 외경 (nexans, daeshincable 기준)
+        let hfix_1c: HashMap<&'static str, f64> = [
+            ("1.5", 3.3), ("2.5", 4.0), ("4", 4.6), ("6", 5.2),
+            ("10", 6.5), ("16", 8.0), ("25", 10.1), ("35", 11.3),
+            ("50", 13.2), ("70", 15.5), ("95", 18.0), ("120", 20.0),
+            ("150", 22.5), ("185", 25.0), ("240", 28.5), ("300", 32.0),
+        ].iter().cloned().collect();
+
+        // CV 케이블 (TFR-CV와 유사하나 약간 작음)
+        let cv_1c: HashMap<&'static str, f64> = [
+            ("1.5", 6.0), ("2.5", 6.4), ("4", 6.9), ("6", 7.5),
+            ("10", 9.0), ("16", 9.6), ("25", 11.5), ("35", 12.5),
+            ("50", 14.0), ("70", 15.5), ("95", 18.0), ("120", 19.5),
+            ("150", 21.5), ("185", 23.5), ("240", 26.5), ("300", 29.5),
+            ("400", 33.0), ("500", 36.0),
+        ].iter().cloned().collect();
+
+        let cv_2c: HashMap<&'static str, f64> = [
+            ("1.5", 10.5), ("2.5", 11.5), ("4", 12.5), ("6", 13.5),
+            ("10", 17.0), ("16", 20.0), ("25", 24.0), ("35", 28.0),
+            ("50", 33.0), ("70", 38.0), ("95", 43.0), ("120", 49.0),
+            ("150", 54.0), ("185", 60.0), ("240", 66.0), ("300", 74.0),
+        ].iter().cloned().collect();
+
+        // FR-CV (내화 케이블) - TFR-CV보다 약간 큼
+        let fr_cv_1c: HashMap<&'static str, f64> = [
+            ("1.5", 6.8), ("2.5", 7.2), ("4", 7.7), ("6", 8.3),
+            ("10", 10.0), ("16", 10.6), ("25", 12.6), ("35", 13.6),
+            ("50", 15.1), ("70", 16.6), ("95", 19.1), ("120", 20.6),
+            ("150", 22.6), ("185", 24.6), ("240", 27.6), ("300", 30.6),
+            ("400", 34.6), ("500", 37.6),
+        ].iter().cloned().collect();
+
+        // FR-CV 다심 대표값 (1.5~95sq), 95sq 초과는 마지막 표 값에서 단심 외경 비로 환산
+        // 특정 제조사 규격서와 대조하지 않은 값이므로 근사로 표시(is_outer_diameter_approximated), 시공 제품의 외경은 custom_outer_diameter로 확인 권장
+        // 내화층(마이카 테이프) 두께가 규격과 무관하게 일정해 소규격일수록 단심 배율보다 큼
+        let fr_cv_2c: HashMap<&'static str, f64> = [
+            ("1.5", 12.0), ("2.5", 13.0), ("4", 14.0), ("6", 15.0),
+            ("10", 19.0), ("16", 22.0), ("25", 26.0), ("35", 30.0),
+            ("50", 35.0), ("70", 40.0), ("95", 45.0),
+        ].iter().cloned().collect();
+
+        let fr_cv_3c: HashMap<&'static str, f64> = [
+            ("1.5", 12.5), ("2.5", 13.5), ("4", 14.5), ("6", 16.0),
+            ("10", 20.0), ("16", 23.0), ("25", 28.0), ("35", 31.0),
+            ("50", 36.0), ("70", 42.0), ("95", 48.0),
+        ].iter().cloned().collect();
+
+        let fr_cv_4c: HashMap<&'static str, f64> = [
+            ("1.5", 13.5), ("2.5", 14.5), ("4", 16.0), ("6", 17.5),
+            ("10", 21.5), ("16", 25.0), ("25", 30.5), ("35", 34.0),
+            ("50", 39.5), ("70", 46.0), ("95", 52.0),
+        ].iter().cloned().collect();
+
+        // TFR-8 (내열 케이블)
+        let tfr_8_1c: HashMap<&'static str, f64> = [
+            ("1.5", 6.5), ("2.5", 6.9), ("4", 7.4), ("6", 8.0),
+            ("10", 9.6), ("16", 10.2), ("25", 12.2), ("35", 13.2),
+            ("50", 14.7), ("70", 16.2), ("95", 18.7), ("120", 20.2),
+            ("150", 22.2), ("185", 24.2), ("240", 27.2), ("300", 30.2),
+        ].iter().cloned().collect();
+
+        // TFR-8 다심 대표값 (1.5~95sq, FR-CV 다심과 같은 환산 규칙·근사 표시)
+        let tfr_8_2c: HashMap<&'static str, f64> = [
+            ("1.5", 11.5), ("2.5", 12.5), ("4", 13.5), ("6", 14.5),
+            ("10", 18.5), ("16", 21.5), ("25", 25.5), ("35", 29.5),
+            ("50", 34.5), ("70", 39.5), ("95", 44.5),
+        ].iter().cloned().collect();
+
+        let tfr_8_3c: HashMap<&'static str, f64> = [
+            ("1.5", 12.0), ("2.5", 13.0), ("4", 14.0), ("6", 15.5),
+            ("10", 19.5), ("16", 22.5), ("25", 27.5), ("35", 30.5),
+            ("50", 35.5), ("70", 41.5), ("95", 47.5),
+        ].iter().cloned().collect();
+
+        let tfr_8_4c: HashMap<&'static str, f64> = [
+            ("1.5", 13.0), ("2.5", 14.0), ("4", 15.5), ("6", 17.0),
+            ("10", 21.0), ("16", 24.5), ("25", 30.0), ("35", 33.5),
+            ("50", 39.0), ("70", 45.5), ("95", 51.5),
+        ].iter().cloned().collect();
+
+        OuterDiameterTables {
+            tfr_cv_1c,
+            tfr_cv_2c,
+            hfix_1c,
+            cv_1c,
+            cv_2c,
+            fr_cv_1c,
+            fr_cv_2c,
+            fr_cv_3c,
+            fr_cv_4c,
+            tfr_8_1c,
+            tfr_8_2c,
+            tfr_8_3c,
+            tfr_8_4c,
+        }
+    })
+}
+
+/// 입력 케이블의 외경 (mm): 사용자 지정 외경이 있으면 그 값, 없으면 내장 테이블
+fn cable_outer_diameter(data: &CableData) -> Option<f64> {
+    data.custom_outer_diameter
+        .or_else(|| get_cable_outer_diameter(&data.cable_type, &data.size, &data.cores))
+}
+
+/// 내장 외경 테이블 조회 (mm, 사용자 지정 외경 확인·덮어쓰기 전 기준값), 데이터가 없으면 None
+pub fn lookup_outer_diameter(cable_type: &str, size: &str, cores: &str) -> Option<f64> {
+    get_cable_outer_diameter(cable_type.trim(), size.trim(), cores.trim())
+}
+
+/// FR-CV/TFR-8 다심 대표값 외경 (mm), 다심 테이블에 없는 규격이면 None
+fn tabulated_multi_core_diameter(cable_type: &str, size: &str, cores: &str) -> Option<f64> {
+    let tables = outer_diameter_tables();
+    let table = match (cable_type, cores) {
+        ("FR-CV", "2C") => &tables.fr_cv_2c,
+        ("FR-CV", "3C") => &tables.fr_cv_3c,
+        ("FR-CV", "4C") => &tables.fr_cv_4c,
+        ("TFR-8", "2C") => &tables.tfr_8_2c,
+        ("TFR-8", "3C") => &tables.tfr_8_3c,
+        ("TFR-8", "4C") => &tables.tfr_8_4c,
+        _ => return None,
+    };
+    table.get(size).copied()
+}
+
+/// 전선 종류별 외경 데이터 (mm) - KEC 기준 제조사 규격
+/// TFR-CV: 0.6/1kV 가교폴리에틸렌 절연 난연 PVC 시스 케이블
+/// FR-CV/TFR-8 다심은 다심 대표값 테이블 우선, 없는 규격은 아래쪽 가장 가까운 테이블 값 × 단심 외경 비로 근사
+/// (테이블 구간과 근사 구간 경계에서도 외경이 규격에 따라 단조 증가)
+fn get_cable_outer_diameter(cable_type: &str, size: &str, cores: &str) -> Option<f64> {
+    let tables = outer_diameter_tables();
+    match (cable_type, cores) {
+        ("HFIX", "1C") => tables.hfix_1c.get(size).copied(),
+        ("HFIX", _) => None, // HFIX는 단심만 존재
+        
+        ("TFR-CV", "1C") => tables.tfr_cv_1c.get(size).copied(),
+        ("TFR-CV", "2C") => tables.tfr_cv_2c.get(size).copied(),
+        ("TFR-CV", "3C") => tables.tfr_cv_2c.get(size).map(|d| d * 1.15),
+        ("TFR-CV", "4C") => tables.tfr_cv_2c.get(size).map(|d| d * 1.25),
+        
+        ("CV", "1C") => tables.cv_1c.get(size).copied(),
+        ("CV", "2C") => tables.cv_2c.get(size).copied(),
+        ("CV", "3C") => tables.cv_2c.get(size).map(|d| d * 1.15),
+        ("CV", "4C") => tables.cv_2c.get(size).map(|d| d * 1.25),
+
+        ("FR-CV", "1C") => tables.fr_cv_1c.get(size).copied(),
+        ("TFR-8", "1C") => tables.tfr_8_1c.get(size).copied(),
+        ("FR-CV" | "TFR-8", "2C" | "3C" | "4C") => tabulated_multi_core_diameter(cable_type, size, cores).or_else(|| {
+            let single_core = if cable_type == "FR-CV" { &tables.fr_cv_1c } else { &tables.tfr_8_1c };
+            let target = single_core.get(size)?;
+            let area = Size::try_from(size).ok()?.mm2();
+            let (anchor, anchor_diameter) = STANDARD_SIZES
+                .iter()
+                .rev()
+                .filter(|s| Size(s).mm2() < area)
+                .find_map(|s| tabulated_multi_core_diameter(cable_type, s, cores).map(|d| (*s, d)))?;
+            single_core.get(anchor).map(|anchor_single| anchor_diameter * target / anchor_single)
+        }),
+
+        _ => None,
+    }
+}
+
+/// 전선 단면적 계산 (외경 기준, 원형)
+fn calculate_cable_area(outer_diameter: f64) -> Millimeters2 {
+    Millimeters2::circle(outer_diameter)
+}
+
+const UNIFORM_PACKING_DENSITY: f64 = 0.9069;  // 동일 외경 원의 최밀 충전 밀도 (π/√12)
+const MIXED_PACKING_DENSITY: f64 = 0.8;       // 혼합 외경 보수적 근사
+
+/// 케이블 간 공극을 반영한 실효 점유 면적 (mm², 참고값)
+/// 입력: (외경 mm, 개수) 목록
+/// 1가닥은 원 면적 그대로, 동일 외경 복수는 원 면적 합 / 0.9069, 혼합 외경은 원 면적 합 / 0.8.
+/// 본수별 점유율 상한(53/31/40%)은 이미 공극을 감안한 원 면적 합 기준이라 전선관 선정에는 쓰지 않음
+/// (함께 쓰면 공극을 이중 반영), 실제 배열 가능 여부는 can_fit_geometrically로 확인
+pub fn effective_occupied_area(cables: &[(f64, u32)]) -> f64 {
+    let cables: Vec<(f64, u32)> = cables.iter().copied().filter(|(d, n)| *d > 0.0 && *n > 0).collect();
+    let count: u32 = cables.iter().map(|(_, n)| n).sum();
+    let area_sum = cables.iter().map(|(d, n)| calculate_cable_area(*d) * *n as f64).sum::<Millimeters2>().value();
+    if count <= 1 {
+        return area_sum;
+    }
+    let uniform = cables.windows(2).all(|w| w[0].0 == w[1].0);
+    area_sum / if uniform { UNIFORM_PACKING_DENSITY } else { MIXED_PACKING_DENSITY }
+}
+
+/// 전선관 내경 데이터 (mm) - 후강전선관 기준
+const CONDUIT_DATA: &[(&str, f64)] = &[
+    ("C16 (16mm)", 15.8),
+    ("C22 (22mm)", 21.0),
+    ("C28 (28mm)", 26.6),
+    ("C36 (36mm)", 35.0),
+    ("C42 (42mm)", 41.0),
+    ("C54 (54mm)", 53.0),
+    ("C70 (70mm)", 69.0),
+    ("C82 (82mm)", 80.0),
+    ("C92 (92mm)", 89.0),
+    ("C104 (104mm)", 101.0),
+];
+
+/// 박강전선관 (KS C 8401) 호칭별 내경 (mm)
+const THIN_STEEL_CONDUIT_DATA: &[(&str, f64)] = &[
+    ("E19", 15.9),
+    ("E25", 22.2),
+    ("E31", 28.6),
+    ("E39", 34.9),
+    ("E51", 47.6),
+    ("E63", 59.5),
+    ("E75", 72.2),
+];
+
+/// 합성수지제 가요전선관 CD관 (KS C 8454) 호칭별 내경 (mm)
+const CD_CONDUIT_DATA: &[(&str, f64)] = &[
+    ("CD14", 14.0),
+    ("CD16", 16.0),
+    ("CD22", 22.0),
+    ("CD28", 28.0),
+    ("CD36", 36.0),
+    ("CD42", 42.0),
+];
+
+/// 합성수지제 가요전선관 PF관 (KS C 8454, 내연성) 호칭별 내경 (mm)
+const PF_CONDUIT_DATA: &[(&str, f64)] = &[
+    ("PF14", 14.0),
+    ("PF16", 16.0),
+    ("PF22", 22.0),
+    ("PF28", 28.0),
+    ("PF36", 36.0),
+    ("PF42", 42.0),
+    ("PF54", 54.0),
+];
+
+/// 경질비닐전선관 VE (KS C 8431) 호칭별 내경 (mm)
+const PVC_CONDUIT_DATA: &[(&str, f64)] = &[
+    ("VE16", 18.0),
+    ("VE22", 22.0),
+    ("VE28", 28.0),
+    ("VE36", 35.0),
+    ("VE42", 40.0),
+    ("VE54", 51.0),
+    ("VE70", 67.0),
+    ("VE82", 77.0),
+    ("VE100", 100.0),
+];
+
+/// 전선관 종류 (관종별 내경 테이블 선택)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ConduitType {
+    #[default]
+    Steel,      // 후강전선관
+    ThinSteel,  // 박강전선관
+    Cd,         // 합성수지제 가요전선관 CD관 (콘크리트 매입 전용)
+    Pf,         // 합성수지제 가요전선관 PF관
+    Pvc,        // 경질비닐전선관 (VE)
+}
+
+impl ConduitType {
+    pub const ALL: [ConduitType; 5] = [
+        ConduitType::Steel,
+        ConduitType::ThinSteel,
+        ConduitType::Cd,
+        ConduitType::Pf,
+        ConduitType::Pvc,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ConduitType::Steel => "후강전선관",
+            ConduitType::ThinSteel => "박강전선관",
+            ConduitType::Cd => "CD관",
+            ConduitType::Pf => "PF관",
+            ConduitType::Pvc => "경질비닐전선관",
+        }
+    }
+
+    fn data(self) -> &'static [(&'static str, f64)] {
+        match self {
+            ConduitType::Steel => CONDUIT_DATA,
+            ConduitType::ThinSteel => THIN_STEEL_CONDUIT_DATA,
+            ConduitType::Cd => CD_CONDUIT_DATA,
+            ConduitType::Pf => PF_CONDUIT_DATA,
+            ConduitType::Pvc => PVC_CONDUIT_DATA,
+        }
+    }
+}
+
+/// 관종별 규격 목록: (표준 호칭, 내경 mm), 작은 규격부터
+pub fn get_conduit_data(conduit_type: ConduitType) -> Vec<(&'static str, f64)> {
+    conduit_type.data().to_vec()
+}
+
+/// 전선관 규격별 미리 계산한 값 (호칭경·내부 단면적)
+struct ConduitSpec {
+    name: &'static str,
+    nominal: u32,
+    inner_diameter: f64,
+    area: f64,  // 내부 단면적 (mm²)
+}
+
+impl ConduitSpec {
+    /// 호칭만 (예: "C104 (104mm)" -> "C104", "CD42" -> "CD42")
+    fn short_name(&self) -> &'static str {
+        self.name.split_whitespace().next().unwrap_or(self.name)
+    }
+}
+
+/// 관종별 전선관 규격 테이블 (최초 조회 시 한 번만 구성)
+/// 점유율 상한은 규정·가닥수별로 달라지므로 면적만 캐시하고 상한은 조회 시 곱으로 적용
+fn conduit_table(conduit_type: ConduitType) -> &'static [ConduitSpec] {
+    static TABLES: OnceLock<Vec<Vec<ConduitSpec>>> = OnceLock::new();
+    let tables = TABLES.get_or_init(|| {
+        ConduitType::ALL
+            .iter()
+            .map(|conduit_type| {
+                conduit_type
+                    .data()
+                    .iter()
+                    .map(|&(name, inner_diameter)| ConduitSpec {
+                        name,
+                        nominal: conduit_nominal(name),
+                        inner_diameter,
+                        area: std::f64::consts::PI * (inner_diameter / 2.0).powi(2),
+                    })
+                    .collect()
+            })
+            .collect()
+    });
+    let index = ConduitType::ALL.iter().position(|t| *t == conduit_type).unwrap_or(0);
+    &tables[index]
+}
+
+/// 관종별 가장 큰 전선관 규격
+fn largest_conduit(conduit_type: ConduitType) -> &'static ConduitSpec {
+    let table = conduit_table(conduit_type);
+    &table[table.len() - 1]
+}
+
+/// 추천 배선 경로 종류
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RacewayKind {
+    #[default]
+    Conduit,  // 전선관 (점유율 = 실효 점유 면적 ÷ 관 내부 단면적)
+    Tray,     // 케이블 트레이 (E/F 공사방법, 점유율 = 외경 합 ÷ 트레이 폭, 단층 기준)
+}
+
+/// 추천 배선 경로 정보 (전선관 또는 케이블 트레이)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RacewayRecommendation {
+    #[serde(default)]
+    pub kind: RacewayKind,    // 전선관 / 트레이
+    pub nominal: u32,         // 호칭 치수 (mm, 전선관 호칭경 / 트레이 폭)
+    pub name: String,         // 표시 문자열 (예: "C28 (28mm)", "트레이 200mm (단층 6본)")
+    pub inner_diameter: Option<f64>, // 전선관 내경 (mm, 트레이는 None)
+    pub fill_rate: f64,       // 점유율 (%)
+    pub status: String,       // 정상 / 분할 필요 / 최대 초과
+    #[serde(default)]
+    pub fill_limit: f64,      // 적용 점유율 상한 (%, 전선 본수별)
+    #[serde(default)]
+    pub geometry_note: Option<String>, // 면적은 통과하나 배열 불가·케이블 외경 한계로 재추천한 경우 경고
+}
+
+/// 전선관 표시 문자열에서 호칭경 추출 ("C28 (28mm)" -> 28)
+fn conduit_nominal(name: &str) -> u32 {
+    name.trim_start_matches(|c: char| c.is_ascii_alphabetic())
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .collect::<String>()
+        .parse()
+        .unwrap_or(0)
+}
+
+/// 전선 본수별 전선관 점유율 상한: 1본 53%, 2본 31%, 3본 이상 40%
+/// 2본은 관 안에서 나란히 걸려 인입 시 끼임이 생기기 쉬워 1본·3본보다 상한이 낮음
+fn conduit_fill_limit_by_count(cable_count: u32) -> f64 {
+    match cable_count {
+        0 | 1 => 0.53,
+        2 => 0.31,
+        _ => 0.4,
+    }
+}
+
+/// 단일 케이블 최대 외경 / 관 내경 (이보다 굵으면 인입 불가로 판정)
+const MAX_SINGLE_CABLE_RATIO: f64 = 0.8;
+
+/// 전선관 호칭경별 수용 가능한 최대 단일 케이블 외경 (mm, 후강전선관 내경의 80%)
+/// 등록되지 않은 호칭경이면 None
+pub fn max_single_cable_diameter(conduit_nominal: u32) -> Option<f64> {
+    conduit_table(ConduitType::Steel)
+        .iter()
+        .find(|spec| spec.nominal == conduit_nominal)
+        .map(|spec| (spec.inner_diameter * MAX_SINGLE_CABLE_RATIO * 10.0).round() / 10.0)
+}
+
+/// 전선관에 넣을 수 있는 같은 케이블의 최대 본수 (KEC 본수별 점유율 상한 적용)
+/// conduit_name: 관종 무관 호칭 (예: "C28", "E25", "VE28", "C28 (28mm)"도 허용)
+/// 단일 케이블이 관 수용 한계(내경 80%)를 넘으면 0, 알 수 없는 전선관·외경 데이터 없는 규격은 Err (lang: ko/en)
+pub fn max_cables_in_conduit(conduit_name: &str, cable_type: &str, size: &str, cores: &str, lang: &str) -> Result<u32, String> {
+    let compute = || -> Result<u32, &'static str> {
+        let name = conduit_name.trim();
+        let spec = ConduitType::ALL
+            .iter()
+            .flat_map(|conduit_type| conduit_table(*conduit_type))
+            .find(|spec| spec.short_name().eq_ignore_ascii_case(name) || spec.name == name)
+            .ok_or(ERR_UNKNOWN_CONDUIT)?;
+        let diameter = get_cable_outer_diameter(cable_type.trim(), size.trim(), cores.trim()).ok_or(ERR_UNSUPPORTED_SIZE)?;
+        if diameter > spec.inner_diameter * MAX_SINGLE_CABLE_RATIO {
+            return Ok(0);
+        }
+        let cable_area = calculate_cable_area(diameter).value();
+        let fits = |count: u32| count as f64 * cable_area <= spec.area * conduit_fill_limit_by_count(count);
+        // 3본 이상은 상한이 40%로 고정이므로 바로 나눗셈, 3본이 안 들어가면 상한이 다른 2본·1본 순으로 확인
+        let bulk = (spec.area * conduit_fill_limit_by_count(3) / cable_area).floor() as u32;
+        if bulk >= 3 {
+            return Ok(bulk);
+        }
+        Ok((1..=2).rev().find(|count| fits(*count)).unwrap_or(0))
+    };
+    compute().map_err(|key| localize_error(key, lang))
+}
+
+/// 점유율 상한(max_fill_rate) 기준 추천 전선관 크기 계산
+/// 가장 굵은 케이블 외경(largest_diameter, mm)이 관의 수용 한계를 넘으면 해당 관은 건너뛰고 경고 기록.
+/// 최대 전선관으로도 점유율을 만족하지 못하면 "분할 필요",
+/// 최대 전선관 내부 단면적 자체를 넘거나 단일 케이블이 최대 관에도 들어가지 않으면 "최대 초과"
+fn recommend_conduit(conduit_type: ConduitType, total_area: f64, max_fill_rate: f64, largest_diameter: f64) -> RacewayRecommendation {
+    let area_fits = |spec: &&ConduitSpec| spec.area * max_fill_rate >= total_area;
+    let accepts_cable = |spec: &ConduitSpec| largest_diameter <= spec.inner_diameter * MAX_SINGLE_CABLE_RATIO;
+    let by_area = conduit_table(conduit_type).iter().find(area_fits);
+    if let Some(spec) = conduit_table(conduit_type).iter().filter(area_fits).find(|spec| accepts_cable(spec)) {
+        let actual_fill = (total_area / spec.area) * 100.0;
+        tracing::trace!(total_area, max_fill_rate, conduit = spec.name, fill_rate = actual_fill, "전선관 추천");
+        let geometry_note = by_area.filter(|first| first.nominal != spec.nominal).map(|first| {
+            format!(
+                "최대 케이블 외경 {:.1}mm가 {} 수용 한계 {:.1}mm 초과 → {} 추천",
+                largest_diameter,
+                first.name,
+                first.inner_diameter * MAX_SINGLE_CABLE_RATIO,
+                spec.name
+            )
+        });
+        return RacewayRecommendation {
+            kind: RacewayKind::Conduit,
+            nominal: spec.nominal,
+            name: spec.name.to_string(),
+            inner_diameter: Some(spec.inner_diameter),
+            fill_rate: actual_fill,
+            status: "정상".to_string(),
+            fill_limit: max_fill_rate * 100.0,
+            geometry_note,
+        };
+    }
+
+    let largest = largest_conduit(conduit_type);
+    let too_thick = !accepts_cable(largest);
+    let status = if total_area > largest.area || too_thick { "최대 초과" } else { "분할 필요" };
+    tracing::trace!(total_area, max_fill_rate, status, "전선관 추천: 최대 전선관 초과");
+    RacewayRecommendation {
+        kind: RacewayKind::Conduit,
+        nominal: largest.nominal,
+        name: format!("{} 이상 검토 필요", largest.short_name()),
+        inner_diameter: Some(largest.inner_diameter),
+        fill_rate: 100.0,
+        status: status.to_string(),
+        fill_limit: max_fill_rate * 100.0,
+        geometry_note: too_thick.then(|| {
+            format!(
+                "최대 케이블 외경 {:.1}mm가 최대 전선관 수용 한계 {:.1}mm 초과",
+                largest_diameter,
+                largest.inner_diameter * MAX_SINGLE_CABLE_RATIO
+            )
+        }),
+    }
+}
+
+/// 표준 케이블 트레이 폭 (mm)
+const CABLE_TRAY_WIDTHS: &[u32] = &[100, 200, 300, 400, 600];
+/// 단층 포설 시 케이블 외경 합계의 트레이 폭 대비 상한 (NEC 392.22(A)(1)(b): 외경 합 ≤ 트레이 폭)
+const TRAY_WIDTH_FILL_LIMIT: f64 = 1.0;
+
+/// 케이블 트레이 폭 추천 (단층 기준): (표시 문자열, 폭 점유율 %)
+/// 케이블 외경 합계가 들어가는 가장 좁은 표준 폭, 최대 폭으로도 부족하면 "600mm 초과"와 최대 폭 기준 점유율
+pub fn recommend_cable_tray(total_width_mm: f64, cable_count: u32) -> (String, f64) {
+    let fill = |width: u32| (total_width_mm / width as f64 * 100.0 * 10.0).round() / 10.0;
+    match CABLE_TRAY_WIDTHS
+        .iter()
+        .find(|&&width| total_width_mm <= width as f64 * TRAY_WIDTH_FILL_LIMIT)
+    {
+        Some(&width) => (format!("트레이 {}mm (단층 {}본)", width, cable_count), fill(width)),
+        None => {
+            let widest = CABLE_TRAY_WIDTHS[CABLE_TRAY_WIDTHS.len() - 1];
+            (format!("트레이 {}mm 초과 (단층 {}본)", widest, cable_count), fill(widest))
+        }
+    }
+}
+
+/// 트레이 공사방법(E/F) 입력인지 (전선관 대신 트레이 폭으로 점유율 산정)
+fn uses_cable_tray(data: &CableData) -> bool {
+    InstallMethod::try_from(data.install_method.trim()).is_ok_and(|method| matches!(method, InstallMethod::E | InstallMethod::F))
+}
+
+/// 트레이 추천 결과를 공통 추천 구조로 변환 (최대 폭 초과 시 분할 필요)
+fn recommend_tray_for_cables(cable_diameters: &[f64]) -> RacewayRecommendation {
+    let total_width: f64 = cable_diameters.iter().sum();
+    let (name, fill_rate) = recommend_cable_tray(total_width, cable_diameters.len() as u32);
+    let width = CABLE_TRAY_WIDTHS
+        .iter()
+        .copied()
+        .find(|&width| total_width <= width as f64 * TRAY_WIDTH_FILL_LIMIT);
+    let nominal = width.unwrap_or(CABLE_TRAY_WIDTHS[CABLE_TRAY_WIDTHS.len() - 1]);
+    tracing::trace!(total_width, tray = %name, fill_rate, "트레이 추천");
+    RacewayRecommendation {
+        kind: RacewayKind::Tray,
+        nominal,
+        name,
+        inner_diameter: None,
+        fill_rate,
+        status: if width.is_some() { "정상" } else { "분할 필요" }.to_string(),
+        fill_limit: TRAY_WIDTH_FILL_LIMIT * 100.0,
+        geometry_note: None,
+    }
+}
+
+/// 동일 외경 n가닥을 원형 관에 배열할 때 필요한 관 반지름 / 케이블 반지름 (R/r)
+/// 1~7가닥은 알려진 최적 배열(2: 나란히, 3: 삼각, 4: 정사각, 5: 오각, 6·7: 육각+중심),
+/// 8가닥 이상은 육방 밀집 밀도 기준 근사 1 + √((n-1)/0.9069) (보수적)
+fn packing_ratio(count: usize) -> f64 {
+    match count {
+        0 | 1 => 1.0,
+        2 => 2.0,
+        3 => 1.0 + 2.0 / 3f64.sqrt(),
+        4 => 1.0 + 2f64.sqrt(),
+        5 => 1.0 + (2.0 * (1.0 + 1.0 / 5f64.sqrt())).sqrt(),
+        6 | 7 => 3.0,
+        n => 1.0 + ((n - 1) as f64 / UNIFORM_PACKING_DENSITY).sqrt(),
+    }
+}
+
+/// 원형 패킹 기준 실제 배열 가능 여부 (내경·외경 mm)
+/// 외경 내림차순으로 정렬 후 다음을 모두 만족해야 배열 가능으로 판정:
+/// - 가장 큰 두 가닥이 나란히 들어감 (d1 + d2 <= 내경)
+/// - 상위 k가닥을 k번째 외경의 동일 외경 k가닥으로 보고 표준 배열에 들어감 (k = 1..n)
+///
+/// 동일 외경이면 표준 배열 판정과 같고, 혼합 외경은 heuristic 근사
+pub fn can_fit_geometrically(inner_diameter: f64, cable_diameters: Vec<f64>) -> bool {
+    let mut diameters: Vec<f64> = cable_diameters.into_iter().filter(|d| *d > 0.0).collect();
+    diameters.sort_by(|a, b| b.total_cmp(a));
+    if diameters.len() >= 2 && diameters[0] + diameters[1] > inner_diameter {
+        return false;
+    }
+    diameters
+        .iter()
+        .enumerate()
+        .all(|(i, d)| d * packing_ratio(i + 1) <= inner_diameter)
+}
+
+/// 전선관 점유율 상한 산정 기준 (관 내 전선 본수는 외경 목록에서 산정)
+#[derive(Debug, Clone, Copy)]
+enum FillLimit {
+    Regulation(Regulation),  // 규정별 본수 기준 상한 (Regulation::conduit_fill_limit)
+    Fixed(f64),              // 본수와 무관한 고정 비율 (전선관 분배 등 사용자 지정)
+}
+
+impl FillLimit {
+    /// 관 내 전선 본수에 적용할 상한 비율
+    fn ratio(self, cable_count: u32) -> f64 {
+        match self {
+            FillLimit::Regulation(regulation) => regulation.conduit_fill_limit(cable_count),
+            FillLimit::Fixed(ratio) => ratio,
+        }
+    }
+}
+
+/// 면적 기준 추천 후 원형 패킹 배열 검증, 배열 불가 시 한 단계씩 큰 관으로 재추천
+/// cable_diameters: 관에 들어가는 모든 가닥의 외경 (mm), 점유율 상한은 이 본수로 fill_limit에서 결정
+fn recommend_conduit_for_cables(conduit_type: ConduitType, total_area: f64, fill_limit: FillLimit, cable_diameters: &[f64]) -> RacewayRecommendation {
+    let largest_diameter = cable_diameters.iter().copied().fold(0.0, f64::max);
+    let max_fill_rate = fill_limit.ratio(cable_diameters.len() as u32);
+    let recommendation = recommend_conduit(conduit_type, total_area, max_fill_rate, largest_diameter);
+    let inner_diameter = recommendation.inner_diameter.unwrap_or_default();
+    if recommendation.status != "정상" || can_fit_geometrically(inner_diameter, cable_diameters.to_vec()) {
+        return recommendation;
+    }
+
+    let larger = conduit_table(conduit_type)
+        .iter()
+        .filter(|spec| spec.inner_diameter > inner_diameter)
+        .find(|spec| can_fit_geometrically(spec.inner_diameter, cable_diameters.to_vec()));
+    tracing::trace!(conduit = %recommendation.name, refit = ?larger.map(|spec| spec.name), "전선관 배열 불가");
+    let with_previous = |note: String| match &recommendation.geometry_note {
+        Some(previous) => format!("{}; {}", previous, note),
+        None => note,
+    };
+    match larger {
+        Some(spec) => RacewayRecommendation {
+            kind: RacewayKind::Conduit,
+            nominal: spec.nominal,
+            name: spec.name.to_string(),
+            inner_diameter: Some(spec.inner_diameter),
+            fill_rate: (total_area / spec.area) * 100.0,
+            status: "정상".to_string(),
+            fill_limit: recommendation.fill_limit,
+            geometry_note: Some(with_previous(format!("면적 기준 {} 배열 불가 → {} 재추천", recommendation.name, spec.name))),
+        },
+        None => RacewayRecommendation {
+            name: format!("{} 이상 검토 필요", largest_conduit(conduit_type).short_name()),
+            status: "분할 필요".to_string(),
+            geometry_note: Some(with_previous(format!("면적 기준 {} 배열 불가, 더 큰 관으로도 배열 불가", recommendation.name))),
+            ..recommendation
+        },
+    }
+}
+
+/// 전선 종류에 따른 절연체 반환
+fn get_insulation_type(cable_type: &str) -> Insulation {
+    match cable_type {
+        "HFIX" => Insulation::Xlpe,     // 저독성 가교 폴리올레핀 (90°C)
+        "CV" | "TFR-CV" | "FR-CV" | "TFR-8" => Insulation::Xlpe,  // 가교 폴리에틸렌 (90°C)
+        _ => Insulation::Pvc,          // 비닐 (70°C)
+    }
+}
+
+/// 전선 종류 목록 반환 (KEC 기준)
+pub fn get_cable_types() -> Vec<CableTypeInfo> {
+    vec![
+        CableTypeInfo {
+            code: "HFIX".to_string(),
+            name: "HFIX (저독성 난연 전선)".to_string(),
+            description: "KS C 3341, 저독성 난연 폴리올레핀 절연".to_string(),
+            max_temp: 90,
+            insulation: "XLPE".to_string(),
+            fire_rating: "난연".to_string(),
+        },
+        CableTypeInfo {
+            code: "TFR-CV".to_string(),
+            name: "TFR-CV (난연 트레이용)".to_string(),
+            description: "0.6/1kV 가교폴리에틸렌 절연 난연 PVC 시스".to_string(),
+            max_temp: 90,
+            insulation: "XLPE".to_string(),
+            fire_rating: "난연".to_string(),
+        },
+        CableTypeInfo {
+            code: "CV".to_string(),
+            name: "CV (일반 전력 케이블)".to_string(),
+            description: "0.6/1kV 가교폴리에틸렌 절연 비닐 시스".to_string(),
+            max_temp: 90,
+            insulation: "XLPE".to_string(),
+            fire_rating: "일반".to_string(),
+        },
+        CableTypeInfo {
+            code: "FR-CV".to_string(),
+            name: "FR-CV (내화 케이블)".to_string(),
+            description: "0.6/1kV 내화 가교폴리에틸렌 절연".to_string(),
+            max_temp: 90,
+            insulation: "XLPE".to_string(),
+            fire_rating: "내화".to_string(),
+        },
+        CableTypeInfo {
+            code: "TFR-8".to_string(),
+            name: "TFR-8 (내열 케이블)".to_string(),
+            description: "0.6/1kV 내열 가교폴리에틸렌 절연".to_string(),
+            max_temp: 90,
+            insulation: "XLPE".to_string(),
+            fire_rating: "내열".to_string(),
+        },
+    ]
+}
+
+/// 언어별 전선 종류 목록 (lang: ko, en, 그 외는 ko)
+/// 표시명·설명만 번역하며 코드·절연체·난연 등급 값은 언어 무관
+pub fn get_cable_types_localized(lang: &str) -> Vec<CableTypeInfo> {
+    let mut types = get_cable_types();
+    if label_lang(lang) == "en" {
+        for info in &mut types {
+            let (name, description) = match info.code.as_str() {
+                "HFIX" => ("HFIX (low-smoke flame-retardant wire)", "KS C 3341, low-smoke halogen-free polyolefin insulation"),
+                "TFR-CV" => ("TFR-CV (flame-retardant, tray rated)", "0.6/1kV XLPE insulated, flame-retardant PVC sheathed"),
+                "CV" => ("CV (general power cable)", "0.6/1kV XLPE insulated, PVC sheathed"),
+                "FR-CV" => ("FR-CV (fire-resistant cable)", "0.6/1kV fire-resistant XLPE insulated"),
+                "TFR-8" => ("TFR-8 (heat-resistant cable)", "0.6/1kV heat-resistant XLPE insulated"),
+                _ => continue,
+            };
+            info.name = name.to_string();
+            info.description = description.to_string();
+        }
+    }
+    types
+}
+
+/// 난연/내화 등급 순서 (뒤로 갈수록 상위, 상위 등급은 하위 요구를 만족)
+const FIRE_RATINGS: &[&str] = &["일반", "난연", "내열", "내화"];
+
+/// 설치 구역별 요구 등급과 근거 (구역, 최소 등급, 근거)
+const ZONE_FIRE_REQUIREMENTS: &[(&str, &str, &str)] = &[
+    ("일반", "일반", "KEC 232 (일반 배선, 별도 요구 없음)"),
+    ("방재", "난연", "KS C IEC 60332 난연성 (방재구역 연소 확산 방지)"),
+    ("피난", "내열", "NFTC 303 2.7 (유도등·피난설비 내열배선)"),
+    ("비상전원", "내화", "NFTC 102 2.7 (비상전원 회로 내화배선)"),
+];
+
+/// 난연/내화 적합성 판정 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComplianceResult {
+    pub cable_type: String,
+    pub zone: String,
+    pub compliant: bool,
+    pub cable_rating: String,       // 입력 케이블 등급
+    pub required_rating: String,    // 구역 최소 요구 등급
+    pub basis: String,              // 근거 조항
+    pub alternatives: Vec<String>,  // 구역에 적합한 종류 (부적합 시 대체 제안)
+}
+
+fn fire_rating_level(rating: &str) -> usize {
+    FIRE_RATINGS.iter().position(|r| *r == rating).unwrap_or(0)
+}
+
+/// 설치 구역(일반/방재/피난/비상전원)에 대한 케이블 난연/내화 적합성 판정 (lang: ko/en)
+pub fn check_fire_compliance(cable_type: &str, zone: &str, lang: &str) -> Result<ComplianceResult, String> {
+    let check = || -> Result<ComplianceResult, &'static str> {
+        let types = get_cable_types();
+        let info = types.iter().find(|info| info.code == cable_type).ok_or(ERR_UNKNOWN_CABLE_TYPE)?;
+        let (_, required, basis) = ZONE_FIRE_REQUIREMENTS
+            .iter()
+            .find(|(z, _, _)| *z == zone)
+            .ok_or(ERR_UNKNOWN_ZONE)?;
+        let required_level = fire_rating_level(required);
+        let alternatives = types
+            .iter()
+            .filter(|t| fire_rating_level(&t.fire_rating) >= required_level)
+            .map(|t| t.code.clone())
+            .collect();
+        Ok(ComplianceResult {
+            cable_type: cable_type.to_string(),
+            zone: zone.to_string(),
+            compliant: fire_rating_level(&info.fire_rating) >= required_level,
+            cable_rating: info.fire_rating.clone(),
+            required_rating: required.to_string(),
+            basis: basis.to_string(),
+            alternatives,
+        })
+    };
+    check().map_err(|key| localize_error(key, lang))
+}
+
+/// 심선 색상·용도 (KEC 121.2 전선 식별: L1 갈색, L2 흑색, L3 회색, N 청색, PE 녹색-노란색)
+/// 반환: (색상, 용도) 배열. 2C는 단상(L/N), 3C·4C는 3상 기준
+/// 현재 지원 종류(HFIX, CV 계열)는 접지심이 없는 구성으로, 접지는 별도 전선으로 포설
+/// 단심은 절연체 색상 1개, 지원하지 않는 조합은 빈 벡터
+pub fn get_core_colors(cable_type: &str, cores: &str) -> Vec<(String, String)> {
+    let colors: &[(&str, &str)] = match (cable_type, cores) {
+        ("HFIX" | "CV" | "TFR-CV" | "FR-CV" | "TFR-8", "1C") => &[("흑색", "상/중성 (단말부 색상 표시로 식별)")],
+        ("CV" | "TFR-CV" | "FR-CV" | "TFR-8", "2C") => &[("갈색", "상(L)"), ("청색", "중성(N)")],
+        ("CV" | "TFR-CV" | "FR-CV" | "TFR-8", "3C") => &[("갈색", "상(L1)"), ("흑색", "상(L2)"), ("회색", "상(L3)")],
+        ("CV" | "TFR-CV" | "FR-CV" | "TFR-8", "4C") => {
+            &[("갈색", "상(L1)"), ("흑색", "상(L2)"), ("회색", "상(L3)"), ("청색", "중성(N)")]
+        }
+        _ => &[],
+    };
+    colors.iter().map(|(color, role)| (color.to_string(), role.to_string())).collect()
+}
+
+/// 절연체별 도체 최고 허용온도 (°C)
+fn get_max_conductor_temp(insulation: Insulation) -> u32 {
+    match insulation {
+        Insulation::Xlpe => 90,
+        Insulation::Pvc => 70,
+    }
+}
+
+/// 단자 온도 등급 하한 (°C, 허용전류 열 60/75/90 중 최저)
+const MIN_TERMINATION_TEMP: u32 = 60;
+
+/// 케이블·단자 온도 등급 혼용 시 유효 온도 등급 (NEC 110.14(C) 유사 개념)
+/// 단자 등급이 케이블 등급 이상이면 케이블 등급 그대로,
+/// 낮으면 단자 등급 이하인 허용전류 열(90/75/60°C) 중 가장 높은 열
+pub fn apply_termination_temp_limit(cable_temp: u32, termination_temp: u32) -> u32 {
+    if termination_temp >= cable_temp {
+        return cable_temp;
+    }
+    [90, 75, MIN_TERMINATION_TEMP]
+        .into_iter()
+        .find(|column| *column <= termination_temp)
+        .unwrap_or(MIN_TERMINATION_TEMP)
+}
+
+const AIR_BASE_AMBIENT_TEMP: i32 = 30;     // 공기 중 허용전류 표 기준 주변온도 (°C)
+const GROUND_BASE_AMBIENT_TEMP: i32 = 20;  // 지중(D1/D2) 허용전류 표 기준 토양온도 (°C)
+
+/// 허용전류 표의 기준 주변온도 (°C): 공기 중(A1~C, E, F) 30°C, 지중(D1/D2) 20°C
+/// 지중 표 값을 공기 기준으로 보정하면 허용전류가 과대평가되므로 보정표도 이 기준으로 분기
+pub fn base_ambient_temp(method: InstallMethod) -> i32 {
+    reference_ambient_temp(method.is_buried())
+}
+
+/// 지중 여부로 기준 주변온도 선택 (base_ambient_temp 참조)
+fn reference_ambient_temp(buried: bool) -> i32 {
+    if buried {
+        GROUND_BASE_AMBIENT_TEMP
+    } else {
+        AIR_BASE_AMBIENT_TEMP
+    }
+}
+
+/// 도체 온도를 단자 등급으로 제한할 때의 허용전류 비율
+/// 주변온도 보정과 같은 산정식 k = √((θlimit - θref) / (θmax - θref))
+fn termination_temp_factor(max_temp: u32, applied_temp: u32, buried: bool) -> f64 {
+    let base_temp = reference_ambient_temp(buried) as f64;
+    if applied_temp >= max_temp {
+        return 1.0;
+    }
+    ((applied_temp as f64 - base_temp) / (max_temp as f64 - base_temp)).sqrt()
+}
+
+/// 주변온도 보정계수 표 (IEC 60364-5-52, 주변온도 °C, PVC, XLPE)
+/// 공기 중: Table B.52.14 (기준 30°C), 지중: Table B.52.15 (기준 20°C)
+/// PVC는 60°C까지만 규정 (None)
+const AIR_TEMP_FACTORS: &[(i32, Option<f64>, f64)] = &[
+    (10, Some(1.22), 1.15), (15, Some(1.17), 1.12), (20, Some(1.12), 1.08), (25, Some(1.06), 1.04),
+    (30, Some(1.00), 1.00), (35, Some(0.94), 0.96), (40, Some(0.87), 0.91), (45, Some(0.79), 0.87),
+    (50, Some(0.71), 0.82), (55, Some(0.61), 0.76), (60, Some(0.50), 0.71), (65, None, 0.65),
+    (70, None, 0.58), (75, None, 0.50), (80, None, 0.41),
+];
+const GROUND_TEMP_FACTORS: &[(i32, Option<f64>, f64)] = &[
+    (10, Some(1.10), 1.07), (15, Some(1.05), 1.04), (20, Some(1.00), 1.00), (25, Some(0.95), 0.96),
+    (30, Some(0.89), 0.93), (35, Some(0.84), 0.89), (40, Some(0.77), 0.85), (45, Some(0.71), 0.80),
+    (50, Some(0.63), 0.76), (55, Some(0.55), 0.71), (60, Some(0.45), 0.65), (65, None, 0.60),
+    (70, None, 0.53), (75, None, 0.46), (80, None, 0.38),
+];
+
+/// 주변온도 보정계수 표 조회 (표 사이 온도는 인접 값 선형보간, 소수 셋째 자리 반올림)
+/// 표 범위 밖이면 ERR_AMBIENT_TEMP_OUT_OF_RANGE
+fn temperature_factor(insulation: Insulation, ambient_temp: i32, buried: bool) -> Result<f64, &'static str> {
+    let table = if buried { GROUND_TEMP_FACTORS } else { AIR_TEMP_FACTORS };
+    let points: Vec<(i32, f64)> = table
+        .iter()
+        .filter_map(|&(temp, pvc, xlpe)| match insulation {
+            Insulation::Pvc => pvc.map(|factor| (temp, factor)),
+            Insulation::Xlpe => Some((temp, xlpe)),
+        })
+        .collect();
+    if let Some(&(_, factor)) = points.iter().find(|(temp, _)| *temp == ambient_temp) {
+        return Ok(factor);
+    }
+    let (&(t0, f0), &(t1, f1)) = points
+        .windows(2)
+        .find(|w| w[0].0 < ambient_temp && ambient_temp < w[1].0)
+        .map(|w| (&w[0], &w[1]))
+        .ok_or(ERR_AMBIENT_TEMP_OUT_OF_RANGE)?;
+    let factor = f0 + (f1 - f0) * (ambient_temp - t0) as f64 / (t1 - t0) as f64;
+    Ok((factor * 1000.0).round() / 1000.0)
+}
+
+/// 주변온도 보정계수 (IEC 60364-5-52 Table B.52.14 공기 / B.52.15 지중)
+/// insulation: "PVC" 또는 "XLPE", buried: 지중 공사방법(D1/D2) 여부.
+/// 표 사이 온도는 선형보간, 표 범위(10°C~, PVC 60°C / XLPE 80°C) 밖이면 Err (lang: ko/en)
+pub fn get_temperature_factor(insulation: &str, ambient_temp: i32, buried: bool, lang: &str) -> Result<f64, String> {
+    Insulation::try_from(insulation.trim())
+        .and_then(|insulation| temperature_factor(insulation, ambient_temp, buried))
+        .map_err(|key| localize_error(key, lang))
+}
+
+/// 토양 열저항률 보정계수 표 (IEC 60364-5-52 Table B.52.16, 기준 2.5 K·m/W): (열저항률, 계수)
+const SOIL_RESISTIVITY_FACTORS_DUCT: &[(f64, f64)] = &[
+    (1.0, 1.18), (1.5, 1.10), (2.0, 1.05), (2.5, 1.00), (3.0, 0.96),
+];
+const SOIL_RESISTIVITY_FACTORS_DIRECT: &[(f64, f64)] = &[
+    (1.0, 1.50), (1.5, 1.28), (2.0, 1.12), (2.5, 1.00), (3.0, 0.90),
+];
+
+/// (x, 계수) 표에서 선형보간 (소수 셋째 자리 반올림), 표 범위 밖은 가까운 끝값
+fn interpolate_factor(table: &[(f64, f64)], x: f64) -> f64 {
+    let (first, last) = (table[0], table[table.len() - 1]);
+    if x <= first.0 {
+        return first.1;
+    }
+    table.windows(2).find(|w| x <= w[1].0).map_or(last.1, |w| {
+        let ((x0, f0), (x1, f1)) = (w[0], w[1]);
+        let factor = f0 + (f1 - f0) * (x - x0) / (x1 - x0);
+        (factor * 1000.0).round() / 1000.0
+    })
+}
+
+/// 토양 열저항률 보정계수 (D1 지중 덕트 / D2 직매 열 구분, 공기 중 공사방법은 1.0)
+fn soil_resistivity_factor(rho: f64, install_method: InstallMethod) -> f64 {
+    match install_method {
+        InstallMethod::D1 => interpolate_factor(SOIL_RESISTIVITY_FACTORS_DUCT, rho),
+        InstallMethod::D2 => interpolate_factor(SOIL_RESISTIVITY_FACTORS_DIRECT, rho),
+        _ => 1.0,
+    }
+}
+
+/// 토양 열저항률 보정계수 (IEC 60364-5-52 Table B.52.16, 기준 2.5 K·m/W = 1.00)
+/// 1.0~3.0 K·m/W 사이는 선형보간, 범위 밖은 끝값. D1/D2가 아니거나 알 수 없는 공사방법은 1.0
+pub fn get_soil_resistivity_factor(rho: f64, install_method: &str) -> f64 {
+    InstallMethod::try_from(install_method.trim()).map_or(1.0, |method| soil_resistivity_factor(rho, method))
+}
+
+/// 4C 중성선이 부하 도체일 때 3부하 허용전류에 곱하는 감소계수 (IEC 60364-5-52 Annex E)
+const NEUTRAL_LOADED_FACTOR: f64 = 0.86;
+
+/// 3고조파 보정계수 (IEC 60364-5-52 Appendix E Table E.52.1, 4심·5심 케이블 3상 4선)
+/// 반환: (계수, 중성선 전류 기준 사이징 여부)
+/// 15% 이하 1.0, 15~33% 0.86(상전류 기준), 33~45% 0.86(중성선 기준), 45% 초과 1.0(중성선 기준)
+pub fn get_harmonic_factor(third_harmonic_percent: f64) -> (f64, bool) {
+    match third_harmonic_percent {
+        h if h <= 15.0 => (1.0, false),
+        h if h <= 33.0 => (0.86, false),
+        h if h <= 45.0 => (0.86, true),
+        _ => (1.0, true),
+    }
+}
+
+/// 집합 보정 계수 (KEC / IEC 60364-5-52 Table B.52.17·B.52.20·B.52.21, 배치 형태별)
+/// 다층 트레이의 9회로 초과는 표 밖이므로 묶음 계수와 비교해 작은 값 적용
+fn get_grouping_factor(num_circuits: u32, arrangement: Arrangement) -> f64 {
+    match arrangement {
+        Arrangement::Conduit => bunched_grouping_factor(num_circuits),
+        Arrangement::SingleLayerTray => match num_circuits {
+            0 | 1 => 1.00,
+            2 => 0.88,
+            3 => 0.82,
+            4 => 0.77,
+            5 => 0.75,
+            6 | 7 => 0.73,
+            _ => 0.72,
+        },
+        Arrangement::MultiLayerTray => match num_circuits {
+            0 | 1 => 1.00,
+            2 => 0.86,
+            3 => 0.76,
+            4 => 0.71,
+            5 | 6 => 0.66,
+            7..=9 => 0.61,
+            n => bunched_grouping_factor(n).min(0.61),
+        },
+        Arrangement::Spaced => match num_circuits {
+            0..=2 => 1.00,
+            3 => 0.98,
+            4 => 0.95,
+            _ => 0.91,
+        },
+    }
+}
+
+/// 묶음(전선관·밀집) 집합 보정 계수 (Table B.52.17 항목 1)
+fn bunched_grouping_factor(num_circuits: u32) -> f64 {
+    match num_circuits {
+        0 | 1 => 1.00,
+        2 => 0.80,
+        3 => 0.70,
+        4 => 0.65,
+        5 => 0.60,
+        6 => 0.57,
+        7 => 0.54,
+        8 => 0.52,
+        9 => 0.50,
+        10..=12 => 0.45,
+        13..=16 => 0.41,
+        17..=20 => 0.38,
+        _ => 0.38, // 20회로 초과 시 0.38 적용 (보수적 접근)
+    }
+}
+
+/// 허용전류 보정 (기본값 × 각 보정계수의 곱)
+/// 보정계수는 서로 독립이므로 적용 순서와 무관, 계수마다 한 번씩만 전달할 것
+fn apply_derating(base_current: Amperes, factors: &[f64]) -> Amperes {
+    let derated = factors.iter().fold(base_current, |current, factor| current * *factor);
+    tracing::trace!(base_current = %base_current, factors = ?factors, derated = %derated, "보정계수 적용");
+    derated
+}
+
+/// 가닥수와 전압 방식으로 부하 도체 수 결정 (2 또는 3)
+/// 2C: 단상 2선 전용이므로 항상 2부하
+/// 1C/3C/4C: 1Φ -> 2부하, 3Φ -> 3부하
+/// DC: +, - 2도체이므로 2부하
+fn determine_loaded_conductors(cores: &str, system: &str) -> u8 {
+    match (cores, system) {
+        ("2C", _) | (_, "DC") => 2,
+        (_, "3Φ") => 3,
+        _ => 2,
+    }
+}
+
+/// 가닥수/전압 방식 모순 조합 차단 (get_cores_for_system 기준)
+/// 2C는 3상 회로에, 4C는 단상 회로에, 3C/4C는 직류 회로에 사용할 수 없음
+fn validate_combination(cores: &str, system: &str) -> Result<(), &'static str> {
+    match (cores, system) {
+        ("3C" | "4C", "DC") => Err(ERR_CORES_IN_DC),
+        ("2C", "3Φ") => Err(ERR_2C_IN_THREE_PHASE),
+        ("4C", "1Φ") => Err(ERR_4C_IN_SINGLE_PHASE),
+        _ => Ok(()),
+    }
+}
+
+/// 집합 보정 계수 산정용 회로 수
+/// 1C(단심) 케이블인 경우, 회로 수 계산:
+/// 1Φ: 2가닥 = 1회로
+/// 3Φ: 3가닥 = 1회로
+/// 나누어떨어지지 않는 가닥수는 정책에 따라 올림/내림/에러
+fn count_circuits(cores: &str, system: &str, quantity: u32, policy: CircuitCountPolicy) -> Result<u32, &'static str> {
+    if cores == "1C" {
+        let cables_per_circuit = determine_loaded_conductors(cores, system) as u32;
+        match policy {
+            CircuitCountPolicy::CeilUp => Ok(quantity.div_ceil(cables_per_circuit)),
+            CircuitCountPolicy::FloorDown => Ok(quantity / cables_per_circuit),
+            CircuitCountPolicy::RequireExact if quantity.is_multiple_of(cables_per_circuit) => Ok(quantity / cables_per_circuit),
+            CircuitCountPolicy::RequireExact => Err(ERR_CIRCUIT_COUNT_NOT_EXACT),
+        }
+    } else {
+        // 다심 케이블은 수량 자체가 회로 수
+        Ok(quantity)
+    }
+}
+
+/// 입력의 정책으로 회로 수 산정
+fn data_circuit_count(data: &CableData) -> Result<u32, &'static str> {
+    count_circuits(&data.cores, &data.system, data.quantity, data.circuit_count_policy.unwrap_or_default())
+}
+
+/// 집합 보정 대상 회로 수 (병렬 런은 런마다 별도 회로로 집계)
+fn grouping_circuit_count(data: &CableData) -> Result<u32, &'static str> {
+    Ok(data_circuit_count(data)? * data.parallel_runs.max(1))
+}
+
+/// 공사방법 설명 (lang: ko, en)
+fn get_install_method_description(method: InstallMethod, lang: &str) -> String {
+    let desc = match (method, label_lang(lang)) {
+        (InstallMethod::A1, "en") => "Conduit in thermally insulated wall (insulated conductors/single-core cables)",
+        (InstallMethod::A2, "en") => "Conduit in thermally insulated wall (multi-core cable)",
+        (InstallMethod::B1, "en") => "Conduit on wall (insulated conductors/single-core cables)",
+        (InstallMethod::B2, "en") => "Conduit on wall (multi-core cable)",
+        (InstallMethod::C, "en") => "Clipped direct to wall/ceiling (in air)",
+        (InstallMethod::D1, "en") => "Underground duct",
+        (InstallMethod::D2, "en") => "Direct buried in ground",
+        (InstallMethod::E, "en") => "Perforated cable tray (single-core)",
+        (InstallMethod::F, "en") => "Perforated cable tray (multi-core)",
+        (InstallMethod::A1, _) => "단열벽 속 전선관 (절연전선/단심 케이블)",
+        (InstallMethod::A2, _) => "단열벽 속 전선관 (다심 케이블)",
+        (InstallMethod::B1, _) => "벽면 고정 전선관 (절연전선/단심 케이블)",
+        (InstallMethod::B2, _) => "벽면 고정 전선관 (다심 케이블)",
+        (InstallMethod::C, _) => "벽면/천정 직접 고정 (공기 중)",
+        (InstallMethod::D1, _) => "지중 매설 덕트",
+        (InstallMethod::D2, _) => "지중 매설 직매",
+        (InstallMethod::E, _) => "케이블 트레이 (천공형, 단심)",
+        (InstallMethod::F, _) => "케이블 트레이 (천공형, 다심)",
+    };
+    desc.to_string()
+}
+
+/// 공사방법 코드의 설명 조회 (lang: ko, en, 그 외는 ko), 알 수 없는 코드면 None
+pub fn describe_install_method(method: &str, lang: &str) -> Option<String> {
+    InstallMethod::try_from(method.trim())
+        .ok()
+        .map(|method| get_install_method_description(method, lang))
+}
+
+/// 부하 도체 수 표시 라벨 (예: "2부하(단상)", lang: ko, en)
+fn loaded_label(loaded_conductors: u8, system: &str, lang: &str) -> &'static str {
+    match (loaded_conductors, system, label_lang(lang)) {
+        (3, _, "en") => "3 loaded (three-phase)",
+        (_, "1Φ", "en") => "2 loaded (single-phase)",
+        (_, "DC", "en") => "2 loaded (DC)",
+        (_, _, "en") => "2 loaded (default)",
+        (3, _, _) => "3부하(3상)",
+        (_, "1Φ", _) => "2부하(단상)",
+        (_, "DC", _) => "2부하(직류)",
+        _ => "2부하(기본)",
+    }
+}
+
+/// 공사방법 선택 목록 (코드, 표시명)
+pub fn get_install_methods(lang: &str) -> Vec<(String, String)> {
+    InstallMethod::ALL
+        .iter()
+        .map(|method| (method.as_str().to_string(), method.label(lang)))
+        .collect()
+}
+
+/// 가닥수에 맞는 공사방법 선택 목록 (코드, 표시명), 알 수 없는 가닥수면 빈 목록
+pub fn get_install_methods_for_cores(cores: &str, lang: &str) -> Vec<(String, String)> {
+    install_methods_for_cores(cores)
+        .iter()
+        .map(|method| (method.as_str().to_string(), method.label(lang)))
+        .collect()
+}
+
+/// 가닥수 표시명 (예: "1C (단심)"), 알 수 없는 코드는 코드 그대로
+pub fn core_label(cores: &str, lang: &str) -> String {
+    let name = match (cores, label_lang(lang)) {
+        ("1C", "en") => "single-core",
+        ("2C", "en") => "2-core",
+        ("3C", "en") => "3-core",
+        ("4C", "en") => "4-core",
+        ("1C", _) => "단심",
+        ("2C", _) => "2심",
+        ("3C", _) => "3심",
+        ("4C", _) => "4심",
+        _ => return cores.to_string(),
+    };
+    format!("{} ({})", cores, name)
+}
+
+/// 가닥수별 적용 전압 방식 표시명 (예: "2C (단상 2선 / 직류 +/-)"), 알 수 없는 코드는 None
+pub fn core_system_label(cores: &str, lang: &str) -> Option<&'static str> {
+    Some(match (cores, label_lang(lang)) {
+        ("1C", "en") => "1C (single-core)",
+        ("2C", "en") => "2C (1-phase 2-wire / DC +/-)",
+        ("3C", "en") => "3C (1-phase 3-wire / 3-phase 3-wire)",
+        ("4C", "en") => "4C (3-phase 4-wire)",
+        ("1C", _) => "1C (단심)",
+        ("2C", _) => "2C (단상 2선 / 직류 +/-)",
+        ("3C", _) => "3C (단상 3선 / 3상 3선)",
+        ("4C", _) => "4C (3상 4선)",
+        _ => return None,
+    })
+}
+
+/// 공사방법 결정 (미지정 시 단심 B1, 다심 B2)
+fn resolve_install_method(data: &CableData) -> Result<InstallMethod, &'static str> {
+    if data.install_method.is_empty() {
+        match data.cores.as_str() {
+            "1C" => Ok(InstallMethod::B1),
+            _ => Ok(InstallMethod::B2),
+        }
+    } else {
+        InstallMethod::try_from(data.install_method.as_str())
+    }
+}
+
+/// 도체 재질별 현재 계산에 쓰이는 허용전류 테이블
+/// 동은 활성 테이블(사용자 정의 포함), 알루미늄은 활성 버전 수록 범위의 알루미늄 테이블
+fn active_current_table_for(material: Material) -> Arc<CurrentTable> {
+    match material {
+        Material::Copper => active_current_table(),
+        Material::Aluminium => with_active_current_tables(|tables| Arc::clone(&tables.aluminium)),
+    }
+}
+
+/// 도체 재질별 허용전류 테이블 기본값 조회 (보정 전)
+fn lookup_base_current_for(
+    material: Material,
+    size: Size,
+    insulation: Insulation,
+    method: InstallMethod,
+    loaded_conductors: u8,
+) -> Result<Amperes, &'static str> {
+    if material == Material::Copper {
+        return lookup_base_current(size, insulation, method, loaded_conductors);
+    }
+    let &(c2, c3) = active_current_table_for(material)
+        .get(&(size, insulation, method))
+        .ok_or(ERR_CURRENT_DATA_NOT_FOUND)?;
+    Ok(Amperes(if loaded_conductors == 3 { c3 } else { c2 }))
+}
+
+/// 허용전류 테이블 기본값 조회 (보정 전)
+/// 2 Loaded -> 테이블 .0, 3 Loaded -> 테이블 .1
+fn lookup_base_current(size: Size, insulation: Insulation, method: InstallMethod, loaded_conductors: u8) -> Result<Amperes, &'static str> {
+    let current_table = active_current_table();
+    let Some(current_values) = current_table.get(&(size, insulation, method)) else {
+        tracing::trace!(size = size.as_str(), insulation = insulation.as_str(), method = method.as_str(), "허용전류 테이블 항목 없음");
+        return Err(ERR_CURRENT_DATA_NOT_FOUND);
+    };
+    let base_current = if loaded_conductors == 3 { current_values.1 } else { current_values.0 };
+    tracing::trace!(
+        size = size.as_str(),
+        insulation = insulation.as_str(),
+        method = method.as_str(),
+        loaded_conductors,
+        base_current,
+        "허용전류 테이블 조회"
+    );
+    Ok(Amperes(base_current))
+}
+
+/// 용도·전압 방식별 최소 규격 (용도, 전압 방식("*"는 전체), 최소 규격 mm²)
+/// 위에서부터 처음 일치하는 규칙 적용 (KEC 231.3.1 저압 전선 최소 굵기 기준)
+const MINIMUM_SIZES: &[(&str, &str, &str)] = &[
+    ("동력 간선", "3Φ", "4"),
+    ("동력 간선", "*", "2.5"),
+    ("동력 분기", "*", "2.5"),
+    ("전등 간선", "*", "2.5"),
+    ("전등 분기", "*", "1.5"),
+    ("제어", "*", "1.5"),
+];
+
+/// 용도·전압 방식별 최소 규격 (mm², lang: ko/en)
+pub fn get_minimum_size(usage: &str, system: &str, lang: &str) -> Result<&'static str, String> {
+    minimum_size(usage.trim(), system.trim()).map_err(|key| localize_error(key, lang))
+}
+
+fn minimum_size(usage: &str, system: &str) -> Result<&'static str, &'static str> {
+    MINIMUM_SIZES
+        .iter()
+        .find(|(u, s, _)| *u == usage && (*s == "*" || *s == system))
+        .map(|(_, _, size)| *size)
+        .ok_or(ERR_UNKNOWN_USAGE)
+}
+
+/// 입력 용도의 최소 규격 제약 설명 (용도 미지정 시 None)
+fn minimum_size_note(data: &CableData) -> Result<Option<String>, &'static str> {
+    let Some(usage) = data.usage.as_deref().map(str::trim).filter(|u| !u.is_empty()) else {
+        return Ok(None);
+    };
+    let minimum = minimum_size(usage, &data.system)?;
+    let lang = error_lang(data);
+    Ok(Some(match label_lang(lang) {
+        "en" => format!("{} minimum {} mm²", desc_label(usage, lang), minimum),
+        _ => format!("{} 최소 {} mm²", usage, minimum),
+    }))
+}
+
+const MAX_NEAREST_SUGGESTIONS: usize = 5;
+
+/// 데이터 없는 조합의 대안 제안 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NearestAvailable {
+    pub suggestions: Vec<(String, String, String)>,  // (절연, 공사방법, 규격), 허용전류 차이가 작은 순
+    pub reason: Option<String>,                      // 제안이 없을 때 사유
+}
+
+/// 같은 절연·공사방법의 다른 규격, 같은 규격·절연의 다른 공사방법 중 데이터가 있는 조합을
+/// 기준 허용전류(3부하)와의 차이가 작은 순으로 반환
+/// 기준: 같은 규격의 다른 공사방법 평균, 없으면 같은 방법에서 단면적이 가장 가까운 규격
+fn nearest_available(table: &CurrentTable, size: Size, insulation: Insulation, method: InstallMethod) -> Vec<(Size, InstallMethod)> {
+    let same_size: Vec<(Size, InstallMethod, f64)> = InstallMethod::ALL
+        .into_iter()
+        .filter(|m| *m != method)
+        .filter_map(|m| table.get(&(size, insulation, m)).map(|c| (size, m, c.1)))
+        .collect();
+    let same_method: Vec<(Size, InstallMethod, f64)> = STANDARD_SIZES
+        .iter()
+        .map(|s| Size(s))
+        .filter(|s| *s != size)
+        .filter_map(|s| table.get(&(s, insulation, method)).map(|c| (s, method, c.1)))
+        .collect();
+
+    let reference = if same_size.is_empty() {
+        let log_distance = |s: Size| (s.mm2() / size.mm2()).ln().abs();
+        match same_method.iter().min_by(|a, b| log_distance(a.0).total_cmp(&log_distance(b.0))) {
+            Some(nearest) => nearest.2,
+            None => return Vec::new(),
+        }
+    } else {
+        same_size.iter().map(|c| c.2).sum::<f64>() / same_size.len() as f64
+    };
+
+    let mut candidates: Vec<(Size, InstallMethod, f64)> = same_size.into_iter().chain(same_method).collect();
+    candidates.sort_by(|a, b| (a.2 - reference).abs().total_cmp(&(b.2 - reference).abs()));
+    candidates
+        .into_iter()
+        .take(MAX_NEAREST_SUGGESTIONS)
+        .map(|(s, m, _)| (s, m))
+        .collect()
+}
+
+/// 허용전류 데이터가 없는 조합에 대한 유사 조합 제안 (활성 데이터셋 기준, lang: ko/en)
+pub fn suggest_nearest_available(size: &str, insulation: &str, method: &str, lang: &str) -> NearestAvailable {
+    let parsed = || -> Result<(Size, Insulation, InstallMethod), &'static str> {
+        Ok((
+            Size::try_from(size.trim())?,
+            Insulation::try_from(insulation.trim())?,
+            InstallMethod::try_from(method.trim())?,
+        ))
+    };
+    let (size, insulation, method) = match parsed() {
+        Ok(key) => key,
+        Err(key) => return NearestAvailable { suggestions: vec![], reason: Some(localize_error(key, lang)) },
+    };
+    let suggestions: Vec<(String, String, String)> = nearest_available(&active_current_table(), size, insulation, method)
+        .into_iter()
+        .map(|(s, m)| (insulation.as_str().to_string(), m.as_str().to_string(), s.as_str().to_string()))
+        .collect();
+    let reason = suggestions
+        .is_empty()
+        .then(|| match label_lang(lang) {
+            "en" => format!(
+                "No current-carrying capacity data for {} insulation with method {} or size {}mm².",
+                insulation.as_str(),
+                method.as_str(),
+                size.as_str()
+            ),
+            _ => format!("{} 절연의 {} 공사방법이나 {}mm² 규격에 허용전류 데이터가 없습니다.", insulation.as_str(), method.as_str(), size.as_str()),
+        });
+    NearestAvailable { suggestions, reason }
+}
+
+/// 허용전류 조회 실패 에러에 덧붙일 가장 가까운 조합 힌트
+fn nearest_available_hint(data: &CableData, method: InstallMethod, lang: &str) -> Option<String> {
+    let size = Size::try_from(data.size.as_str()).ok()?;
+    let insulation = get_insulation_type(&data.cable_type);
+    let table = active_current_table_for(resolve_material(data).ok()?);
+    let (s, m) = *nearest_available(&table, size, insulation, method).first()?;
+    let combination = format!("{}/{}/{}mm²", insulation.as_str(), m.as_str(), s.as_str());
+    Some(match label_lang(lang) {
+        "en" => format!("nearest available combination: {}", combination),
+        _ => format!("가장 가까운 사용 가능 조합: {}", combination),
+    })
+}
+
+/// 메인 계산 함수 (GUI/CLI 공용)
+/// 에러는 `data.lang` 언어로 지역화된 메시지로 반환
+pub fn calculate_core(data: &CableData) -> Result<CalculationResult, String> {
+    CalculationPipeline::new(data).run().map_err(|failure| failure.message)
+}
+
+/// 사용자 계산 진입점: 계산 후 감사 로그 기록 (GUI 없이 Tauri calculate 커맨드와 같은 동작)
+/// 에러는 코드로 분기할 수 있는 CalcError, 감사 로그 경로가 설정되지 않았으면 calculate_core와 같은 계산
+pub fn calculate_result(data: &CableData) -> Result<CalculationResult, CalcError> {
+    let result = CalculationPipeline::new(data).run()?;
+    record_audit(data, &result);
+    Ok(result)
+}
+
+/// 계산 파이프라인 단계 (normalize -> validate -> derive_defaults -> compute)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PipelineStage {
+    Normalize,
+    Validate,
+    DeriveDefaults,
+    Compute,
+}
+
+impl PipelineStage {
+    pub fn label(self) -> &'static str {
+        match self {
+            PipelineStage::Normalize => "정규화",
+            PipelineStage::Validate => "검증",
+            PipelineStage::DeriveDefaults => "기본값 도출",
+            PipelineStage::Compute => "계산",
+        }
+    }
+}
+
+/// 파이프라인 실패 정보 (실패 단계 + 에러 키 + 지역화된 메시지)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PipelineFailure {
+    pub stage: PipelineStage,
+    pub error_key: String,
+    pub message: String,
+}
+
+impl From<PipelineFailure> for CalcError {
+    fn from(failure: PipelineFailure) -> Self {
+        CalcError { code: CalcErrorCode::from_key(&failure.error_key), message: failure.message }
+    }
+}
+
+/// 정규화 단계 출력: 앞뒤 공백 제거, AWG 입력은 표준 mm² 규격으로 변환
+#[derive(Debug, Clone)]
+struct NormalizedInput {
+    data: CableData,
+    awg: Option<&'static str>,  // imperial 입력의 AWG 표시명
+    regulation: Regulation,
+}
+
+/// 기본값 도출 단계 출력: 공사방법 확정
+#[derive(Debug, Clone)]
+struct ResolvedInput {
+    data: CableData,
+    awg: Option<&'static str>,
+    regulation: Regulation,
+    install_method: InstallMethod,
+}
+
+/// 계산 파이프라인: 각 단계의 출력을 다음 단계 입력으로 전달
+/// 보정계수 등 후속 기능은 해당 단계 함수에 추가
+pub struct CalculationPipeline<'a> {
+    data: &'a CableData,
+    calculated_at: Option<u64>,
+}
+
+impl<'a> CalculationPipeline<'a> {
+    pub fn new(data: &'a CableData) -> Self {
+        CalculationPipeline { data, calculated_at: None }
+    }
+
+    /// 출처에 기록할 계산 시각 지정 (Unix epoch 초, 미지정 시 현재 시각)
+    /// 같은 입력·시각이면 결과와 계산서 출력이 항상 같음 (재현·테스트용)
+    pub fn calculated_at(mut self, unix_secs: u64) -> Self {
+        self.calculated_at = Some(unix_secs);
+        self
+    }
+
+    /// 전체 단계 실행, 실패 시 실패한 단계와 사유 반환
+    /// 입력 요약·소요시간은 trace, 실패는 error 레벨로 기록 (제조사·모델 등 부가 정보는 기록하지 않음)
+    pub fn run(&self) -> Result<CalculationResult, PipelineFailure> {
+        let data = self.data;
+        let span = tracing::trace_span!(
+            "calculate",
+            cable_type = %data.cable_type,
+            cores = %data.cores,
+            size = %data.size,
+            quantity = data.quantity,
+            system = %data.system,
+            method = %data.install_method
+        );
+        let _entered = span.enter();
+        let elapsed_us = start_stopwatch();
+
+        let fail = |stage: PipelineStage| {
+            move |key: &'static str| {
+                tracing::error!(stage = stage.label(), error_key = key, cable_type = %data.cable_type, size = %data.size, "계산 실패");
+                PipelineFailure {
+                    stage,
+                    error_key: key.to_string(),
+                    message: localize_error(key, error_lang(data)),
+                }
+            }
+        };
+        let normalized = normalize(data).map_err(fail(PipelineStage::Normalize))?;
+        validate(&normalized).map_err(fail(PipelineStage::Validate))?;
+        let resolved = derive_defaults(normalized).map_err(fail(PipelineStage::DeriveDefaults))?;
+        let calculated_at = self.calculated_at.unwrap_or_else(|| unix_now().as_secs());
+        let result = compute(&resolved, calculated_at).map_err(|key| {
+            let mut failure = fail(PipelineStage::Compute)(key);
+            if key == ERR_CURRENT_DATA_NOT_FOUND {
+                if let Some(hint) = nearest_available_hint(&resolved.data, resolved.install_method, error_lang(data)) {
+                    failure.message = format!("{} ({})", failure.message, hint);
+                }
+            }
+            failure
+        })?;
+        let result = CalculationResult { alternatives: size_alternatives(&resolved), ..result };
+        tracing::trace!(
+            allowable_current = result.allowable_current,
+            conduit = %result.recommended_raceway.name,
+            fill_rate = result.fill_rate,
+            elapsed_us = elapsed_us(),
+            "계산 완료"
+        );
+        Ok(result)
+    }
+}
+
+/// 입력 종류·가닥수가 지원하는 모든 표준 규격의 계산 결과 (규격 오름차순)
+/// 입력 규격 외 조건은 그대로 두고 규격만 바꿔 계산, 외경·허용전류 데이터가 없거나 검증에 실패하는 규격은 제외.
+/// imperial 입력도 mm² 표준 규격으로 비교
+pub fn calculate_candidates(data: CableData) -> Vec<CalculationResult> {
+    STANDARD_SIZES
+        .iter()
+        .filter(|size| get_cable_outer_diameter(data.cable_type.trim(), size, data.cores.trim()).is_some())
+        .filter_map(|size| {
+            // 사용자 지정 외경은 입력 규격 전용이므로 다른 규격에는 내장 외경 사용
+            let candidate = CableData { size: size.to_string(), unit_system: None, custom_outer_diameter: None, ..data.clone() };
+            calculate_core(&candidate).ok()
+        })
+        .collect()
+}
+
+/// 선정 규격 바로 아래·위 표준 규격의 계산 결과 (최소·최대 규격이면 한쪽만)
+/// 외경·허용전류 데이터가 없는 규격은 제외, 대안은 mm² 규격으로 표시
+fn size_alternatives(input: &ResolvedInput) -> Vec<SizeAlternative> {
+    let Some(index) = STANDARD_SIZES.iter().position(|s| *s == input.data.size) else {
+        return Vec::new();
+    };
+    let minimum = input
+        .data
+        .usage
+        .as_deref()
+        .map(str::trim)
+        .filter(|u| !u.is_empty())
+        .and_then(|usage| minimum_size(usage, &input.data.system).ok())
+        .and_then(|size| Size::try_from(size).ok());
+    [index.checked_sub(1), Some(index + 1)]
+        .into_iter()
+        .flatten()
+        .filter_map(|i| STANDARD_SIZES.get(i))
+        .filter_map(|size| {
+            let mut alternative = input.clone();
+            alternative.data.size = size.to_string();
+            alternative.data.custom_outer_diameter = None;
+            alternative.awg = None;
+            let (_, _, _, conduit) = compute_fill(&alternative.data, alternative.regulation).ok()?;
+            let (allowable_current, ..) =
+                compute_current(&alternative.data, alternative.install_method, alternative.regulation, None).ok()?;
+            let overloaded = input.data.load_current.map(|load| load > allowable_current);
+            let below_minimum = minimum.is_some_and(|min| Size::try_from(*size).is_ok_and(|s| s.mm2() < min.mm2()));
+            Some(SizeAlternative {
+                size: size.to_string(),
+                allowable_current,
+                recommended_raceway: conduit.name,
+                fill_rate: conduit.fill_rate,
+                suitable: conduit.status == "정상" && overloaded != Some(true) && !below_minimum,
+                overloaded,
+            })
+        })
+        .collect()
+}
+
+/// 1단계 정규화: 문자열 공백 제거, 적용 규정·단위 체계 해석 (imperial이면 AWG -> 표준 mm²)
+fn normalize(data: &CableData) -> Result<NormalizedInput, &'static str> {
+    let mut normalized = CableData {
+        cable_type: data.cable_type.trim().to_string(),
+        cores: data.cores.trim().to_string(),
+        size: data.size.trim().to_string(),
+        system: data.system.trim().to_string(),
+        ground_wire: data.ground_wire.trim().to_string(),
+        install_method: data.install_method.trim().to_string(),
+        ..data.clone()
+    };
+    let awg = match normalized.unit_system.as_deref() {
+        None | Some("") | Some("metric") => None,
+        Some("imperial") => {
+            let label = awg_label(&normalized.size).ok_or(ERR_INVALID_AWG)?;
+            // 허용전류 안전측으로 AWG 단면적 이하의 표준 규격 적용
+            normalized.size = awg_to_standard_size(&normalized.size).ok_or(ERR_INVALID_AWG)?.to_string();
+            Some(label)
+        }
+        Some(_) => return Err(ERR_INVALID_UNIT_SYSTEM),
+    };
+    normalized.unit_system = None;
+    let regulation = resolve_regulation(&normalized)?;
+    Ok(NormalizedInput { data: normalized, awg, regulation })
+}
+
+/// 입력 검증만 수행 (계산 파이프라인의 정규화·검증·기본값 도출 단계, 계산 없이 입력 폼 확인용)
+/// 수량 1 이상, 지원 종류·가닥수·규격(숫자로 해석 가능하고 외경 데이터가 있는 규격), 가닥수와 공사방법 조합
+pub fn validate_cable_data(data: &CableData) -> Result<(), CalcError> {
+    normalize(data)
+        .and_then(|input| validate(&input).map(|_| input))
+        .and_then(derive_defaults)
+        .map(|_| ())
+        .map_err(|key| CalcError::new(key, error_lang(data)))
+}
+
+/// 입력 필드 검증 (정규화된 입력 기준, imperial 규격은 mm²로 변환된 뒤)
+/// 규격이 숫자로 해석되지 않으면 도체 단면적이 0으로 계산되지 않도록 여기서 차단
+fn validate_fields(data: &CableData) -> Result<(), &'static str> {
+    if data.quantity == 0 {
+        return Err(ERR_INVALID_QUANTITY);
+    }
+    if !get_cable_types().iter().any(|info| info.code == data.cable_type) {
+        return Err(ERR_UNKNOWN_CABLE_TYPE);
+    }
+    if !matches!(data.cores.as_str(), "1C" | "2C" | "3C" | "4C") {
+        return Err(ERR_INVALID_CORES);
+    }
+    if data.size.parse::<f64>().is_err() {
+        return Err(ERR_INVALID_SIZE);
+    }
+    if data.custom_outer_diameter.is_some_and(|od| !(od > 0.0 && od.is_finite())) {
+        return Err(ERR_INVALID_OUTER_DIAMETER);
+    }
+    // 알 수 없는 공사방법은 기본값 도출 단계에서 처리
+    if InstallMethod::try_from(data.install_method.as_str()).is_ok_and(|method| !method.supports_cores(&data.cores)) {
+        return Err(ERR_INSTALL_METHOD_CORES_MISMATCH);
+    }
+    Ok(())
+}
+
+/// 2단계 검증: 입력 필드, 가닥수/전압 방식 조합, 용도별 최소 규격, 외경 데이터가 있는 종류·규격인지
+fn validate(input: &NormalizedInput) -> Result<(), &'static str> {
+    let data = &input.data;
+    validate_fields(data)?;
+    validate_combination(&data.cores, &data.system)?;
+    if let Some(usage) = data.usage.as_deref().map(str::trim).filter(|u| !u.is_empty()) {
+        let minimum = Size::try_from(minimum_size(usage, &data.system)?)?;
+        if Size::try_from(data.size.as_str())?.mm2() < minimum.mm2() {
+            return Err(ERR_BELOW_MINIMUM_SIZE);
+        }
+    }
+    if data.termination_temp.is_some_and(|temp| temp < MIN_TERMINATION_TEMP) {
+        return Err(ERR_INVALID_TERMINATION_TEMP);
+    }
+    if data.parallel_runs == 0 {
+        return Err(ERR_INVALID_PARALLEL_RUNS);
+    }
+    resolve_material(data)?;
+    cable_outer_diameter(data).map(|_| ()).ok_or(ERR_UNSUPPORTED_SIZE)
+}
+
+/// 3단계 기본값 도출: 공사방법 미지정 시 가닥수 기준 기본값 (1C: B1, 다심: B2)
+fn derive_defaults(input: NormalizedInput) -> Result<ResolvedInput, &'static str> {
+    let install_method = resolve_install_method(&input.data)?;
+    let mut data = input.data;
+    data.install_method = install_method.as_str().to_string();
+    Ok(ResolvedInput { data, awg: input.awg, regulation: input.regulation, install_method })
+}
+
+/// 4단계 계산: 점유율·허용전류·물량
+/// imperial 입력이면 규격은 AWG, 전선관은 인치 호칭으로 표시, calculated_at은 출처에 기록할 계산 시각 (Unix epoch 초)
+fn compute(input: &ResolvedInput, calculated_at: u64) -> Result<CalculationResult, &'static str> {
+    let data = &input.data;
+    let (total_area, effective_area, conductor_area, mut recommended_raceway) = compute_fill(data, input.regulation)?;
+    let (allowable_current, install_method_desc, mut size_label, conductor_max_temp, applied_temp_rating, current_formula) =
+        compute_current(data, input.install_method, input.regulation, input.awg)?;
+    let (total_cable_length, ground_wire_length) = compute_lengths(data);
+    let minimum_size = minimum_size_note(data)?;
+    let ground_wire_size = protective_conductor_size(data)?.unwrap_or_default().to_string();
+    let short_circuit = compute_short_circuit(data)?;
+
+    if let Some(awg) = input.awg {
+        size_label = format!("{} ({} mm² 적용)", awg, data.size);
+        let steel = recommended_raceway.kind == RacewayKind::Conduit && data.conduit_type.unwrap_or_default() == ConduitType::Steel;
+        if let Some(trade_size) = conduit_trade_size(recommended_raceway.nominal).filter(|_| steel) {
+            if recommended_raceway.status == "정상" {
+                recommended_raceway.name = format!("{} ({})", trade_size, recommended_raceway.name);
+            }
+        }
+    }
+    let mut formulas = vec![current_formula];
+    set_formula(&mut formulas, FORMULA_FILL, fill_formula(total_area, &recommended_raceway, error_lang(data)));
+    set_formula(&mut formulas, FORMULA_SHORT_CIRCUIT, short_circuit.as_ref().map(|(.., formula)| formula.clone()));
+
+    Ok(CalculationResult {
+        total_area,
+        effective_area,
+        conductor_area,
+        allowable_current,
+        fill_rate: recommended_raceway.fill_rate,
+        recommended_raceway,
+        install_method_desc,
+        size_label,
+        conductor_max_temp,
+        total_cable_length,
+        ground_wire_length,
+        regulation: input.regulation.as_str().to_string(),
+        minimum_size,
+        applied_temp_rating,
+        data_provenance: current_data_provenance(input.regulation, calculated_at),
+        alternatives: Vec::new(),
+        formulas,
+        ground_wire_size,
+        short_circuit_ok: short_circuit.as_ref().map(|&(ok, ..)| ok),
+        short_circuit_min_size: short_circuit.as_ref().map(|&(_, min_area, _)| min_area),
+        size: data.size.clone(),
+        load_margin_percent: load_margin_percent(data.load_current, allowable_current),
+        recommended_breaker_a: breaker_for_load(data.load_current, allowable_current),
+        reference: result_reference(data, input.install_method, input.regulation),
+        annual_loss_kwh: annual_loss_for(data),
+        min_bending_radius_mm: bending_radius_for(data),
+    })
+}
+
+/// 부하전류 대비 허용전류 여유율 (%, 소수 첫째 자리), 부하전류가 없거나 0 이하이면 None
+fn load_margin_percent(load_current: Option<f64>, allowable_current: f64) -> Option<f64> {
+    load_current
+        .filter(|load| *load > 0.0)
+        .map(|load| ((allowable_current - load) / load * 100.0 * 10.0).round() / 10.0)
+}
+
+/// 부하전류가 있으면 보호 협조 최소 차단기 정격 (주택용 배선차단기 기준), 부하전류가 없거나 협조 불가면 None
+fn breaker_for_load(load_current: Option<f64>, allowable_current: f64) -> Option<u32> {
+    load_current
+        .filter(|load| *load > 0.0)
+        .and_then(|load| recommend_breaker(load, allowable_current, BreakerType::default(), "ko").ok())
+}
+
+/// 물량 계산: (총 케이블 길이, 접지선 길이)
+/// 수량은 케이블 가닥 수이므로 단심은 이미 회로당 가닥수(2 또는 3)가 반영됨
+/// 접지선은 점유율 계산과 같이 병렬 런마다 1가닥으로 집계
+fn compute_lengths(data: &CableData) -> (f64, f64) {
+    if data.length <= 0.0 {
+        return (0.0, 0.0);
+    }
+    let runs = data.parallel_runs.max(1) as f64;
+    let total_cable_length = data.length * data.quantity as f64 * runs;
+    let ground_wire_length = if data.ground_wire == "HFIX" { data.length * runs } else { 0.0 };
+    (
+        (total_cable_length * 10.0).round() / 10.0,
+        (ground_wire_length * 10.0).round() / 10.0,
+    )
+}
+
+/// 점유율 계산: (총 단면적, 실효 점유 면적, 도체 단면적, 추천 전선관)
+/// 종류·규격·가닥수·수량·접지선·적용 규정에만 의존
+fn compute_fill(data: &CableData, regulation: Regulation) -> Result<(f64, f64, f64, RacewayRecommendation), &'static str> {
+    // 규격 단면적 (해석 불가 규격은 0으로 계산하지 않고 에러)
+    let size_mm2: f64 = data.size.parse().map_err(|_| ERR_INVALID_SIZE)?;
+
+    // 외경 계산 (사용자 지정 외경 우선)
+    let outer_diameter = cable_outer_diameter(data).ok_or(ERR_UNSUPPORTED_SIZE)?;
+    
+    // 단위 케이블 단면적 (외경 기준)
+    let single_cable_area = calculate_cable_area(outer_diameter);
+    
+    // 총 단면적 (수량 × 병렬 런 수 적용)
+    let cable_count = data.quantity * data.parallel_runs.max(1);
+    let mut total_area = single_cable_area * cable_count as f64;
+    // 실효 면적 산정용 (외경, 개수)
+    let mut cables = vec![(outer_diameter, cable_count)];
+    
+    // 도체 단면적 계산
+    let conductor_area = size_mm2 * cable_count as f64;
+
+    // 접지선 단면적 추가 (HFIX, KEC 542.3 보호도체 규격, 병렬 런마다 1가닥)
+    if let Some(ground_size) = protective_conductor_size(data)? {
+        if let Some(ground_od) = get_cable_outer_diameter("HFIX", ground_size, "1C") {
+            let ground_count = data.parallel_runs.max(1);
+            total_area += calculate_cable_area(ground_od) * ground_count as f64;
+            cables.push((ground_od, ground_count));
+        }
+    }
+
+    // 추천 전선관 계산 (점유율 상한과 같은 원 면적 합 기준, 실효 면적은 참고값)
+    let effective_area = effective_occupied_area(&cables);
+    let diameters: Vec<f64> = cables.iter().flat_map(|&(od, count)| std::iter::repeat_n(od, count as usize)).collect();
+    // 트레이 공사(E/F)는 전선관 대신 트레이 폭 기준
+    let recommended_raceway = if uses_cable_tray(data) {
+        recommend_tray_for_cables(&diameters)
+    } else {
+        recommend_conduit_for_cables(
+            data.conduit_type.unwrap_or_default(),
+            total_area.value(),
+            FillLimit::Regulation(regulation),
+            &diameters,
+        )
+    };
+    let fill_rate = recommended_raceway.fill_rate;
+
+    Ok((
+        (total_area.value() * 100.0).round() / 100.0,
+        (effective_area * 100.0).round() / 100.0,
+        (conductor_area * 100.0).round() / 100.0,
+        RacewayRecommendation {
+            fill_rate: (fill_rate * 10.0).round() / 10.0,
+            ..recommended_raceway
+        },
+    ))
+}
+
+/// 허용전류 계산: (허용전류, 공사방법 설명, 적용 규격 표시, 도체 최고온도, 적용 온도 등급, 계산 수식)
+/// 종류·규격·가닥수·전압 방식·수량·공사방법·적용 규정에만 의존
+/// NEC는 AWG ampacity 테이블 경로로 분기 (awg: imperial 입력의 AWG 표시명)
+fn compute_current(
+    data: &CableData,
+    install_method: InstallMethod,
+    regulation: Regulation,
+    awg: Option<&'static str>,
+) -> Result<(f64, String, String, u32, u32, String), &'static str> {
+    // 절연체 종류 결정 (온도보정 기준이 되는 도체 최고온도 포함)
+    let insulation = get_insulation_type(&data.cable_type);
+    let conductor_max_temp = get_max_conductor_temp(insulation);
+    let material = resolve_material(data)?;
+    if regulation == Regulation::Nec {
+        // NEC 알루미늄 ampacity 열은 미수록
+        if material == Material::Aluminium {
+            return Err(ERR_CURRENT_DATA_NOT_FOUND);
+        }
+        return compute_current_nec(data, insulation, awg);
+    }
+
+    // 허용전류 테이블에서 값 조회 (2부하/3부하 분리, 도체 재질별 테이블)
+    let size = Size::try_from(data.size.as_str())?;
+    let loaded_conductors = determine_loaded_conductors(&data.cores, &data.system);
+    let base_current = lookup_base_current_for(material, size, insulation, install_method, loaded_conductors)?;
+    let loaded_label = loaded_label(loaded_conductors, &data.system, error_lang(data));
+
+    // 집합 보정 계수 (Grouping Factor) 계산
+    let num_circuits = grouping_circuit_count(data)?;
+    let arrangement = data.arrangement.unwrap_or_default();
+    let grouping_factor = get_grouping_factor(num_circuits, arrangement);
+
+    // 심선 수 감소계수 (기존 코드는 이걸로 3상 변환을 시도했으나, 이제 표준 테이블 사용)
+    // 그러나 "1C"가 아닌 "2C/3C/4C" 케이블 자체의 열적 특성은 이미 테이블에 반영됨 (2/3 loaded)
+    // 단, 4C 케이블의 경우 KEC에서 3부하 도체로 간주하므로 추가 감소 없음 (중성선 부하 제외 가정)
+    // 중성선도 부하 도체이면(neutral_loaded) 아래에서 3부하 값에 감소계수 적용
+    // 따라서 별도의 심선 수 감소계수는 삭제하고, Grouping Factor와 Loaded Table로 대체함.
+
+    // 단자 등급이 케이블 등급보다 낮으면 낮은 온도 기준으로 제한
+    let applied_temp = data
+        .termination_temp
+        .map_or(conductor_max_temp, |temp| apply_termination_temp_limit(conductor_max_temp, temp));
+    let termination_factor = termination_temp_factor(conductor_max_temp, applied_temp, install_method.is_buried());
+
+    // 주변온도 보정 (미지정 시 기준온도로 보고 1.0)
+    let temp_factor = match data.ambient_temp {
+        Some(ambient) => temperature_factor(insulation, ambient, install_method.is_buried())?,
+        None => 1.0,
+    };
+
+    // 토양 열저항률 보정 (지중 공사방법만, 미지정 시 기준 2.5 K·m/W로 보고 1.0)
+    // 지중 온도 계수(B.52.15)·토양 계수는 서로 다른 기준 조건을 보정하므로 각각 한 번씩만 곱함
+    // 매설 깊이는 IEC 60364-5-52에 보정표가 없어(지중 표는 0.7m 기준) 값 검증·설명 표시만 하고 보정하지 않음
+    if data.burial_depth_m.is_some_and(|depth| !(depth > 0.0 && depth.is_finite())) {
+        return Err(ERR_INVALID_BURIAL_DEPTH);
+    }
+    if data.soil_resistivity.is_some_and(|rho| !(rho > 0.0 && rho.is_finite())) {
+        return Err(ERR_INVALID_SOIL_RESISTIVITY);
+    }
+    let soil_factor = data.soil_resistivity.map_or(1.0, |rho| soil_resistivity_factor(rho, install_method));
+
+    // 3고조파 보정 (4C 3상 4선만). 중성선 기준이면 중성선 전류(상전류 × 3 × 함유율)로 사이징하므로
+    // 상전류 환산 허용전류 = 중성선 허용전류 ÷ (3 × 함유율)
+    if data.third_harmonic_percent.is_some_and(|h| !(0.0..=100.0).contains(&h)) {
+        return Err(ERR_INVALID_HARMONIC_PERCENT);
+    }
+    let harmonic_applies = data.cores == "4C" && data.system == "3Φ";
+    let (harmonic_factor, neutral_sizing) = match data.third_harmonic_percent {
+        Some(h) if harmonic_applies => get_harmonic_factor(h),
+        _ => (1.0, false),
+    };
+    let neutral_factor = match data.third_harmonic_percent {
+        Some(h) if neutral_sizing => 1.0 / (3.0 * h / 100.0),
+        _ => 1.0,
+    };
+
+    // 중성선 부하 (4C만): 4부하를 3부하 값 × 0.86으로 환산
+    // 3고조파 보정(15% 초과)은 이미 중성선 전류를 반영하므로 중복 적용하지 않음
+    let harmonic_covers_neutral = harmonic_factor < 1.0 || neutral_sizing;
+    let neutral_load_applies = data.neutral_loaded && data.cores == "4C" && !harmonic_covers_neutral;
+    let neutral_load_factor = if neutral_load_applies { NEUTRAL_LOADED_FACTOR } else { 1.0 };
+
+    // 최종 허용전류 계산
+    // 허용전류 = 기본값 * 집합보정계수 * 단자 온도 제한계수 * 온도보정계수 * 토양 계수
+    //          * 고조파 계수 (* 중성선 환산) (* 중성선 부하) * 병렬 런 수
+    let parallel_runs = data.parallel_runs as f64;
+    let allowable_current = apply_derating(
+        base_current,
+        &[
+            grouping_factor,
+            termination_factor,
+            temp_factor,
+            soil_factor,
+            harmonic_factor,
+            neutral_factor,
+            neutral_load_factor,
+            parallel_runs,
+        ],
+    );
+
+    // 공사방법 설명 (입력 언어로 표기)
+    let lang = error_lang(data);
+    let t = |ko| desc_label(ko, lang);
+    // 단심은 가닥수로 회로 수를 산정하므로 적용 정책 표기
+    let mut policy_label = if data.cores == "1C" {
+        format!(" / {}: {}", t("회로 수 산정"), t(data.circuit_count_policy.unwrap_or_default().label()))
+    } else {
+        String::new()
+    };
+    if arrangement != Arrangement::Conduit {
+        policy_label.push_str(&format!(" / {}: {}", t("배치"), t(arrangement.label())));
+    }
+    let mut install_method_desc = format!(
+        "{} / {} / {}: {:.2} ({}{}){} / {} {} / {}°C {}",
+        get_install_method_description(install_method, lang),
+        loaded_label,
+        t("집합계수"),
+        grouping_factor,
+        num_circuits,
+        t("회로"),
+        policy_label,
+        t("집합계수 출처"),
+        arrangement.table_reference(),
+        conductor_max_temp,
+        t("절연 기준")
+    );
+    if material == Material::Aluminium {
+        install_method_desc.push_str(&format!(" / {}", t("알루미늄 도체")));
+    }
+    if applied_temp < conductor_max_temp {
+        install_method_desc.push_str(&format!(
+            " / {} {}°C {} ({} {:.2})",
+            t("단자 등급"),
+            applied_temp,
+            t("제한"),
+            t("계수"),
+            termination_factor
+        ));
+    }
+    if let Some(ambient) = data.ambient_temp {
+        let temp_table = if install_method.is_buried() { "Table B.52.15" } else { "Table B.52.14" };
+        install_method_desc.push_str(&format!(" / {} {}°C ({} {:.2}, {})", t("주변온도"), ambient, t("계수"), temp_factor, temp_table));
+    }
+    if let Some(depth) = data.burial_depth_m {
+        if install_method.is_buried() {
+            install_method_desc.push_str(&format!(" / {} {}m ({})", t("매설 깊이"), depth, t("보정 미적용, 0.7m 기준 표 값")));
+        } else {
+            install_method_desc.push_str(&format!(" / {} {} ({})", t("매설 깊이"), t("무시"), t("공기 중 공사방법")));
+        }
+    }
+    if let Some(rho) = data.soil_resistivity {
+        if install_method.is_buried() {
+            install_method_desc.push_str(&format!(" / {} {} K·m/W ({} {:.2})", t("토양 열저항률"), rho, t("계수"), soil_factor));
+        } else {
+            install_method_desc.push_str(&format!(" / {} {} ({})", t("토양 열저항률"), t("무시"), t("공기 중 공사방법")));
+        }
+    }
+    if let Some(h) = data.third_harmonic_percent {
+        if !harmonic_applies {
+            install_method_desc.push_str(&format!(" / {}", t("고조파 보정 무시 (4C 3상 회로만 적용)")));
+        } else if neutral_sizing {
+            install_method_desc.push_str(&format!(
+                " / {} {}% {} ({} {:.2}, {} = {} × {:.2})",
+                t("3고조파"),
+                h,
+                t("중성선 기준"),
+                t("계수"),
+                harmonic_factor,
+                t("중성선 전류"),
+                t("상전류"),
+                3.0 * h / 100.0
+            ));
+        } else {
+            install_method_desc.push_str(&format!(" / {} {}% ({} {:.2})", t("3고조파"), h, t("계수"), harmonic_factor));
+        }
+    }
+    if data.neutral_loaded {
+        if data.cores != "4C" {
+            install_method_desc.push_str(&format!(" / {} {} ({})", t("중성선 부하"), t("무시"), t("4C 케이블만 적용")));
+        } else if neutral_load_applies {
+            install_method_desc.push_str(&format!(
+                " / {} ({} {:.2})",
+                t("중성선 부하 고려 적용"),
+                t("계수"),
+                NEUTRAL_LOADED_FACTOR
+            ));
+        } else {
+            install_method_desc.push_str(&format!(" / {} ({})", t("중성선 부하 고려 적용"), t("3고조파 보정에 포함")));
+        }
+    }
+    if data.parallel_runs > 1 {
+        install_method_desc.push_str(&parallel_runs_note(data.parallel_runs, lang));
+    }
+    if data.system == "DC" {
+        // PV 전용 케이블 데이터 미수록: 교류 2부하 표 값을 그대로 준용
+        install_method_desc.push_str(&format!(" / {} ({})", t("DC 근사 적용"), t("교류 2부하 허용전류 준용")));
+    }
+
+    let allowable_current = (allowable_current.value() * 10.0).round() / 10.0;
+    let formula = derating_formula(
+        base_current,
+        &[
+            (grouping_factor, "집합"),
+            (termination_factor, "단자 온도"),
+            (temp_factor, "온도"),
+            (soil_factor, "토양"),
+            (harmonic_factor, "고조파"),
+            (neutral_factor, "중성선 환산"),
+            (neutral_load_factor, "중성선 부하"),
+            (parallel_runs, "병렬"),
+        ],
+        allowable_current,
+        lang,
+    );
+    Ok((
+        allowable_current,
+        install_method_desc,
+        format!("{} mm²", size.as_str()),
+        conductor_max_temp,
+        applied_temp,
+        formula,
+    ))
+}
+
+/// 병렬 런 설명 (KEC 123: 병렬 도체는 각 런의 재질·규격·길이·포설 조건이 같아야 전류가 균등 분담)
+fn parallel_runs_note(parallel_runs: u32, lang: &str) -> String {
+    format!(
+        " / {} {}{} ({})",
+        desc_label("병렬", lang),
+        parallel_runs,
+        desc_label("런", lang),
+        desc_label("각 런의 규격·길이 동일 필요", lang)
+    )
+}
+
+/// 공사방법 설명 문구의 영문 라벨 (한국어 문구 → 영문)
+const DESC_LABELS_EN: &[(&str, &str)] = &[
+    ("회로 수 산정", "Circuit count"),
+    ("올림", "round up"),
+    ("정확히 나눔", "exact division"),
+    ("내림", "round down"),
+    ("배치", "Arrangement"),
+    ("전선관 묶음", "bunched in conduit"),
+    ("트레이 단일 층", "single-layer tray"),
+    ("트레이 다층", "multi-layer tray"),
+    ("간격 유지", "spaced"),
+    ("집합계수", "Grouping factor"),
+    ("회로", " circuit(s)"),
+    ("집합계수 출처", "Grouping factor source"),
+    ("절연 기준", "insulation rating"),
+    ("알루미늄 도체", "Aluminium conductor"),
+    ("단자 등급", "Terminal rating"),
+    ("제한", "limit"),
+    ("계수", "factor"),
+    ("주변온도", "Ambient"),
+    ("매설 깊이", "Burial depth"),
+    ("토양 열저항률", "Soil thermal resistivity"),
+    ("무시", "ignored"),
+    ("보정 미적용, 0.7m 기준 표 값", "not corrected, tables assume 0.7 m"),
+    ("공기 중 공사방법", "in-air method"),
+    ("고조파 보정 무시 (4C 3상 회로만 적용)", "Harmonic correction ignored (4C three-phase circuits only)"),
+    ("3고조파", "3rd harmonic"),
+    ("중성선 기준", "neutral basis"),
+    ("중성선 전류", "neutral current"),
+    ("상전류", "phase current"),
+    ("중성선 부하", "Neutral load"),
+    ("4C 케이블만 적용", "4C cables only"),
+    ("중성선 부하 고려 적용", "Loaded neutral applied"),
+    ("3고조파 보정에 포함", "included in 3rd harmonic correction"),
+    ("DC 근사 적용", "DC approximation"),
+    ("교류 2부하 허용전류 준용", "AC two-loaded-conductor ampacity applied"),
+    ("병렬", "Parallel"),
+    ("런", " runs"),
+    ("각 런의 규격·길이 동일 필요", "each run must have equal size and length"),
+    ("열", "column"),
+    ("통전 도체", "Current-carrying conductors"),
+    ("가닥", ""),
+    ("조정계수", "Adjustment factor"),
+    ("허용전류", "Ampacity"),
+    ("보호협조", "Protection coordination"),
+    ("전압강하", "Voltage drop"),
+    ("단락용량", "Short-circuit capacity"),
+    ("집합", "Grouping"),
+    ("단자 온도", "Terminal temperature"),
+    ("온도", "Temperature"),
+    ("토양", "Soil"),
+    ("고조파", "Harmonics"),
+    ("중성선 환산", "Neutral conversion"),
+    ("조정", "Adjustment"),
+    ("점유율", "Fill rate"),
+    ("내부 단면적", "inner area"),
+    ("외경 합", "sum of outer diameters"),
+    ("트레이 폭", "tray width"),
+    ("단락 최소 단면적", "Minimum short-circuit area"),
+    ("전압강하(직류)", "Voltage drop (DC)"),
+    ("변압기 전압강하", "Transformer voltage drop"),
+    ("전압강하율", "Voltage drop ratio"),
+    ("동력 간선", "Power feeder"),
+    ("동력 분기", "Power branch"),
+    ("전등 간선", "Lighting feeder"),
+    ("전등 분기", "Lighting branch"),
+    ("제어", "Control"),
+    ("점유율 상한", "Fill limit"),
+    ("추천 전선관", "Recommended conduit"),
+];
+
+/// 공사방법 설명 문구 지역화 (ko는 그대로, 표에 없는 문구도 그대로)
+fn desc_label<'a>(ko: &'a str, lang: &str) -> &'a str {
+    if label_lang(lang) != "en" {
+        return ko;
+    }
+    DESC_LABELS_EN.iter().find(|(key, _)| *key == ko).map_or(ko, |(_, en)| en)
+}
+
+/// 계산 근거 수식 항목 이름 (수식 문자열의 " = " 앞부분)
+const FORMULA_CURRENT: &str = "허용전류";
+const FORMULA_FILL: &str = "점유율";
+const FORMULA_SHORT_CIRCUIT: &str = "단락 최소 단면적";
+
+/// 허용전류 대입 수식 (1.00이 아닌 적용 계수만 표시, 항목·계수 이름은 입력 언어로 표기)
+/// 예: "허용전류 = 119A × 0.80(집합) = 95.2A"
+fn derating_formula(base: Amperes, factors: &[(f64, &'static str)], allowable_current: f64, lang: &str) -> String {
+    let mut formula = format!("{} = {}A", desc_label(FORMULA_CURRENT, lang), base.value());
+    for (factor, label) in factors.iter().filter(|(factor, _)| (factor - 1.0).abs() > f64::EPSILON) {
+        formula.push_str(&format!(" × {:.2}({})", factor, desc_label(label, lang)));
+    }
+    format!("{} = {:.1}A", formula, allowable_current)
+}
+
+/// 점유율 대입 수식 (전선관·트레이 선정이 정상일 때만)
+/// 예: "점유율 = 123.45mm² ÷ 555.72mm²(C28 (28mm) 내부 단면적) × 100 = 22.2%"
+fn fill_formula(total_area: f64, raceway: &RacewayRecommendation, lang: &str) -> Option<String> {
+    if raceway.status != "정상" {
+        return None;
+    }
+    let t = |ko| desc_label(ko, lang);
+    Some(match (raceway.kind, raceway.inner_diameter) {
+        (RacewayKind::Conduit, Some(inner_diameter)) => format!(
+            "{} = {:.2}mm² ÷ {:.2}mm²({} {}) × 100 = {:.1}%",
+            t(FORMULA_FILL),
+            total_area,
+            calculate_cable_area(inner_diameter).value(),
+            raceway.name,
+            t("내부 단면적"),
+            raceway.fill_rate
+        ),
+        _ => format!(
+            "{} = {:.1}mm({}) ÷ {}mm({}) × 100 = {:.1}%",
+            t(FORMULA_FILL),
+            raceway.fill_rate * raceway.nominal as f64 / 100.0,
+            t("외경 합"),
+            raceway.nominal,
+            t("트레이 폭"),
+            raceway.fill_rate
+        ),
+    })
+}
+
+/// 같은 항목의 수식을 교체 (없으면 추가, None이면 제거)
+/// name은 한국어 항목 이름, 기존 수식이 영문이어도 같은 항목으로 찾음
+fn set_formula(formulas: &mut Vec<String>, name: &'static str, formula: Option<String>) {
+    let prefixes = [format!("{} = ", name), format!("{} = ", desc_label(name, "en"))];
+    let position = formulas.iter().position(|f| prefixes.iter().any(|prefix| f.starts_with(prefix)));
+    match (position, formula) {
+        (Some(i), Some(formula)) => formulas[i] = formula,
+        (Some(i), None) => {
+            formulas.remove(i);
+        }
+        (None, Some(formula)) => formulas.push(formula),
+        (None, None) => {}
+    }
+}
+
+/// NEC 310.16 동 도체 ampacity (AWG/kcmil, 60°C / 75°C / 90°C 열)
+/// 전선관·케이블 내 통전 도체 3가닥 이하, 주위온도 30°C 기준
+const NEC_AMPACITY_TABLE: &[(&str, f64, f64, f64)] = &[
+    ("14 AWG", 15.0, 20.0, 25.0), ("12 AWG", 20.0, 25.0, 30.0), ("10 AWG", 30.0, 35.0, 40.0),
+    ("8 AWG", 40.0, 50.0, 55.0), ("6 AWG", 55.0, 65.0, 75.0), ("4 AWG", 70.0, 85.0, 95.0),
+    ("3 AWG", 85.0, 100.0, 115.0), ("2 AWG", 95.0, 115.0, 130.0), ("1 AWG", 110.0, 130.0, 145.0),
+    ("1/0 AWG", 125.0, 150.0, 170.0), ("2/0 AWG", 145.0, 175.0, 195.0), ("3/0 AWG", 165.0, 200.0, 225.0),
+    ("4/0 AWG", 195.0, 230.0, 260.0), ("250 kcmil", 215.0, 255.0, 290.0), ("300 kcmil", 240.0, 285.0, 320.0),
+    ("350 kcmil", 260.0, 310.0, 350.0), ("400 kcmil", 280.0, 335.0, 380.0), ("500 kcmil", 320.0, 380.0, 430.0),
+    ("600 kcmil", 350.0, 420.0, 475.0), ("750 kcmil", 400.0, 475.0, 535.0), ("1000 kcmil", 455.0, 545.0, 615.0),
+];
+
+/// NEC 310.15(C)(1) 통전 도체 4가닥 이상 조정계수
+fn nec_adjustment_factor(current_carrying: u32) -> f64 {
+    match current_carrying {
+        0..=3 => 1.0,
+        4..=6 => 0.8,
+        7..=9 => 0.7,
+        10..=20 => 0.5,
+        21..=30 => 0.45,
+        31..=40 => 0.4,
+        _ => 0.35,
+    }
+}
+
+/// NEC 허용전류 계산 (공사방법 대신 통전 도체 수 조정계수 적용)
+/// metric 입력은 안전측으로 단면적 이하인 가장 큰 AWG 규격 적용.
+/// XLPE는 90°C 열, PVC는 60°C 열 사용 (단자 등급이 낮으면 그 이하 열로 제한)
+fn compute_current_nec(data: &CableData, insulation: Insulation, awg: Option<&'static str>) -> Result<(f64, String, String, u32, u32, String), &'static str> {
+    let size = Size::try_from(data.size.as_str())?;
+    let label = match awg {
+        Some(label) => label,
+        None => AWG_SIZES
+            .iter()
+            .rev()
+            .find(|(_, area)| *area <= size.mm2())
+            .map(|(label, _)| *label)
+            .ok_or(ERR_CURRENT_DATA_NOT_FOUND)?,
+    };
+    let &(_, c60, c75, c90) = NEC_AMPACITY_TABLE
+        .iter()
+        .find(|(l, ..)| *l == label)
+        .ok_or(ERR_CURRENT_DATA_NOT_FOUND)?;
+    let cable_column = match insulation {
+        Insulation::Xlpe => 90,
+        Insulation::Pvc => 60,
+    };
+    let column_temp = data
+        .termination_temp
+        .map_or(cable_column, |temp| apply_termination_temp_limit(cable_column, temp));
+    let base_current = match column_temp {
+        90 => c90,
+        75 => c75,
+        _ => c60,
+    };
+
+    let loaded_conductors = determine_loaded_conductors(&data.cores, &data.system);
+    let current_carrying = grouping_circuit_count(data)? * loaded_conductors as u32;
+    let adjustment = nec_adjustment_factor(current_carrying);
+    // 주변온도 보정은 공기 중 표(B.52.14) 준용
+    let temp_factor = match data.ambient_temp {
+        Some(ambient) => temperature_factor(insulation, ambient, false)?,
+        None => 1.0,
+    };
+    let parallel_runs = data.parallel_runs as f64;
+    let allowable_current = apply_derating(Amperes(base_current), &[adjustment, temp_factor, parallel_runs]);
+
+    let lang = error_lang(data);
+    let t = |ko| desc_label(ko, lang);
+    let mut install_method_desc = format!(
+        "NEC 310.16 ({}°C {}) / {} {}{} / {}: {:.2}",
+        column_temp,
+        t("열"),
+        t("통전 도체"),
+        current_carrying,
+        t("가닥"),
+        t("조정계수"),
+        adjustment
+    );
+    if let Some(ambient) = data.ambient_temp {
+        install_method_desc.push_str(&format!(" / {} {}°C ({} {:.2})", t("주변온도"), ambient, t("계수"), temp_factor));
+    }
+    if data.parallel_runs > 1 {
+        install_method_desc.push_str(&parallel_runs_note(data.parallel_runs, lang));
+    }
+    let size_label = match awg {
+        Some(_) => format!("{} mm²", size.as_str()),
+        None => format!("{} mm² ({} 적용)", size.as_str(), label),
+    };
+    let allowable_current = (allowable_current.value() * 10.0).round() / 10.0;
+    Ok((
+        allowable_current,
+        install_method_desc,
+        size_label,
+        get_max_conductor_temp(insulation),
+        column_temp,
+        derating_formula(
+            Amperes(base_current),
+            &[(adjustment, "조정"), (temp_factor, "온도"), (parallel_runs, "병렬")],
+            allowable_current,
+            lang,
+        ),
+    ))
+}
+
+/// 부분 재계산 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartialRecalculation {
+    pub result: CalculationResult,
+    pub recalculated: Vec<String>,  // 수행한 계산
+    pub skipped: Vec<String>,       // 이전 결과를 재사용한 계산
+}
+
+/// 입력 변경분에 따른 부분 재계산
+/// 전압 방식·공사방법만 바뀌면 허용전류만, 길이만 바뀌면 물량만 다시 계산하고
+/// 나머지는 이전 결과를 재사용. 단위 체계·적용 규정이 바뀌었거나 imperial이면 전체 재계산
+pub fn recalculate_partial(old_data: &CableData, old_result: &CalculationResult, new_data: &CableData) -> Result<PartialRecalculation, String> {
+    const FILL: &str = "점유율(전선관)";
+    const CURRENT: &str = "허용전류";
+    const LENGTH: &str = "물량(길이)";
+
+    let metric = |d: &CableData| matches!(d.unit_system.as_deref(), None | Some("") | Some("metric"));
+    let same_regulation = resolve_regulation(old_data).ok() == resolve_regulation(new_data).ok();
+    if !(metric(old_data) && metric(new_data) && same_regulation && old_data.usage == new_data.usage) {
+        return Ok(PartialRecalculation {
+            result: calculate_core(new_data)?,
+            recalculated: vec![FILL.to_string(), CURRENT.to_string(), LENGTH.to_string()],
+            skipped: vec![],
+        });
+    }
+
+    let common = old_data.cable_type != new_data.cable_type
+        || old_data.size != new_data.size
+        || old_data.cores != new_data.cores
+        || old_data.quantity != new_data.quantity
+        || old_data.parallel_runs != new_data.parallel_runs;
+    let fill_changed = common
+        || uses_cable_tray(old_data) != uses_cable_tray(new_data)
+        || old_data.conduit_type != new_data.conduit_type
+        || old_data.custom_outer_diameter != new_data.custom_outer_diameter
+        || old_data.ground_wire != new_data.ground_wire
+        || old_data.conductor_material != new_data.conductor_material;
+    let current_changed = common
+        || old_data.system != new_data.system
+        || old_data.install_method != new_data.install_method
+        || old_data.circuit_count_policy != new_data.circuit_count_policy
+        || old_data.termination_temp != new_data.termination_temp
+        || old_data.ambient_temp != new_data.ambient_temp
+        || old_data.conductor_material != new_data.conductor_material
+        || old_data.fault_current != new_data.fault_current
+        || old_data.clearing_time != new_data.clearing_time
+        || old_data.arrangement != new_data.arrangement
+        || old_data.burial_depth_m != new_data.burial_depth_m
+        || old_data.soil_resistivity != new_data.soil_resistivity
+        || old_data.third_harmonic_percent != new_data.third_harmonic_percent
+        || old_data.neutral_loaded != new_data.neutral_loaded;
+    let length_changed = old_data.length != new_data.length
+        || old_data.quantity != new_data.quantity
+        || old_data.parallel_runs != new_data.parallel_runs
+        || old_data.ground_wire != new_data.ground_wire;
+
+    let partial = || -> Result<PartialRecalculation, &'static str> {
+        validate_combination(&new_data.cores, &new_data.system)?;
+        let regulation = resolve_regulation(new_data)?;
+        let mut result = old_result.clone();
+        let mut recalculated = Vec::new();
+        let mut skipped = Vec::new();
+
+        if fill_changed {
+            let (total_area, effective_area, conductor_area, recommended_raceway) = compute_fill(new_data, regulation)?;
+            result.total_area = total_area;
+            result.effective_area = effective_area;
+            result.conductor_area = conductor_area;
+            result.fill_rate = recommended_raceway.fill_rate;
+            set_formula(&mut result.formulas, FORMULA_FILL, fill_formula(total_area, &recommended_raceway, error_lang(new_data)));
+            result.recommended_raceway = recommended_raceway;
+            result.ground_wire_size = protective_conductor_size(new_data)?.unwrap_or_default().to_string();
+            recalculated.push(FILL.to_string());
+        } else {
+            skipped.push(FILL.to_string());
+        }
+
+        if current_changed {
+            let (allowable_current, install_method_desc, size_label, conductor_max_temp, applied_temp_rating, formula) =
+                compute_current(new_data, resolve_install_method(new_data)?, regulation, None)?;
+            set_formula(&mut result.formulas, FORMULA_CURRENT, Some(formula));
+            result.allowable_current = allowable_current;
+            result.install_method_desc = install_method_desc;
+            result.size_label = size_label;
+            result.conductor_max_temp = conductor_max_temp;
+            result.applied_temp_rating = applied_temp_rating;
+            let short_circuit = compute_short_circuit(new_data)?;
+            result.short_circuit_ok = short_circuit.as_ref().map(|&(ok, ..)| ok);
+            result.short_circuit_min_size = short_circuit.as_ref().map(|&(_, min_area, _)| min_area);
+            set_formula(&mut result.formulas, FORMULA_SHORT_CIRCUIT, short_circuit.map(|(.., formula)| formula));
+            recalculated.push(CURRENT.to_string());
+        } else {
+            skipped.push(CURRENT.to_string());
+        }
+
+        if length_changed {
+            let (total_cable_length, ground_wire_length) = compute_lengths(new_data);
+            result.total_cable_length = total_cable_length;
+            result.ground_wire_length = ground_wire_length;
+            recalculated.push(LENGTH.to_string());
+        } else {
+            skipped.push(LENGTH.to_string());
+        }
+        result.data_provenance = current_data_provenance(regulation, unix_now().as_secs());
+        let install_method = resolve_install_method(new_data)?;
+        let mut resolved = new_data.clone();
+        resolved.install_method = install_method.as_str().to_string();
+        result.alternatives = size_alternatives(&ResolvedInput { data: resolved, awg: None, regulation, install_method });
+        result.size = new_data.size.clone();
+        result.load_margin_percent = load_margin_percent(new_data.load_current, result.allowable_current);
+        result.recommended_breaker_a = breaker_for_load(new_data.load_current, result.allowable_current);
+        result.reference = result_reference(new_data, install_method, regulation);
+        result.annual_loss_kwh = annual_loss_for(new_data);
+        result.min_bending_radius_mm = bending_radius_for(new_data);
+
+        Ok(PartialRecalculation { result, recalculated, skipped })
+    };
+    partial().map_err(|key| localize_error(key, error_lang(new_data)))
+}
+
+/// 전압 방식별 허용전류 미리보기 항목
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SystemPreviewEntry {
+    pub system: String,                   // 전압 방식 (1Φ, 3Φ)
+    pub loaded_conductors: u8,            // 부하 도체 수
+    pub allowable_current: f64,           // 보정 후 허용전류 (A)
+    pub recommended_size: Option<String>, // 부하전류를 만족하는 최소 규격 (부하전류 미지정 시 None)
+    pub selected: bool,                   // 현재 선택된 방식
+}
+
+/// 전압 방식 변경 미리보기
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SystemChangePreview {
+    pub entries: Vec<SystemPreviewEntry>,  // 계산 가능한 전압 방식만 (1Φ, 3Φ 순)
+    pub difference_percent: Option<f64>,   // 1Φ 대비 3Φ 허용전류 차이 (%), 한쪽만 가능하면 None
+    pub warning: Option<String>,           // 3Φ 전환 시 허용전류 감소 경고
+}
+
+/// 부하전류 이상의 보정 후 허용전류를 갖는 최소 표준 규격 (전선 종류·공사방법 등은 그대로)
+fn recommended_size_for_load(data: &CableData, load_current: f64) -> Option<String> {
+    STANDARD_SIZES.iter().find_map(|size| {
+        let mut candidate = data.clone();
+        candidate.size = size.to_string();
+        calculate_core(&candidate)
+            .ok()
+            .filter(|result| result.allowable_current >= load_current)
+            .map(|_| size.to_string())
+    })
+}
+
+/// 같은 케이블의 1Φ(2부하)·3Φ(3부하) 허용전류와 차이 미리보기
+/// 데이터가 없거나 조합이 맞지 않는 방식은 제외, 둘 다 불가하면 첫 에러 반환
+pub fn preview_system_change(data: &CableData) -> Result<SystemChangePreview, String> {
+    let mut entries = Vec::new();
+    let mut first_error = None;
+    for system in ["1Φ", "3Φ"] {
+        let mut candidate = data.clone();
+        candidate.system = system.to_string();
+        match calculate_core(&candidate) {
+            Ok(result) => entries.push(SystemPreviewEntry {
+                system: system.to_string(),
+                loaded_conductors: determine_loaded_conductors(&candidate.cores, system),
+                allowable_current: result.allowable_current,
+                recommended_size: candidate.load_current.and_then(|load| recommended_size_for_load(&candidate, load)),
+                selected: data.system == system,
+            }),
+            Err(e) => {
+                first_error.get_or_insert(e);
+            }
+        }
+    }
+    if entries.is_empty() {
+        return Err(first_error.unwrap_or_default());
+    }
+
+    let (difference_percent, warning) = match entries.as_slice() {
+        [single, three] if single.allowable_current > 0.0 => {
+            let diff = (three.allowable_current - single.allowable_current) / single.allowable_current * 100.0;
+            let warning = (three.allowable_current < single.allowable_current).then(|| match label_lang(error_lang(data)) {
+                "en" => format!(
+                    "Switching to 3Φ raises loaded conductors from {} to {}, reducing ampacity {:.1}A -> {:.1}A ({:.1}% lower)",
+                    single.loaded_conductors,
+                    three.loaded_conductors,
+                    single.allowable_current,
+                    three.allowable_current,
+                    -diff
+                ),
+                _ => format!(
+                    "3Φ 전환 시 부하 도체 {}가닥 -> {}가닥으로 허용전류 {:.1}A -> {:.1}A ({:.1}% 감소)",
+                    single.loaded_conductors,
+                    three.loaded_conductors,
+                    single.allowable_current,
+                    three.allowable_current,
+                    -diff
+                ),
+            });
+            (Some((diff * 10.0).round() / 10.0), warning)
+        }
+        _ => (None, None),
+    };
+    Ok(SystemChangePreview { entries, difference_percent, warning })
+}
+
+/// 규정 간 차이 강조 기준 (%)
+const CROSS_VALIDATION_THRESHOLD_PERCENT: f64 = 10.0;
+
+/// 규정 간 비교 항목
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RegulationDifference {
+    pub item: String,                     // 비교 항목 (허용전류, 점유율 상한, ..., 입력 언어로 표기)
+    pub kec: String,                      // KEC 기준 값 (표시 문자열)
+    pub iec: String,                      // IEC 기준 값 (표시 문자열)
+    pub difference_percent: Option<f64>,  // KEC 대비 IEC 차이 (%), 수치 항목만
+    pub significant: bool,                // 값이 다르고 차이가 기준(10%) 이상이거나 수치가 아닌 항목이 다름
+}
+
+/// KEC/IEC 교차 검증 결과
+/// 한 규정만 계산 가능하면 그 결과만 채우고 differences는 비움
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrossValidationResult {
+    pub kec: Option<CalculationResult>,
+    pub iec: Option<CalculationResult>,
+    pub differences: Vec<RegulationDifference>,
+    pub note: Option<String>,  // 한 규정만 계산된 경우 사유
+}
+
+/// 수치 항목 비교 (KEC 대비 IEC 차이 %)
+fn numeric_difference(item: &str, kec: f64, iec: f64, unit: &str) -> RegulationDifference {
+    let difference_percent = (kec != 0.0).then(|| ((iec - kec) / kec * 100.0 * 10.0).round() / 10.0);
+    RegulationDifference {
+        item: item.to_string(),
+        kec: format!("{} {}", kec, unit),
+        iec: format!("{} {}", iec, unit),
+        difference_percent,
+        significant: difference_percent.is_some_and(|diff| diff.abs() >= CROSS_VALIDATION_THRESHOLD_PERCENT),
+    }
+}
+
+/// 문자열 항목 비교 (다르면 강조)
+fn text_difference(item: &str, kec: &str, iec: &str) -> RegulationDifference {
+    RegulationDifference {
+        item: item.to_string(),
+        kec: kec.to_string(),
+        iec: iec.to_string(),
+        difference_percent: None,
+        significant: kec != iec,
+    }
+}
+
+/// 같은 입력을 KEC·IEC 기준으로 각각 계산해 항목별 차이 비교
+/// 비교 항목: 허용전류, 점유율 상한, 추천 전선관, 점유율.
+/// 접지선 규격은 두 규정 모두 KEC 542.3(IEC 60364-5-54) 표를 따르므로 비교하지 않음.
+/// 두 규정 모두 계산 불가하면 KEC 에러 반환
+pub fn cross_validate_regulations(data: &CableData) -> Result<CrossValidationResult, String> {
+    let lang = error_lang(data);
+    let t = |ko| desc_label(ko, lang);
+    let calculate = |regulation: Regulation| {
+        let mut candidate = data.clone();
+        candidate.regulation = Some(regulation.as_str().to_string());
+        calculate_core(&candidate)
+    };
+    let skipped = |regulation: Regulation, e: String| match label_lang(lang) {
+        "en" => format!("Comparison skipped because the {} calculation failed: {}", regulation.as_str(), e),
+        _ => format!("{} 계산 불가로 비교 생략: {}", regulation.as_str(), e),
+    };
+    let (kec, iec) = match (calculate(Regulation::Kec), calculate(Regulation::Iec)) {
+        (Ok(kec), Ok(iec)) => (kec, iec),
+        (Err(e), Err(_)) => return Err(e),
+        (Ok(kec), Err(e)) => {
+            return Ok(CrossValidationResult {
+                kec: Some(kec),
+                iec: None,
+                differences: Vec::new(),
+                note: Some(skipped(Regulation::Iec, e)),
+            })
+        }
+        (Err(e), Ok(iec)) => {
+            return Ok(CrossValidationResult {
+                kec: None,
+                iec: Some(iec),
+                differences: Vec::new(),
+                note: Some(skipped(Regulation::Kec, e)),
+            })
+        }
+    };
+
+    let mut differences = vec![numeric_difference(t("허용전류"), kec.allowable_current, iec.allowable_current, "A")];
+    let cable_count = data.quantity + u32::from(data.ground_wire == "HFIX");
+    differences.push(numeric_difference(
+        t("점유율 상한"),
+        (Regulation::Kec.conduit_fill_limit(cable_count) * 100.0).round(),
+        (Regulation::Iec.conduit_fill_limit(cable_count) * 100.0).round(),
+        "%",
+    ));
+    differences.push(text_difference(t("추천 전선관"), &kec.recommended_raceway.name, &iec.recommended_raceway.name));
+    differences.push(numeric_difference(t("점유율"), kec.fill_rate, iec.fill_rate, "%"));
+    Ok(CrossValidationResult { kec: Some(kec), iec: Some(iec), differences, note: None })
+}
+
+/// 가닥수가 지원하는 공사방법별 보정 후 허용전류 (공사방법 선택 미리보기용)
+/// 입력의 공사방법만 바꿔 계산하며 순서는 install_methods_for_cores 순서,
+/// 데이터가 없거나 계산 에러인 조합은 제외 (입력 자체가 잘못되면 빈 목록)
+pub fn compare_install_methods(data: &CableData) -> Vec<(String, f64)> {
+    install_methods_for_cores(data.cores.trim())
+        .iter()
+        .filter_map(|method| {
+            let candidate = CableData { install_method: method.as_str().to_string(), ..data.clone() };
+            let result = calculate_core(&candidate).ok()?;
+            Some((method.as_str().to_string(), result.allowable_current))
+        })
+        .collect()
+}
+
+/// 주변온도·회로 수 변화에 따른 허용전류 민감도 매트릭스
+/// 행: 주변온도(오름차순), 열: 회로 수(오름차순)
+/// 각 셀 = 기본값 × 온도 보정계수(temperature_factor 표) × 집합 보정계수, 도체 최고온도 이상인 행은 0.
+/// 그 밖에 보정표 범위를 벗어난 온도는 Err
+pub fn sensitivity_analysis(data: &CableData, temps: &[u32], circuits: &[u32]) -> Result<Vec<Vec<f64>>, String> {
+    let lang = error_lang(data);
+    validate_combination(&data.cores, &data.system).map_err(|key| localize_error(key, lang))?;
+
+    let insulation = get_insulation_type(&data.cable_type);
+    let loaded_conductors = determine_loaded_conductors(&data.cores, &data.system);
+    let (install_method, base_current) = resolve_install_method(data)
+        .and_then(|method| {
+            let size = Size::try_from(data.size.as_str())?;
+            Ok((method, lookup_base_current(size, insulation, method, loaded_conductors)?))
+        })
+        .map_err(|key| localize_error(key, lang))?;
+    let buried = install_method.is_buried();
+    let arrangement = data.arrangement.unwrap_or_default();
+
+    let mut temps = temps.to_vec();
+    temps.sort_unstable();
+    temps.dedup();
+    let mut circuits = circuits.to_vec();
+    circuits.sort_unstable();
+    circuits.dedup();
+
+    let max_temp = get_max_conductor_temp(insulation);
+    temps
+        .iter()
+        .map(|&temp| {
+            let temp_factor = if temp >= max_temp {
+                0.0
+            } else {
+                temperature_factor(insulation, temp as i32, buried).map_err(|key| localize_error(key, lang))?
+            };
+            Ok(circuits
+                .iter()
+                .map(|&n| (apply_derating(base_current, &[temp_factor, get_grouping_factor(n, arrangement)]).value() * 10.0).round() / 10.0)
+                .collect())
+        })
+        .collect()
+}
+
+/// 수직 포설 검토 기준
+const COPPER_DENSITY: f64 = 8.89e-3;            // 동 도체 kg/m per mm²
+const SHEATH_DENSITY: f64 = 1.4e-3;             // 절연체·시스(XLPE/PVC) kg/m per mm²
+const CONDUCTOR_ALLOWABLE_TENSION: f64 = 7.0;   // 동 도체 허용 장력 kgf/mm²
+const STRICT_TENSION_FACTOR: f64 = 0.7;         // 내화/대규격(240mm² 이상) 강화 계수
+const VERTICAL_SUPPORT_SPACING_MAX: f64 = 2.0;  // 수직 지지점 최대 간격 (m)
+const DEFAULT_PULL_FRICTION: f64 = 0.5;         // 전선관 내 케이블 동마찰계수 (윤활제 사용 시 대표값)
+
+/// 견인 경로 구간 (입구 → 출구 순서)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PullSection {
+    Straight { length: f64 },    // 수평 직선 (m)
+    Vertical { length: f64 },    // 상향 수직 (m)
+    Bend { angle_deg: f64 },     // 굴곡 (도)
+}
+
+/// 견인 장력 계산 결과 (kgf)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PullingTension {
+    pub section_tensions: Vec<f64>,  // 각 구간 출구 장력 (kgf, 소수 첫째 자리)
+    pub total_tension: f64,          // 최종 견인 장력 (kgf)
+    pub allowable_tension: f64,      // 도체 허용 장력 (kgf)
+    pub exceeded: bool,              // 허용 장력 초과 여부
+}
+
+/// 수직 포설 검토 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerticalCheck {
+    pub checked: bool,                   // 수직 길이 0이면 검토 생략
+    pub vertical_length: f64,            // 수직 구간 길이 (m)
+    pub cable_weight: f64,               // 케이블 단위 중량 (kg/m)
+    pub max_vertical_length: f64,        // 지지 없이 허용되는 최대 수직장 (m)
+    pub exceeded: bool,                  // 허용 수직장 초과 여부
+    pub support_spacing: Option<f64>,    // 제안 클리트 간격 (m)
+    pub support_count: u32,              // 필요 중간 지지 개수
+}
+
+/// 케이블 단위 중량 근사 (kg/m)
+/// 도체(동) 중량 + (외경 단면적 - 도체 단면적) × 절연체·시스 밀도
+pub fn get_cable_weight(cable_type: &str, size: &str, cores: &str) -> Option<f64> {
+    let outer_diameter = get_cable_outer_diameter(cable_type, size, cores)?;
+    let core_count: f64 = cores.trim_end_matches('C').parse().ok()?;
+    let conductor_area = size.parse::<f64>().ok()? * core_count;
+    let sheath_area = (calculate_cable_area(outer_diameter) - Millimeters2(conductor_area)).value().max(0.0);
+    Some(conductor_area * COPPER_DENSITY + sheath_area * SHEATH_DENSITY)
+}
+
+/// 케이블 단위 중량(kg/m)과 도체 허용 장력(kgf)
+/// 허용 장력 = 7kgf/mm² × 도체 단면적, 내화(FR-CV) 및 240mm² 이상 대규격은 0.7 적용
+fn cable_weight_and_allowable_tension(data: &CableData) -> Result<(f64, f64), &'static str> {
+    let size: f64 = data.size.parse().map_err(|_| ERR_INVALID_SIZE)?;
+    let cable_weight = get_cable_weight(&data.cable_type, &data.size, &data.cores).ok_or(ERR_UNSUPPORTED_SIZE)?;
+    let core_count: f64 = data.cores.trim_end_matches('C').parse().unwrap_or(1.0);
+    let strict = data.cable_type == "FR-CV" || size >= 240.0;
+    let tension_factor = if strict { STRICT_TENSION_FACTOR } else { 1.0 };
+    Ok((cable_weight, CONDUCTOR_ALLOWABLE_TENSION * size * core_count * tension_factor))
+}
+
+/// 견인 장력 계산 (kgf, 입구 장력 0에서 시작해 구간 순서대로 누적)
+/// 수평 직선: T_out = T_in + W·μ·L, 상향 수직: T_out = T_in + W·L,
+/// 굴곡: T_out = T_in · e^(μθ) (캡스턴 식, θ는 rad)
+/// 출처: IEEE Std 1185 (케이블 포설 권장 실무) 견인 장력 산정식, W는 단위 중량(kg/m)
+/// friction 미지정 시 동마찰계수 0.5
+pub fn calculate_pulling_tension(data: &CableData, sections: &[PullSection], friction: Option<f64>) -> Result<PullingTension, String> {
+    let compute = || -> Result<PullingTension, &'static str> {
+        let mu = friction.unwrap_or(DEFAULT_PULL_FRICTION);
+        if !(mu > 0.0 && mu.is_finite()) {
+            return Err(ERR_INVALID_PULL_SECTION);
+        }
+        let (weight, allowable_tension) = cable_weight_and_allowable_tension(data)?;
+        let mut tension = 0.0;
+        let mut section_tensions = Vec::with_capacity(sections.len());
+        for section in sections {
+            tension = match *section {
+                PullSection::Straight { length } if length >= 0.0 && length.is_finite() => tension + weight * mu * length,
+                PullSection::Vertical { length } if length >= 0.0 && length.is_finite() => tension + weight * length,
+                PullSection::Bend { angle_deg } if (0.0..=360.0).contains(&angle_deg) => tension * (mu * angle_deg.to_radians()).exp(),
+                _ => return Err(ERR_INVALID_PULL_SECTION),
+            };
+            section_tensions.push((tension * 10.0).round() / 10.0);
+        }
+        Ok(PullingTension {
+            section_tensions,
+            total_tension: (tension * 10.0).round() / 10.0,
+            allowable_tension: (allowable_tension * 10.0).round() / 10.0,
+            exceeded: tension > allowable_tension,
+        })
+    };
+    compute().map_err(|key| localize_error(key, error_lang(data)))
+}
+
+/// 수직 포설 구간의 자중 검토
+/// 허용 수직장 = 도체 허용 장력 / 단위 중량 (견인 장력 식의 상향 수직 구간 T = W·L이 허용 장력에 도달하는 길이)
+/// 내화(FR-CV) 및 240mm² 이상 대규격은 허용 장력에 0.7 적용
+pub fn check_vertical_run(data: &CableData, vertical_length: f64) -> Result<VerticalCheck, String> {
+    let lang = error_lang(data);
+    if vertical_length < 0.0 || !vertical_length.is_finite() {
+        return Err(localize_error(ERR_INVALID_VERTICAL_LENGTH, lang));
+    }
+    let (cable_weight, allowable_tension) = cable_weight_and_allowable_tension(data).map_err(|key| localize_error(key, lang))?;
+    let max_vertical_length = ((allowable_tension / cable_weight) * 10.0).round() / 10.0;
+    let cable_weight = (cable_weight * 1000.0).round() / 1000.0;
+
+    if vertical_length == 0.0 {
+        return Ok(VerticalCheck {
+            checked: false,
+            vertical_length,
+            cable_weight,
+            max_vertical_length,
+            exceeded: false,
+            support_spacing: None,
+            support_count: 0,
+        });
+    }
+
+    let exceeded = vertical_length > max_vertical_length;
+    let (support_spacing, support_count) = if exceeded {
+        // 0.1m 단위 내림, 지지점 최대 간격 이하
+        let spacing = (max_vertical_length.min(VERTICAL_SUPPORT_SPACING_MAX) * 10.0).floor() / 10.0;
+        let count = (vertical_length / spacing).ceil() as u32 - 1;
+        (Some(spacing), count)
+    } else {
+        (None, 0)
+    };
+
+    Ok(VerticalCheck {
+        checked: true,
+        vertical_length,
+        cable_weight,
+        max_vertical_length,
+        exceeded,
+        support_spacing,
+        support_count,
+    })
+}
+
+const COPPER_RESISTIVITY_20C: f64 = 1.0 / 58.0;  // 동 고유저항 (Ω·mm²/m, 20°C)
+const COPPER_TEMP_COEFFICIENT: f64 = 0.00393;    // 동 저항 온도계수 (1/°C)
+const ALUMINIUM_RESISTIVITY_20C: f64 = 1.0 / 35.0;  // 알루미늄 고유저항 (Ω·mm²/m, 20°C)
+const ALUMINIUM_TEMP_COEFFICIENT: f64 = 0.00403;    // 알루미늄 저항 온도계수 (1/°C)
+const CABLE_REACTANCE: OhmsPerKm = OhmsPerKm(0.08); // 저압 케이블 리액턴스 근사값
+const DEFAULT_POWER_FACTOR: f64 = 0.9;
+const DEFAULT_TRANSFORMER_XR_RATIO: f64 = 5.0;   // 배전용 변압기 X/R 근사값
+const FEET_TO_METERS: f64 = 0.3048;
+
+/// 상류 변압기 정보
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransformerInfo {
+    pub kva: f64,                   // 정격 용량 (kVA)
+    pub impedance_percent: f64,     // %임피던스 (%Z)
+    #[serde(default)]
+    pub x_r_ratio: Option<f64>,     // X/R 비, 미지정 시 5
+}
+
+/// 전압강하 계산 입력
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VoltageDropInput {
+    pub length: f64,                // 선로 길이 (m, imperial이면 ft)
+    #[serde(default)]
+    pub current: Option<f64>,       // 부하전류 (A), 미지정 시 data.load_current
+    pub voltage: f64,               // 공칭전압 (V, 3상은 선간전압)
+    #[serde(default)]
+    pub power_factor: Option<f64>,  // 역률, 미지정 시 0.9
+    #[serde(default)]
+    pub transformer: Option<TransformerInfo>,
+    #[serde(default)]
+    pub installation_type: Option<String>,  // 설비 유형 (VOLTAGE_DROP_LIMITS), 미지정 시 "저압수전 기타"
+    #[serde(default)]
+    pub is_branch: bool,                    // 분기(최종) 회로 여부
+}
+
+/// 전압강하 계산 결과 (V, %)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VoltageDropResult {
+    pub cable_drop: f64,                // 케이블 기여분 (V)
+    pub transformer_drop: f64,          // 변압기 기여분 (V), 변압기 정보 없으면 0
+    pub total_drop: f64,                // 합계 (V)
+    pub cable_drop_percent: f64,        // 케이블 기여분 (%)
+    pub transformer_drop_percent: f64,  // 변압기 기여분 (%)
+    pub total_drop_percent: f64,        // 합계 (%)
+    pub limit_percent: f64,             // 적용 허용치 (%)
+    pub limit_basis: String,            // 허용치 근거
+    pub within_limit: bool,             // 합계가 허용치 이내인지
+    #[serde(default)]
+    pub formulas: Vec<String>,          // 계산 근거 수식 (대입값 포함)
+}
+
+/// KEC 232.3.9 표 232.3-1 수용가설비 전압강하 허용치 (설비 유형, 허용치 %)
+/// A: 저압으로 수전, B: 고압 이상으로 수전
+const VOLTAGE_DROP_LIMITS: &[(&str, f64)] = &[
+    ("저압수전 조명", 3.0),
+    ("저압수전 기타", 5.0),
+    ("고압수전 조명", 6.0),
+    ("고압수전 기타", 8.0),
+];
+const DEFAULT_INSTALLATION_TYPE: &str = "저압수전 기타";
+const LONG_RUN_THRESHOLD: Meters = Meters(100.0);  // 추가 허용분 적용 기준 길이
+const LONG_RUN_ALLOWANCE_PER_M: f64 = 0.005;     // 100m 초과분 미터당 추가 허용치 (%)
+const LONG_RUN_ALLOWANCE_MAX: f64 = 0.5;         // 추가 허용치 상한 (%)
+
+/// 설비 유형·회로 구분·선로 길이(m)에 따른 전압강하 허용치 (%, 근거)
+/// 고압수전이라도 분기(최종) 회로는 가능한 한 A(저압수전) 값을 넘지 않도록 함
+fn voltage_drop_limit(installation_type: &str, is_branch: bool, length: Meters) -> Result<(f64, String), &'static str> {
+    let installation_type = installation_type.trim();
+    let (_, mut limit) = *VOLTAGE_DROP_LIMITS
+        .iter()
+        .find(|(kind, _)| *kind == installation_type)
+        .ok_or(ERR_UNKNOWN_INSTALLATION_TYPE)?;
+    let mut basis = format!("KEC 232.3.9 표 232.3-1 {} {}%", installation_type, limit);
+    if is_branch && installation_type.starts_with("고압수전") {
+        let low_voltage_kind = installation_type.replacen("고압수전", "저압수전", 1);
+        if let Some((_, branch_limit)) = VOLTAGE_DROP_LIMITS.iter().find(|(kind, _)| *kind == low_voltage_kind) {
+            limit = *branch_limit;
+            basis = format!("{} (최종회로는 A 유형 {}% 적용)", basis, limit);
+        }
+    }
+    if length > LONG_RUN_THRESHOLD {
+        let allowance = ((length - LONG_RUN_THRESHOLD).value() * LONG_RUN_ALLOWANCE_PER_M).min(LONG_RUN_ALLOWANCE_MAX);
+        limit += allowance;
+        basis = format!("{} + 100m 초과분 {:.2}%", basis, allowance);
+    }
+    Ok(((limit * 100.0).round() / 100.0, basis))
+}
+
+/// 전압강하 허용치 (%, lang: ko/en)
+/// length_over_100m이면 100m 초과분 추가 허용치의 상한(0.5%)을 더함
+/// (실제 길이로 판정할 때는 calculate_voltage_drop이 미터당 0.005%로 산정)
+pub fn get_voltage_drop_limit(installation_type: &str, is_branch: bool, length_over_100m: bool, lang: &str) -> Result<f64, String> {
+    let length = if length_over_100m {
+        LONG_RUN_THRESHOLD + Meters(LONG_RUN_ALLOWANCE_MAX / LONG_RUN_ALLOWANCE_PER_M)
+    } else {
+        Meters(0.0)
+    };
+    voltage_drop_limit(installation_type, is_branch, length)
+        .map(|(limit, _)| limit)
+        .map_err(|key| localize_error(key, lang))
+}
+
+/// 도체 최고온도에서의 도체 교류저항 근사 (Ω/km)
+/// R = ρ20 / A × (1 + α(θmax - 20)) × 1000
+fn conductor_resistance_per_km(area: Millimeters2, insulation: Insulation, material: Material) -> OhmsPerKm {
+    let temp_rise = get_max_conductor_temp(insulation) as f64 - 20.0;
+    let (resistivity, temp_coefficient) = match material {
+        Material::Copper => (COPPER_RESISTIVITY_20C, COPPER_TEMP_COEFFICIENT),
+        Material::Aluminium => (ALUMINIUM_RESISTIVITY_20C, ALUMINIUM_TEMP_COEFFICIENT),
+    };
+    OhmsPerKm(resistivity / area.value() * (1.0 + temp_coefficient * temp_rise) * 1000.0)
+}
+
+/// 상당 병렬 도체 수 (전압강하·전력손실 공통)
+/// 1C는 상당 가닥수로 나눈 상별 묶음 수 × 병렬 런, 다심은 병렬 런만 (수량은 별도 회로 수)
+fn parallel_conductor_count(data: &CableData) -> f64 {
+    let per_run = if data.cores == "1C" {
+        let per_circuit = determine_loaded_conductors(&data.cores, &data.system) as u32;
+        (data.quantity / per_circuit).max(1)
+    } else {
+        1
+    };
+    (per_run * data.parallel_runs.max(1)) as f64
+}
+
+/// 연간 전력손실 산정 기본 운전시간 (h/년, 연속 운전)
+const DEFAULT_OPERATING_HOURS_PER_YEAR: f64 = 8760.0;
+
+/// 도체 저항 손실 (W): 통전 도체 수 × I² × R(θ) × L ÷ 병렬 수
+/// R(θ)는 conductor_resistance_per_km와 같이 도체 최고허용온도에서의 저항
+fn conductor_loss_watts(resistance_per_km: OhmsPerKm, length: Meters, current: f64, system: &str, parallel: f64) -> f64 {
+    let conductors = if system == "3Φ" { 3.0 } else { 2.0 };
+    let resistance = (resistance_per_km * length).value();
+    conductors * current * current * resistance / parallel
+}
+
+/// 연간 도체 전력손실 (kWh, 소수 첫째 자리)
+/// 3Φ는 3선, 1Φ·DC는 2선 손실, 도체 저항은 20°C 저항 × [1 + α(θ - 20)]로 온도 보정
+/// 운전온도 θ는 절연체(PVC 70°C, XLPE 90°C)의 도체 최고허용온도
+/// 규격·재질·절연체를 해석할 수 없거나 길이·전류·운전시간이 0 이하이면 0
+pub fn calculate_power_loss(size: &str, material: &str, insulation: &str, length_m: f64, current: f64, system: &str, hours_per_year: f64) -> f64 {
+    let (Ok(size), Ok(material), Ok(insulation)) = (
+        Size::try_from(size.trim()),
+        Material::try_from(material.trim()),
+        Insulation::try_from(insulation.trim()),
+    ) else {
+        return 0.0;
+    };
+    if !(length_m > 0.0 && current > 0.0 && hours_per_year > 0.0) {
+        return 0.0;
+    }
+    let resistance_per_km = conductor_resistance_per_km(Millimeters2(size.mm2()), insulation, material);
+    let watts = conductor_loss_watts(resistance_per_km, Meters(length_m), current, system, 1.0);
+    (watts * hours_per_year / 1000.0 * 10.0).round() / 10.0
+}
+
+/// 계산 입력(mm² 규격)의 연간 전력손실 (kWh, 연속 운전 기준), 길이·부하전류가 없으면 None
+/// 케이블 절연체의 도체 최고허용온도 반영, 부하전류를 나눠 흘리는 병렬 런 수로만 나눔
+/// (수량은 각자 부하전류를 흘리는 별도 케이블이므로 손실을 줄이지 않음)
+fn annual_loss_for(data: &CableData) -> Option<f64> {
+    let current = data.load_current.filter(|load| *load > 0.0)?;
+    if data.length <= 0.0 {
+        return None;
+    }
+    let area = Millimeters2(Size::try_from(data.size.as_str()).ok()?.mm2());
+    let length = if data.unit_system.as_deref() == Some("imperial") {
+        Meters::from_feet(data.length)
+    } else {
+        Meters(data.length)
+    };
+    let material = resolve_material(data).ok()?;
+    let resistance_per_km = conductor_resistance_per_km(area, get_insulation_type(&data.cable_type), material);
+    let watts = conductor_loss_watts(resistance_per_km, length, current, &data.system, data.parallel_runs.max(1) as f64);
+    Some((watts * DEFAULT_OPERATING_HOURS_PER_YEAR / 1000.0 * 10.0).round() / 10.0)
+}
+
+/// 전압강하 계산 (케이블 + 선택적 상류 변압기)
+/// 3상: ΔV = √3 · I · (R cosφ + X sinφ), 단상: ΔV = 2 · I · (R cosφ + X sinφ) (왕복)
+/// 직류: ΔV = 2 · I · R · L (리액턴스·역률 무시, 변압기 적용 불가)
+/// 변압기 정보가 있으면 2차측 환산 임피던스에 의한 강하를 더함
+/// 합계는 설비 유형·회로 구분·선로 길이에 따른 KEC 허용치로 판정
+pub fn calculate_voltage_drop(data: &CableData, input: &VoltageDropInput) -> Result<VoltageDropResult, String> {
+    let compute = || -> Result<VoltageDropResult, &'static str> {
+        let imperial = data.unit_system.as_deref() == Some("imperial");
+        let area = Millimeters2(if imperial {
+            awg_to_mm2(&data.size).ok_or(ERR_INVALID_AWG)?
+        } else {
+            Size::try_from(data.size.as_str())?.mm2()
+        });
+        let length = if imperial { Meters::from_feet(input.length) } else { Meters(input.length) };
+        let current = Amperes(input.current.or(data.load_current).unwrap_or(0.0));
+        let power_factor = input.power_factor.unwrap_or(DEFAULT_POWER_FACTOR);
+        if !(length.value() > 0.0 && current.value() > 0.0 && input.voltage > 0.0 && power_factor > 0.0 && power_factor <= 1.0) {
+            return Err(ERR_INVALID_VOLTAGE_DROP_INPUT);
+        }
+        let sin_phi = (1.0 - power_factor * power_factor).sqrt();
+        let three_phase = data.system == "3Φ";
+        let dc = data.system == "DC";
+        if dc && input.transformer.is_some() {
+            return Err(ERR_TRANSFORMER_IN_DC);
+        }
+
+        let parallel = parallel_conductor_count(data);
+        let resistance_per_km = conductor_resistance_per_km(area, get_insulation_type(&data.cable_type), resolve_material(data)?);
+        let resistance = resistance_per_km * length / parallel;
+        let reactance = CABLE_REACTANCE * length / parallel;
+        let loop_factor = if three_phase { 3f64.sqrt() } else { 2.0 };
+        let loop_label = if three_phase { "√3" } else { "2" };
+        let cable_drop = if dc {
+            resistance * current * loop_factor
+        } else {
+            (resistance * power_factor + reactance * sin_phi) * current * loop_factor
+        };
+        let t = |ko| desc_label(ko, error_lang(data));
+        let parallel_label = if parallel > 1.0 { format!(" ÷ {}({})", parallel, t("병렬")) } else { String::new() };
+        let mut formulas = vec![if dc {
+            format!(
+                "{} = 2 × {}A × {:.3}km × {:.3}Ω/km{} = {:.2}V",
+                t("전압강하(직류)"),
+                current.value(),
+                length.km(),
+                resistance_per_km.value(),
+                parallel_label,
+                cable_drop.value()
+            )
+        } else {
+            format!(
+                "{} = {} × {}A × {:.3}km × ({:.3}Ω/km × {:.2} + {:.3}Ω/km × {:.2}){} = {:.2}V",
+                t("전압강하"),
+                loop_label,
+                current.value(),
+                length.km(),
+                resistance_per_km.value(),
+                power_factor,
+                CABLE_REACTANCE.value(),
+                sin_phi,
+                parallel_label,
+                cable_drop.value()
+            )
+        }];
+
+        // 변압기 2차측 환산 임피던스 (상당 1상, Ω)
+        //   Z_tr = (%Z / 100) × V² / S   (V: 2차 정격전압[V], 3상은 선간, S: 정격용량[VA])
+        //   R_tr = Z_tr / √(1 + (X/R)²),  X_tr = R_tr × (X/R)
+        // 강하: 3상 √3 · I · (R_tr cosφ + X_tr sinφ), 단상 I · (R_tr cosφ + X_tr sinφ)
+        // (단상 변압기의 %Z는 왕복 루프 임피던스 기준이므로 2배하지 않음)
+        let transformer_drop = match &input.transformer {
+            None => Volts(0.0),
+            Some(tr) => {
+                if !(tr.kva > 0.0 && tr.impedance_percent > 0.0) {
+                    return Err(ERR_INVALID_TRANSFORMER);
+                }
+                let xr = tr.x_r_ratio.filter(|r| *r > 0.0).unwrap_or(DEFAULT_TRANSFORMER_XR_RATIO);
+                let z = Ohms(tr.impedance_percent / 100.0 * input.voltage.powi(2) / (tr.kva * 1000.0));
+                let r = z / (1.0 + xr * xr).sqrt();
+                let x = r * xr;
+                let factor = if three_phase { 3f64.sqrt() } else { 1.0 };
+                let drop = (r * power_factor + x * sin_phi) * current * factor;
+                formulas.push(format!(
+                    "{} = {}{}A × ({:.4}Ω × {:.2} + {:.4}Ω × {:.2}) = {:.2}V",
+                    t("변압기 전압강하"),
+                    if three_phase { "√3 × " } else { "" },
+                    current.value(),
+                    r.value(),
+                    power_factor,
+                    x.value(),
+                    sin_phi,
+                    drop.value()
+                ));
+                drop
+            }
+        };
+
+        let percent = |v: Volts| (v.value() / input.voltage * 100.0 * 100.0).round() / 100.0;
+        let total_drop = cable_drop + transformer_drop;
+        let installation_type = input.installation_type.as_deref().unwrap_or(DEFAULT_INSTALLATION_TYPE);
+        let (limit_percent, limit_basis) = voltage_drop_limit(installation_type, input.is_branch, length)?;
+        formulas.push(format!(
+            "{} = {:.2}V ÷ {}V × 100 = {:.2}%",
+            t("전압강하율"),
+            total_drop.value(),
+            input.voltage,
+            percent(total_drop)
+        ));
+        Ok(VoltageDropResult {
+            cable_drop: (cable_drop.value() * 100.0).round() / 100.0,
+            transformer_drop: (transformer_drop.value() * 100.0).round() / 100.0,
+            total_drop: (total_drop.value() * 100.0).round() / 100.0,
+            cable_drop_percent: percent(cable_drop),
+            transformer_drop_percent: percent(transformer_drop),
+            total_drop_percent: percent(total_drop),
+            limit_percent,
+            limit_basis,
+            within_limit: percent(total_drop) <= limit_percent,
+            formulas,
+        })
+    };
+    compute().map_err(|key| localize_error(key, error_lang(data)))
+}
+
+const TRAY_MIN_GROUPING_FACTOR: f64 = 0.5;     // 단당 허용 최저 집합계수 (9회로)
+
+/// 트레이 1단 배치 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrayAssignment {
+    pub width: f64,             // 트레이 폭 (mm)
+    pub cables: Vec<usize>,     // 배정된 케이블 (입력 목록 인덱스)
+    pub occupied_width: f64,    // 케이블 외경 합계 (mm, 단층 기준)
+    pub fill_rate: f64,         // 폭 점유율 (%, recommend_cable_tray와 같은 단층 기준)
+    pub circuits: u32,          // 회로 수
+    pub grouping_factor: f64,   // 적용 집합계수
+}
+
+/// 트레이 배치 제안
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrayLayoutPlan {
+    pub trays: Vec<TrayAssignment>,     // 트레이 단 목록 (단수 = 길이)
+    pub unplaced: Vec<(usize, String)>, // 배치 불가 케이블 (인덱스, 사유)
+}
+
+/// 트레이 단 최대 회로 수 (묶음 집합계수가 하한 아래로 떨어지지 않는 범위, 배치 형태와 무관하게 보수적 적용)
+fn max_circuits_per_tray() -> u32 {
+    (1..=20)
+        .take_while(|n| bunched_grouping_factor(*n) >= TRAY_MIN_GROUPING_FACTOR)
+        .last()
+        .unwrap_or(1)
+}
+
+/// 트레이 폭별 단층 수용 한계 (케이블 외경 합계, mm)
+fn tray_capacity(width: f64) -> f64 {
+    width * TRAY_WIDTH_FILL_LIMIT
+}
+
+/// 복수 케이블 트레이 배치 최적화 (First-Fit Decreasing 근사)
+/// 트레이 폭 추천(recommend_cable_tray)과 같은 단층 외경 합계 기준.
+/// 외경 합계가 큰 케이블부터 기존 단에 채우고, 들어갈 곳이 없으면 새 단을 추가한 뒤
+/// 각 단을 내용물이 들어가는 가장 좁은 폭으로 줄여 단수·규격을 최소화
+pub fn optimize_tray_layout(cables: &[CableData], tray_widths: &[f64]) -> Result<TrayLayoutPlan, String> {
+    let mut widths: Vec<f64> = tray_widths.iter().copied().filter(|w| *w > 0.0).collect();
+    if widths.is_empty() || widths.len() != tray_widths.len() {
+        return Err(localize_error(ERR_INVALID_TRAY_WIDTH, batch_lang(cables)));
+    }
+    widths.sort_by(f64::total_cmp);
+    widths.dedup();
+    let widest = widths[widths.len() - 1];
+    let max_circuits = max_circuits_per_tray();
+
+    // 케이블별 외경 합계·회로 수
+    let mut unplaced = Vec::new();
+    let mut items = Vec::new();
+    for (index, data) in cables.iter().enumerate() {
+        let item = validate_combination(&data.cores, &data.system)
+            .and_then(|_| cable_outer_diameter(data).ok_or(ERR_UNSUPPORTED_SIZE))
+            .and_then(|od| {
+                let width = od * (data.quantity * data.parallel_runs.max(1)) as f64;
+                let circuits = grouping_circuit_count(data)?.max(1);
+                if width > tray_capacity(widest) || circuits > max_circuits {
+                    Err(ERR_CABLE_EXCEEDS_TRAY)
+                } else {
+                    Ok((index, width, circuits))
+                }
+            });
+        match item {
+            Ok(item) => items.push(item),
+            Err(key) => unplaced.push((index, localize_error(key, error_lang(data)))),
+        }
+    }
+    items.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    // (배정 케이블, 외경 합계, 회로 수) - 배치 중에는 가장 넓은 폭 기준
+    let mut tiers: Vec<(Vec<usize>, f64, u32)> = Vec::new();
+    for (index, width, circuits) in items {
+        let fit = tiers
+            .iter_mut()
+            .find(|(_, used, n)| used + width <= tray_capacity(widest) && n + circuits <= max_circuits);
+        match fit {
+            Some((assigned, used, n)) => {
+                assigned.push(index);
+                *used += width;
+                *n += circuits;
+            }
+            None => tiers.push((vec![index], width, circuits)),
+        }
+    }
+
+    let trays = tiers
+        .into_iter()
+        .map(|(mut assigned, used, circuits)| {
+            assigned.sort_unstable();
+            let width = widths.iter().copied().find(|w| used <= tray_capacity(*w)).unwrap_or(widest);
+            TrayAssignment {
+                width,
+                cables: assigned,
+                occupied_width: (used * 10.0).round() / 10.0,
+                fill_rate: (used / width * 100.0 * 10.0).round() / 10.0,
+                circuits,
+                grouping_factor: bunched_grouping_factor(circuits),
+            }
+        })
+        .collect();
+
+    Ok(TrayLayoutPlan { trays, unplaced })
+}
+
+/// 전선관 분리 배관 그룹 (관 1개)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConduitGroup {
+    pub cables: Vec<(usize, u32)>,             // 배정 케이블 (입력 인덱스, 가닥수)
+    pub ground_wires: u32,                     // 함께 넣은 접지선 가닥수
+    #[serde(alias = "effective_area")]
+    pub total_area: f64,                       // 케이블·접지선 단면적 합 (mm², 외경 원 면적)
+    pub conduit: RacewayRecommendation,        // 사용 관 규격·점유율
+}
+
+/// 분배 단위: (입력 인덱스, 케이블 외경, 가닥수, 접지선 외경)
+type ConduitItem = (usize, f64, u32, Option<f64>);
+
+/// 분배 단위 목록의 단면적 합 (mm², 점유율 상한과 같은 원 면적 기준)
+fn conduit_items_area(items: &[ConduitItem]) -> f64 {
+    items
+        .iter()
+        .flat_map(|&(_, od, count, ground)| std::iter::once((od, count)).chain(ground.map(|g| (g, 1))))
+        .map(|(od, count)| calculate_cable_area(od).value() * count as f64)
+        .sum()
+}
+
+/// 여러 케이블을 점유율 한계 이하가 되도록 여러 후강전선관에 분배 (First-Fit Decreasing 근사)
+/// 면적이 큰 단위부터 최대 전선관 한계 안에서 기존 관에 채우고, 없으면 관을 추가한 뒤
+/// 각 관을 내용물이 들어가는 가장 작은 규격으로 선정해 관 개수를 최소화.
+/// keep_circuits_together면 입력 항목(회로) 단위로, 아니면 케이블 가닥 단위로 분배
+pub fn distribute_cables_to_conduits(cables: &[CableData], fill_limit: f64, keep_circuits_together: bool) -> Result<Vec<ConduitGroup>, String> {
+    if !(fill_limit > 0.0 && fill_limit <= 1.0) {
+        return Err(localize_error(ERR_INVALID_FILL_LIMIT, batch_lang(cables)));
+    }
+    let capacity = largest_conduit(ConduitType::Steel).area * fill_limit;
+
+    let mut items: Vec<ConduitItem> = Vec::new();
+    for (index, data) in cables.iter().enumerate() {
+        let item = || -> Result<(f64, Option<f64>), &'static str> {
+            validate_combination(&data.cores, &data.system)?;
+            let od = cable_outer_diameter(data).ok_or(ERR_UNSUPPORTED_SIZE)?;
+            let ground = protective_conductor_size(data)?.and_then(|size| get_cable_outer_diameter("HFIX", size, "1C"));
+            Ok((od, ground))
+        };
+        let (od, ground) = item().map_err(|key| format!("#{}: {}", index + 1, localize_error(key, error_lang(data))))?;
+        if keep_circuits_together {
+            items.push((index, od, data.quantity, ground));
+        } else {
+            // 접지선은 첫 가닥과 같은 관에 배정
+            items.extend((0..data.quantity).map(|i| (index, od, 1, if i == 0 { ground } else { None })));
+        }
+    }
+    for item in &items {
+        if conduit_items_area(std::slice::from_ref(item)) > capacity {
+            let data = &cables[item.0];
+            return Err(format!("#{}: {}", item.0 + 1, localize_error(ERR_CABLE_EXCEEDS_CONDUIT, error_lang(data))));
+        }
+    }
+    items.sort_by(|a, b| conduit_items_area(std::slice::from_ref(b)).total_cmp(&conduit_items_area(std::slice::from_ref(a))));
+
+    let mut groups: Vec<Vec<ConduitItem>> = Vec::new();
+    for item in items {
+        let fit = groups.iter_mut().find(|group| {
+            let mut candidate = group.to_vec();
+            candidate.push(item);
+            conduit_items_area(&candidate) <= capacity
+        });
+        match fit {
+            Some(group) => group.push(item),
+            None => groups.push(vec![item]),
+        }
+    }
+
+    Ok(groups
+        .into_iter()
+        .map(|group| {
+            let total_area = conduit_items_area(&group);
+            let mut assigned: Vec<(usize, u32)> = Vec::new();
+            for &(index, _, count, _) in &group {
+                match assigned.iter_mut().find(|(i, _)| *i == index) {
+                    Some((_, n)) => *n += count,
+                    None => assigned.push((index, count)),
+                }
+            }
+            assigned.sort_unstable();
+            let diameters: Vec<f64> = group
+                .iter()
+                .flat_map(|&(_, od, count, ground)| std::iter::repeat_n(od, count as usize).chain(ground))
+                .collect();
+            let conduit = recommend_conduit_for_cables(ConduitType::Steel, total_area, FillLimit::Fixed(fill_limit), &diameters);
+            ConduitGroup {
+                cables: assigned,
+                ground_wires: group.iter().filter(|(.., ground)| ground.is_some()).count() as u32,
+                total_area: (total_area * 100.0).round() / 100.0,
+                conduit: RacewayRecommendation {
+                    fill_rate: (conduit.fill_rate * 10.0).round() / 10.0,
+                    ..conduit
+                },
+            }
+        })
+        .collect())
+}
+
+/// 풀박스 인입 관 사이·관과 벽 사이 최소 간격 (mm)
+const PULL_BOX_CONDUIT_SPACING: f64 = 20.0;
+
+/// 케이블 종류·가닥수별 최소 굽힘 반경 배수 (외경 D 대비)
+/// 단심 15D, 다심 12D, 내화(FR-CV)는 내화층 손상 방지를 위해 단심 20D / 다심 15D
+fn bending_radius_factor(cable_type: &str, cores: &str) -> f64 {
+    match (cable_type, cores) {
+        ("FR-CV", "1C") => 20.0,
+        ("FR-CV", _) => 15.0,
+        (_, "1C") => 15.0,
+        _ => 12.0,
+    }
+}
+
+/// 최소 굽힘 반경 (mm) = 외경 × 종류별 배수 (bending_radius_factor)
+pub fn min_bending_radius(cable_type: &str, outer_diameter: f64, cores: &str) -> f64 {
+    outer_diameter * bending_radius_factor(cable_type.trim(), cores.trim())
+}
+
+/// 계산 입력의 최소 굽힘 반경 (mm, 소수 첫째 자리), 외경을 알 수 없으면 0
+fn bending_radius_for(data: &CableData) -> f64 {
+    cable_outer_diameter(data)
+        .map(|od| (min_bending_radius(&data.cable_type, od, &data.cores) * 10.0).round() / 10.0)
+        .unwrap_or(0.0)
+}
+/// 표준 풀박스 치수 (mm), 초과 시 100mm 단위 올림
+const PULL_BOX_STANDARD_SIZES: &[f64] = &[150.0, 200.0, 250.0, 300.0, 400.0, 500.0, 600.0, 800.0, 1000.0, 1200.0];
+
+/// 풀박스·접속함 추천 치수 (mm)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BoxDimensions {
+    pub min_length: f64,       // 계산된 최소 길이 (인입 방향)
+    pub min_width: f64,        // 계산된 최소 폭
+    pub min_depth: f64,        // 계산된 최소 깊이
+    pub length: f64,           // 표준 치수로 올림한 추천 길이
+    pub width: f64,            // 표준 치수로 올림한 추천 폭
+    pub depth: f64,            // 표준 치수로 올림한 추천 깊이
+    pub largest_conduit: u32,  // 최대 인입 관 호칭경 (mm)
+    pub basis: String,         // 적용 규칙 설명
+}
+
+/// 풀박스 치수를 표준 치수로 올림
+fn round_up_box_size(value: f64) -> f64 {
+    PULL_BOX_STANDARD_SIZES
+        .iter()
+        .copied()
+        .find(|size| *size >= value)
+        .unwrap_or_else(|| (value / 100.0).ceil() * 100.0)
+}
+
+/// 인입 케이블(회로별 1관)과 인입 방향으로 최소 풀박스 치수 추천 (NEC 314.28 유사 규칙)
+/// 직선 인입: 길이 ≥ 최대 관경 × 8
+/// 직각 인입: 각 변 ≥ 최대 관경 × 6 + 같은 벽 나머지 관경 합, 케이블 최소 굽힘 반경(min_bending_radius) 이상
+/// 폭은 관경 합 + 관 사이·벽 간격, 깊이는 최대 관경 + 양쪽 간격
+pub fn recommend_pull_box(cables: &[CableData], entry_type: &str) -> Result<BoxDimensions, String> {
+    let angle = match entry_type.trim() {
+        "직선" | "straight" => false,
+        "직각" | "angle" => true,
+        _ => return Err(localize_error(ERR_INVALID_ENTRY_TYPE, batch_lang(cables))),
+    };
+    if cables.is_empty() {
+        return Err(localize_error(ERR_EMPTY_CABLE_LIST, batch_lang(cables)));
+    }
+
+    let mut conduits: Vec<f64> = Vec::new();
+    let mut bending_radius: f64 = 0.0;
+    for (index, data) in cables.iter().enumerate() {
+        let result = calculate_core(data).map_err(|e| format!("#{}: {}", index + 1, e))?;
+        if result.recommended_raceway.status != "정상" {
+            return Err(format!("#{}: {}", index + 1, localize_error(ERR_CABLE_EXCEEDS_CONDUIT, error_lang(data))));
+        }
+        // 트레이 포설 회로는 관 인입이 없으므로 관경 산정에서 제외 (굴곡 반경만 반영)
+        bending_radius = bending_radius.max(result.min_bending_radius_mm);
+        if result.recommended_raceway.kind == RacewayKind::Tray {
+            continue;
+        }
+        conduits.push(result.recommended_raceway.nominal as f64);
+    }
+
+    let largest = conduits.iter().copied().fold(0.0, f64::max);
+    let conduit_sum: f64 = conduits.iter().sum();
+    let row_width = conduit_sum + PULL_BOX_CONDUIT_SPACING * (conduits.len() + 1) as f64;
+    let (min_length, min_width, basis) = if angle {
+        let side = (largest * 6.0 + (conduit_sum - largest)).max(bending_radius);
+        (
+            side,
+            side.max(row_width),
+            format!("직각 인입: 최대 관경 {}mm × 6 + 나머지 관경 합, 굴곡 반경 {:.0}mm 이상", largest, bending_radius),
+        )
+    } else {
+        (largest * 8.0, row_width, format!("직선 인입: 최대 관경 {}mm × 8", largest))
+    };
+    let min_depth = largest + PULL_BOX_CONDUIT_SPACING * 2.0;
+
+    Ok(BoxDimensions {
+        min_length: (min_length * 10.0).round() / 10.0,
+        min_width: (min_width * 10.0).round() / 10.0,
+        min_depth: (min_depth * 10.0).round() / 10.0,
+        length: round_up_box_size(min_length),
+        width: round_up_box_size(min_width),
+        depth: round_up_box_size(min_depth),
+        largest_conduit: largest as u32,
+        basis,
+    })
+}
+
+/// 여러 전선 종류의 허용전류(보정 전 테이블값) 일괄 조회
+/// 규격·공사방법·전압 방식은 고정, 결과는 입력 순서 유지 (종류별 에러 개별 반환)
+/// 부하 도체 수: 3Φ -> 3부하, 그 외 2부하 (lang: ko/en)
+pub fn bulk_current_by_type(types: &[String], size: &str, method: &str, system: &str, lang: &str) -> Vec<(String, Result<f64, String>)> {
+    let known_types: Vec<String> = get_cable_types().into_iter().map(|info| info.code).collect();
+    let loaded_conductors = determine_loaded_conductors("1C", system);
+    let lookup = |cable_type: &str| -> Result<f64, &'static str> {
+        if !known_types.iter().any(|code| code == cable_type) {
+            return Err(ERR_UNKNOWN_CABLE_TYPE);
+        }
+        let size = Size::try_from(size)?;
+        let method = InstallMethod::try_from(method)?;
+        lookup_base_current(size, get_insulation_type(cable_type), method, loaded_conductors).map(Amperes::value)
+    };
+    types
+        .iter()
+        .map(|cable_type| (cable_type.clone(), lookup(cable_type).map_err(|key| localize_error(key, lang))))
+        .collect()
+}
+
+/// 부하율 과부하 기준 (%)
+pub const OVERLOAD_RATIO_PERCENT: f64 = 100.0;
+
+/// 실제 전류에 대한 규격별 부하율 표 (규격, 허용전류 A, 부하율 %)
+/// 허용전류는 보정 전 테이블값, 부하 도체 수는 3Φ -> 3부하, 그 외 2부하.
+/// 부하율이 OVERLOAD_RATIO_PERCENT를 넘는 규격은 과부하.
+/// 규격 오름차순, 데이터가 없는 규격과 잘못된 입력(종류·공사방법·전류)은 빈 결과
+pub fn load_ratio_table(cable_type: &str, method: &str, system: &str, current: f64) -> Vec<(String, f64, f64)> {
+    if current.is_nan() || current <= 0.0 || !get_cable_types().iter().any(|info| info.code == cable_type) {
+        return Vec::new();
+    }
+    let Ok(method) = InstallMethod::try_from(method) else {
+        return Vec::new();
+    };
+    let insulation = get_insulation_type(cable_type);
+    let loaded_conductors = determine_loaded_conductors("1C", system);
+    STANDARD_SIZES
+        .iter()
+        .filter_map(|size| {
+            let allowable = lookup_base_current(Size(size), insulation, method, loaded_conductors).ok()?;
+            let ratio = current / allowable.value() * 100.0;
+            Some((size.to_string(), allowable.value(), (ratio * 10.0).round() / 10.0))
+        })
+        .collect()
+}
+
+/// 역산 모드: 보정 후 허용전류가 부하전류 이상인 최소 규격의 입력 반환
+/// 수량은 1회로분 (1C는 부하 도체 수만큼, 다심은 1가닥), 주변온도·집합 보정 포함.
+/// 최대 규격으로도 부족하면 ERR_PARALLEL_CONDUCTORS_REQUIRED, 모든 규격 계산이 실패하면 첫 에러 (lang: ko/en)
+pub fn recommend_cable_size(
+    load_current: f64,
+    cable_type: &str,
+    cores: &str,
+    system: &str,
+    install_method: &str,
+    ambient_temp: i32,
+    lang: &str,
+) -> Result<CableData, String> {
+    if load_current.is_nan() || load_current <= 0.0 {
+        return Err(localize_error(ERR_INVALID_LOAD_CURRENT, lang));
+    }
+    let quantity = if cores == "1C" { determine_loaded_conductors(cores, system) as u32 } else { 1 };
+    let base = CableData {
+        cable_type: cable_type.to_string(),
+        cores: cores.to_string(),
+        size: String::new(),
+        quantity,
+        system: system.to_string(),
+        ground_wire: "없음".to_string(),
+        install_method: install_method.to_string(),
+        load_current: Some(load_current),
+        ambient_temp: Some(ambient_temp),
+        lang: Some(lang.to_string()),
+        ..Default::default()
+    };
+
+    let mut first_error = None;
+    let mut calculated = false;
+    for size in STANDARD_SIZES {
+        let candidate = CableData { size: size.to_string(), ..base.clone() };
+        match calculate_core(&candidate) {
+            Ok(result) if result.allowable_current >= load_current => return Ok(candidate),
+            Ok(_) => calculated = true,
+            Err(e) => {
+                first_error.get_or_insert(e);
+            }
+        }
+    }
+    match first_error {
+        Some(e) if !calculated => Err(e),
+        _ => Err(localize_error(ERR_PARALLEL_CONDUCTORS_REQUIRED, lang)),
+    }
+}
+
+/// 부하 용량 역산 시 역률 미지정 기본값 (전동기 부하 기준)
+const DEFAULT_LOAD_POWER_FACTOR: f64 = 0.8;
+
+/// 부하 유효전력(kW)에서 선전류 (A)
+/// 3Φ: I = P / (√3 · V · cosφ), 1Φ: I = P / (V · cosφ), DC: I = P / V (역률 무시), 3상 전압은 선간전압.
+/// 입력 검증은 호출 측에서 수행 (recommend_cable_size_from_power 참조)
+pub fn power_to_current(power_kw: f64, voltage: f64, power_factor: f64, system: &str) -> f64 {
+    match system {
+        "3Φ" => power_kw * 1000.0 / (3f64.sqrt() * voltage * power_factor),
+        "DC" => power_kw * 1000.0 / voltage,
+        _ => power_kw * 1000.0 / (voltage * power_factor),
+    }
+}
+
+/// 부하 용량 기준 역산 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PowerSizeRecommendation {
+    pub load_current: f64,  // 계산 근거 전류 (A, 소수 첫째 자리)
+    pub basis: String,      // 근거 표시 (예: "15kW / (√3 × 380V × 0.8) = 28.5A")
+    pub data: CableData,    // 추천 규격 입력 (recommend_cable_size 결과)
+}
+
+/// 역산 모드 (부하 용량 입력): 부하 kW·역률에서 전류를 구해 recommend_cable_size로 연결
+/// 역률 미지정 시 0.8, 부하·전압이 0 이하이거나 역률이 0 < cosφ ≤ 1 밖이면 ERR_INVALID_LOAD_POWER (lang: ko/en)
+#[allow(clippy::too_many_arguments)]
+pub fn recommend_cable_size_from_power(
+    power_kw: f64,
+    voltage: f64,
+    power_factor: Option<f64>,
+    cable_type: &str,
+    cores: &str,
+    system: &str,
+    install_method: &str,
+    ambient_temp: i32,
+    lang: &str,
+) -> Result<PowerSizeRecommendation, String> {
+    let power_factor = power_factor.unwrap_or(DEFAULT_LOAD_POWER_FACTOR);
+    if !(power_kw > 0.0 && voltage > 0.0 && power_factor > 0.0 && power_factor <= 1.0) {
+        return Err(localize_error(ERR_INVALID_LOAD_POWER, lang));
+    }
+    let current = (power_to_current(power_kw, voltage, power_factor, system) * 10.0).round() / 10.0;
+    let denominator = match system {
+        "3Φ" => format!("√3 × {}V × {}", voltage, power_factor),
+        "DC" => format!("{}V", voltage),
+        _ => format!("{}V × {}", voltage, power_factor),
+    };
+    let data = recommend_cable_size(current, cable_type, cores, system, install_method, ambient_temp, lang)?;
+    Ok(PowerSizeRecommendation {
+        load_current: current,
+        basis: format!("{}kW / ({}) = {}A", power_kw, denominator, current),
+        data,
+    })
+}
+
+/// 해 탐색 제약 (모두 선택, 미지정 시 전체 탐색)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SolutionConstraints {
+    #[serde(default)]
+    pub max_size: Option<String>,  // 최대 규격 (mm²)
+    #[serde(default)]
+    pub methods: Vec<String>,      // 허용 공사방법, 비어 있으면 전체
+    #[serde(default)]
+    pub cores: Option<String>,     // 가닥수, 미지정 시 1Φ 2C / 3Φ 3C
+}
+
+/// 규격·공사방법 조합 해
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Solution {
+    pub size: String,                 // 규격 (mm²)
+    pub install_method: String,       // 공사방법
+    pub allowable_current: f64,       // 보정 후 허용전류 (A)
+    pub margin_percent: f64,          // 목표 전류 대비 여유율 (%), 음수면 부족
+    #[serde(alias = "recommended_conduit")]
+    pub recommended_raceway: String,  // 추천 전선관·트레이 표시 문자열
+    pub satisfies: bool,              // 목표 전류 만족 여부 (false면 근접 제안)
+}
+
+/// 목표 전류를 만족하는 모든 규격·공사방법 조합 (수량 1조 기준, 여유율 오름차순)
+/// 만족하는 조합이 없으면 허용전류가 가장 큰(가장 근접한) 조합 하나를 satisfies = false로 반환.
+/// 종류·전압 방식·목표 전류가 유효하지 않으면 빈 목록
+pub fn explore_solutions(cable_type: &str, system: &str, target_current: f64, constraints: &SolutionConstraints) -> Vec<Solution> {
+    if target_current.is_nan() || target_current <= 0.0 || !get_cable_types().iter().any(|info| info.code == cable_type) {
+        return Vec::new();
+    }
+    let cores = constraints
+        .cores
+        .clone()
+        .unwrap_or_else(|| if system == "1Φ" { "2C" } else { "3C" }.to_string());
+    let max_size = constraints.max_size.as_deref().and_then(|size| Size::try_from(size).ok());
+    let methods: Vec<InstallMethod> = if constraints.methods.is_empty() {
+        InstallMethod::ALL.to_vec()
+    } else {
+        constraints.methods.iter().filter_map(|m| InstallMethod::try_from(m.trim()).ok()).collect()
+    };
+
+    let mut candidates: Vec<Solution> = STANDARD_SIZES
+        .iter()
+        .filter(|size| max_size.is_none_or(|max| Size(size).mm2() <= max.mm2()))
+        .flat_map(|size| methods.iter().map(move |method| (*size, *method)))
+        .filter_map(|(size, method)| {
+            let data = CableData {
+                cable_type: cable_type.to_string(),
+                cores: cores.clone(),
+                size: size.to_string(),
+                quantity: 1,
+                system: system.to_string(),
+                ground_wire: "없음".to_string(),
+                install_method: method.as_str().to_string(),
+                ..Default::default()
+            };
+            let result = calculate_core(&data).ok()?;
+            let margin = (result.allowable_current - target_current) / target_current * 100.0;
+            Some(Solution {
+                size: size.to_string(),
+                install_method: method.as_str().to_string(),
+                allowable_current: result.allowable_current,
+                margin_percent: (margin * 10.0).round() / 10.0,
+                recommended_raceway: result.recommended_raceway.name,
+                satisfies: result.allowable_current >= target_current,
+            })
+        })
+        .collect();
+
+    if !candidates.iter().any(|c| c.satisfies) {
+        return candidates
+            .into_iter()
+            .max_by(|a, b| a.allowable_current.total_cmp(&b.allowable_current))
+            .into_iter()
+            .collect();
+    }
+    candidates.retain(|c| c.satisfies);
+    candidates.sort_by(|a, b| a.margin_percent.total_cmp(&b.margin_percent));
+    candidates
+}
+
+/// 보정 전 테이블값이 필요 전류 이상인 최소 표준 규격
+/// 절연·공사방법 조합의 허용전류 표 최대 규격 (630mm² 이상은 단심 트레이만 수록)
+fn largest_tabulated_size(insulation: Insulation, method: InstallMethod) -> Size {
+    let table = active_current_table();
+    STANDARD_SIZES
+        .iter()
+        .rev()
+        .map(|s| Size(s))
+        .find(|size| table.contains_key(&(*size, insulation, method)))
+        .unwrap_or(Size(STANDARD_SIZES[STANDARD_SIZES.len() - 1]))
+}
+
+fn smallest_size_for_current(insulation: Insulation, method: InstallMethod, loaded_conductors: u8, current: Amperes) -> Option<Size> {
+    STANDARD_SIZES
+        .iter()
+        .map(|s| Size(s))
+        .find(|size| lookup_base_current(*size, insulation, method, loaded_conductors).is_ok_and(|c| c >= current))
+}
+
+/// 부하 유효전력과 전압 방식에서 역률별 필요 전류·추천 규격
+/// 3Φ: I = P / (√3 · V · cosφ), 1Φ: I = P / (V · cosφ)
+/// 반환: (역률, 필요 전류 A, 추천 규격), 0 < 역률 ≤ 1 범위 밖 값은 건너뜀 (lang: ko/en)
+pub fn size_vs_power_factor(p_kw: f64, pf_values: &[f64], system: &str, voltage: f64, cable_type: &str, method: &str, lang: &str) -> Result<Vec<(f64, f64, String)>, String> {
+    let compute = || -> Result<Vec<(f64, f64, String)>, &'static str> {
+        if !(p_kw > 0.0 && voltage > 0.0) {
+            return Err(ERR_INVALID_LOAD_POWER);
+        }
+        if !get_cable_types().iter().any(|info| info.code == cable_type) {
+            return Err(ERR_UNKNOWN_CABLE_TYPE);
+        }
+        let method = InstallMethod::try_from(method)?;
+        let insulation = get_insulation_type(cable_type);
+        let three_phase = system == "3Φ";
+        let loaded_conductors = if three_phase { 3 } else { 2 };
+
+        Ok(pf_values
+            .iter()
+            .copied()
+            .filter(|pf| *pf > 0.0 && *pf <= 1.0)
+            .map(|pf| {
+                let current = power_to_current(p_kw, voltage, pf, system);
+                let size = match smallest_size_for_current(insulation, method, loaded_conductors, Amperes(current)) {
+                    Some(size) => size.as_str().to_string(),
+                    None => match label_lang(lang) {
+                        "en" => format!("over {} (consider parallel runs)", largest_tabulated_size(insulation, method).as_str()),
+                        _ => format!("{} 초과 (병렬 검토)", largest_tabulated_size(insulation, method).as_str()),
+                    },
+                };
+                (pf, (current * 10.0).round() / 10.0, size)
+            })
+            .collect())
+    };
+    compute().map_err(|key| localize_error(key, lang))
+}
+
+/// 단락 보호 k 계수 (IEC 60364-5-54 Table A.54.2 / 5-43 Table 43A, 초기 온도 = 도체 최고허용온도)
+fn short_circuit_k(insulation: Insulation, material: Material) -> f64 {
+    match (material, insulation) {
+        (Material::Copper, Insulation::Pvc) => 115.0,
+        (Material::Copper, Insulation::Xlpe) => 143.0,
+        (Material::Aluminium, Insulation::Pvc) => 76.0,
+        (Material::Aluminium, Insulation::Xlpe) => 94.0,
+    }
+}
+
+/// 단락 지속시간 허용 범위 (단열 가정이 성립하는 5초 이하)
+const MAX_SHORT_CIRCUIT_DURATION: f64 = 5.0;
+
+/// 규격별 최대 허용 단락 전류 (kA)
+/// I = k·S/√t, duration 미지정 시 1초 기준 (lang: ko/en)
+pub fn max_short_circuit_current(size: &str, insulation: &str, material: &str, duration_s: Option<f64>, lang: &str) -> Result<f64, String> {
+    let compute = || -> Result<f64, &'static str> {
+        let size = Size::try_from(size.trim())?;
+        let insulation = Insulation::try_from(insulation.trim())?;
+        let material = Material::try_from(material.trim())?;
+        let duration = duration_s.unwrap_or(1.0);
+        if !(duration > 0.0 && duration <= MAX_SHORT_CIRCUIT_DURATION) {
+            return Err(ERR_INVALID_DURATION);
+        }
+        let current_ka = short_circuit_k(insulation, material) * size.mm2() / duration.sqrt() / 1000.0;
+        Ok((current_ka * 100.0).round() / 100.0)
+    };
+    compute().map_err(|key| localize_error(key, lang))
+}
+
+/// 단락 열적 허용 최소 단면적 (mm²): I²t ≤ k²S² -> S ≥ I·√t / k
+/// fault_current_ka·clearing_time 범위 검증 포함
+fn short_circuit_min_area(k: f64, fault_current_ka: f64, clearing_time: f64) -> Result<f64, &'static str> {
+    if fault_current_ka.is_nan() || fault_current_ka <= 0.0 {
+        return Err(ERR_INVALID_FAULT_CURRENT);
+    }
+    if !(clearing_time > 0.0 && clearing_time <= MAX_SHORT_CIRCUIT_DURATION) {
+        return Err(ERR_INVALID_DURATION);
+    }
+    Ok(fault_current_ka * 1000.0 * clearing_time.sqrt() / k)
+}
+
+/// 단락전류(kA)가 보호장치 동작시간(초) 동안 흘러도 도체가 견디는지 검증 (I²t ≤ k²S²)
+/// k: 동 XLPE 143 / 동 PVC 115 / 알루미늄 XLPE 94 / 알루미늄 PVC 76 (lang: ko/en)
+pub fn check_short_circuit_withstand(
+    size: &str,
+    material: &str,
+    insulation: &str,
+    fault_current: f64,
+    clearing_time: f64,
+    lang: &str,
+) -> Result<bool, String> {
+    let compute = || -> Result<bool, &'static str> {
+        let size = Size::try_from(size.trim())?;
+        let k = short_circuit_k(Insulation::try_from(insulation.trim())?, Material::try_from(material.trim())?);
+        Ok(size.mm2() >= short_circuit_min_area(k, fault_current, clearing_time)?)
+    };
+    compute().map_err(|key| localize_error(key, lang))
+}
+
+/// 입력의 단락 열적 허용 검증: (만족 여부, 최소 단면적 mm², 계산 수식), 단락전류 미지정 시 None
+fn compute_short_circuit(data: &CableData) -> Result<Option<(bool, f64, String)>, &'static str> {
+    let Some(fault_current) = data.fault_current else {
+        return Ok(None);
+    };
+    let clearing_time = data.clearing_time.unwrap_or(1.0);
+    let k = short_circuit_k(get_insulation_type(&data.cable_type), resolve_material(data)?);
+    let min_area = short_circuit_min_area(k, fault_current, clearing_time)?;
+    let size = Size::try_from(data.size.as_str())?;
+    let formula = format!(
+        "{} = {:.0}A × √{:.2}s ÷ {:.0} = {:.2}mm²",
+        desc_label(FORMULA_SHORT_CIRCUIT, error_lang(data)),
+        fault_current * 1000.0,
+        clearing_time,
+        k,
+        min_area
+    );
+    Ok(Some((size.mm2() >= min_area, (min_area * 100.0).round() / 100.0, formula)))
+}
+
+/// 표준 차단기 정격전류 (A, KS C IEC 60898 / 60947-2 통용 정격)
+const BREAKER_RATINGS: &[u32] = &[6, 10, 13, 16, 20, 25, 32, 40, 50, 63, 80, 100, 125, 160, 200, 250, 320, 400, 500, 630, 800];
+/// 과부하 보호 협조 조건 I2 ≤ 1.45 · IZ의 배수
+const OVERLOAD_PROTECTION_FACTOR: f64 = 1.45;
+
+/// 과부하 보호장치 종류 (KEC 212.3.4 보호장치 동작 특성)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BreakerType {
+    #[default]
+    Household,   // 주택용 배선차단기 (IEC 60898-1)
+    Industrial,  // 산업용 배선차단기 (IEC 60947-2)
+    FuseGg,      // 범용 퓨즈 gG (IEC 60269-2)
+}
+
+impl BreakerType {
+    /// 규약 동작전류 배수 I2 / IN (KEC 212.3.4: 주택용 1.45, 산업용 1.3, gG 퓨즈 4A 이하 2.1 / 16A 미만 1.9 / 16A 이상 1.6)
+    pub fn conventional_trip_factor(self, rating: u32) -> f64 {
+        match self {
+            BreakerType::Household => 1.45,
+            BreakerType::Industrial => 1.3,
+            BreakerType::FuseGg => match rating {
+                0..=4 => 2.1,
+                5..=15 => 1.9,
+                _ => 1.6,
+            },
+        }
+    }
+}
+
+/// 과부하 보호 협조(KEC 212.4.1)를 만족하는 최소 차단기 정격 (A)
+/// IB ≤ IN ≤ IZ 및 I2 ≤ 1.45 · IZ (I2 = 보호장치 종류별 규약 동작전류 배수 × IN), IB: 부하전류, IZ: 보정 후 허용전류.
+/// 만족하는 표준 정격이 없으면(전선이 부하 대비 과소) ERR_CABLE_UNDERSIZED_FOR_BREAKER
+pub fn recommend_breaker(load_current: f64, allowable_current: f64, breaker_type: BreakerType, lang: &str) -> Result<u32, String> {
+    if load_current.is_nan() || load_current <= 0.0 {
+        return Err(localize_error(ERR_INVALID_LOAD_CURRENT, lang));
+    }
+    BREAKER_RATINGS
+        .iter()
+        .copied()
+        .find(|&rating| {
+            let conventional_trip = breaker_type.conventional_trip_factor(rating) * rating as f64;
+            load_current <= rating as f64
+                && rating as f64 <= allowable_current
+                && conventional_trip <= OVERLOAD_PROTECTION_FACTOR * allowable_current
+        })
+        .ok_or_else(|| localize_error(ERR_CABLE_UNDERSIZED_FOR_BREAKER, lang))
+}
+
+/// 통합 회로 설계 입력
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CircuitDesignInput {
+    pub cable_type: String,                 // 전선 종류
+    #[serde(default)]
+    pub cores: Option<String>,              // 가닥수, 미지정 시 1Φ 2C / 3Φ 3C
+    pub system: String,                     // 전압 방식 (1Φ, 3Φ)
+    pub voltage: f64,                       // 공칭전압 (V, 3상은 선간전압)
+    #[serde(default)]
+    pub load_current: Option<f64>,          // 부하전류 (A), 지정 시 부하전력보다 우선
+    #[serde(default)]
+    pub load_kw: Option<f64>,               // 부하 유효전력 (kW)
+    #[serde(default)]
+    pub power_factor: Option<f64>,          // 역률, 미지정 시 0.9
+    pub length: f64,                        // 선로 길이 (m)
+    pub install_method: String,             // 공사방법
+    #[serde(default)]
+    pub installation_type: Option<String>,  // 설비 유형 (VOLTAGE_DROP_LIMITS), 미지정 시 "저압수전 기타"
+    #[serde(default)]
+    pub is_branch: bool,                    // 분기(최종) 회로 여부
+    #[serde(default)]
+    pub fault_current_ka: Option<f64>,      // 예상 단락전류 (kA), 지정 시 단락용량 검토
+    #[serde(default)]
+    pub fault_duration_s: Option<f64>,      // 단락 지속시간 (초), 미지정 시 1초
+    #[serde(default)]
+    pub breaker_type: BreakerType,          // 과부하 보호장치 종류, 미지정 시 주택용 배선차단기
+    #[serde(default)]
+    pub conductor_material: Option<String>, // 도체 재질 (Cu, Al), 미지정 시 동
+    #[serde(default)]
+    pub lang: Option<String>,               // 에러 메시지·제약 이름 언어 (ko, en), 미지정 시 ko
+}
+
+/// 제약별 검토 결과
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DesignConstraintCheck {
+    pub constraint: String,        // 허용전류, 보호협조, 전압강하, 단락용량 (입력 언어로 표기)
+    pub min_size: Option<String>,  // 이 제약만 고려한 최소 규격 (만족 규격 없으면 None)
+}
+
+/// 통합 회로 설계 결과
+/// 모든 제약을 만족하는 규격이 없으면 size 이하 선정값은 None이고 suggestions에 해결책 제시
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CircuitDesignResult {
+    pub load_current: f64,                       // 설계 부하전류 (A)
+    pub breaker_rating: Option<u32>,             // 선정 차단기 정격 (A, recommend_breaker), 만족 규격이 없으면 None
+    pub size: Option<String>,                    // 선정 규격 (mm²)
+    pub allowable_current: Option<f64>,          // 선정 규격 허용전류 (A)
+    pub voltage_drop_percent: Option<f64>,       // 선정 규격 전압강하 (%)
+    pub voltage_drop_limit: f64,                 // 전압강하 허용치 (%)
+    pub short_circuit_capacity_ka: Option<f64>,  // 선정 규격 허용 단락전류 (kA), 단락전류 미지정 시 None
+    #[serde(alias = "recommended_conduit")]
+    pub recommended_raceway: Option<String>,     // 추천 전선관·트레이
+    pub binding_constraint: Option<String>,      // 규격을 결정한 제약
+    pub checks: Vec<DesignConstraintCheck>,      // 제약별 최소 규격 (검토 순서)
+    pub suggestions: Vec<String>,                // 해가 없을 때 해결책
+}
+
+/// 부하 조건으로 규격·차단기·전선관 통합 선정
+/// 제약을 순서대로 검토: 허용전류(Iz ≥ IB) → 보호협조(recommend_breaker) → 전압강하(≤ 허용치) → 단락용량(지정 시).
+/// 각 제약은 규격이 커질수록 완화되므로 최종 규격은 제약별 최소 규격 중 가장 큰 값이며,
+/// 그 최소 규격을 만든 제약(동률이면 먼저 검토한 제약)을 binding으로 표시
+pub fn design_circuit(input: &CircuitDesignInput) -> Result<CircuitDesignResult, String> {
+    let lang = input.lang.as_deref().unwrap_or("ko");
+    let compute = || -> Result<CircuitDesignResult, &'static str> {
+        if !get_cable_types().iter().any(|info| info.code == input.cable_type) {
+            return Err(ERR_UNKNOWN_CABLE_TYPE);
+        }
+        let method = InstallMethod::try_from(input.install_method.trim())?;
+        let power_factor = input.power_factor.unwrap_or(DEFAULT_POWER_FACTOR);
+        if !(input.voltage > 0.0 && power_factor > 0.0 && power_factor <= 1.0) {
+            return Err(ERR_INVALID_LOAD_POWER);
+        }
+        let three_phase = input.system == "3Φ";
+        let load_current = match (input.load_current, input.load_kw) {
+            (Some(current), _) if current > 0.0 => current,
+            (None, Some(kw)) if kw > 0.0 => power_to_current(kw, input.voltage, power_factor, &input.system),
+            _ => return Err(ERR_INVALID_LOAD_POWER),
+        };
+        if input.length.is_nan() || input.length <= 0.0 {
+            return Err(ERR_INVALID_VOLTAGE_DROP_INPUT);
+        }
+        let duration = input.fault_duration_s.unwrap_or(1.0);
+        if !(duration > 0.0 && duration <= MAX_SHORT_CIRCUIT_DURATION) {
+            return Err(ERR_INVALID_DURATION);
+        }
+        let installation_type = input.installation_type.clone().unwrap_or_else(|| DEFAULT_INSTALLATION_TYPE.to_string());
+        let (voltage_drop_limit, _) = voltage_drop_limit(&installation_type, input.is_branch, Meters(input.length))?;
+        let material = material_or_copper(input.conductor_material.as_deref())?;
+
+        let breaker_for = |allowable_current: f64| recommend_breaker(load_current, allowable_current, input.breaker_type, lang).ok();
+        let insulation = get_insulation_type(&input.cable_type);
+        let cores = input
+            .cores
+            .clone()
+            .unwrap_or_else(|| if three_phase { "3C" } else { "2C" }.to_string());
+        let drop_input = VoltageDropInput {
+            length: input.length,
+            current: Some(load_current),
+            voltage: input.voltage,
+            power_factor: Some(power_factor),
+            transformer: None,
+            installation_type: Some(installation_type),
+            is_branch: input.is_branch,
+        };
+
+        // 규격별 (규격, 결과, 전압강하 %, 허용 단락전류 kA), 데이터 없는 규격 제외
+        let evaluated: Vec<(&str, CalculationResult, f64, f64)> = STANDARD_SIZES
+            .iter()
+            .filter_map(|size| {
+                let data = CableData {
+                    cable_type: input.cable_type.clone(),
+                    cores: cores.clone(),
+                    size: size.to_string(),
+                    quantity: 1,
+                    system: input.system.clone(),
+                    ground_wire: "없음".to_string(),
+                    install_method: method.as_str().to_string(),
+                    load_current: Some(load_current),
+                    conductor_material: input.conductor_material.clone(),
+                    ..Default::default()
+                };
+                let result = calculate_core(&data).ok()?;
+                let drop = calculate_voltage_drop(&data, &drop_input).ok()?;
+                let capacity = short_circuit_k(insulation, material) * Size(size).mm2() / duration.sqrt() / 1000.0;
+                Some((*size, result, drop.total_drop_percent, (capacity * 100.0).round() / 100.0))
+            })
+            .collect();
+        if evaluated.is_empty() {
+            return Err(ERR_CURRENT_DATA_NOT_FOUND);
+        }
+
+        let t = |ko| desc_label(ko, lang);
+        type Check<'a> = (&'static str, Box<dyn Fn(&(&str, CalculationResult, f64, f64)) -> bool + 'a>);
+        let mut constraints: Vec<Check> = vec![
+            (t("허용전류"), Box::new(|(_, result, ..)| result.allowable_current >= load_current)),
+            (
+                t("보호협조"),
+                Box::new(|(_, result, ..)| breaker_for(result.allowable_current).is_some()),
+            ),
+            (t("전압강하"), Box::new(|(_, _, drop, _)| *drop <= voltage_drop_limit)),
+        ];
+        if let Some(fault_ka) = input.fault_current_ka {
+            constraints.push((t("단락용량"), Box::new(move |(.., capacity)| *capacity >= fault_ka)));
+        }
+        let min_index: Vec<Option<usize>> = constraints
+            .iter()
+            .map(|(_, check)| evaluated.iter().position(check))
+            .collect();
+        let checks = constraints
+            .iter()
+            .zip(&min_index)
+            .map(|((name, _), index)| DesignConstraintCheck {
+                constraint: name.to_string(),
+                min_size: index.map(|i| evaluated[i].0.to_string()),
+            })
+            .collect();
+
+        let selected = evaluated
+            .iter()
+            .enumerate()
+            .find(|(_, entry)| constraints.iter().all(|(_, check)| check(entry)));
+        let Some((index, (size, result, drop, capacity))) = selected else {
+            let largest = &evaluated[evaluated.len() - 1];
+            let english = label_lang(lang) == "en";
+            let mut suggestions = Vec::new();
+            let max_breaker = BREAKER_RATINGS[BREAKER_RATINGS.len() - 1];
+            if load_current > max_breaker as f64 {
+                suggestions.push(if english {
+                    format!("Load current {:.1}A exceeds the largest standard breaker rating {}A: consider splitting the circuit", load_current, max_breaker)
+                } else {
+                    format!("부하전류 {:.1}A가 표준 차단기 최대 정격 {}A 초과: 회로 분할 검토", load_current, max_breaker)
+                });
+            }
+            if min_index[0].is_none() || min_index[1].is_none() {
+                let parallel = (load_current / largest.1.allowable_current).ceil().max(2.0);
+                let per_run = load_current / parallel;
+                suggestions.push(if english {
+                    format!("Consider parallel runs: {} × {} ({:.1}A per run)", largest.0, parallel, per_run)
+                } else {
+                    format!("병렬 포설 검토: {} {}조 (조당 {:.1}A)", largest.0, parallel, per_run)
+                });
+            }
+            if min_index[2].is_none() && largest.2 > 0.0 {
+                let max_length = (input.length * voltage_drop_limit / largest.2).floor();
+                suggestions.push(if english {
+                    format!("Consider a shorter run: about {:.0}m or less with {} (or parallel runs to reduce voltage drop)", max_length, largest.0)
+                } else {
+                    format!("거리 단축 검토: {} 기준 약 {:.0}m 이하 (또는 병렬 포설로 전압강하 감소)", largest.0, max_length)
+                });
+            }
+            if min_index.get(3).is_some_and(Option::is_none) {
+                suggestions.push(if english {
+                    "Insufficient short-circuit capacity: shorten the clearing time or consider a current-limiting breaker".to_string()
+                } else {
+                    "단락용량 부족: 차단 시간 단축 또는 한류형 차단기 검토".to_string()
+                });
+            }
+            return Ok(CircuitDesignResult {
+                load_current: (load_current * 10.0).round() / 10.0,
+                breaker_rating: None,
+                size: None,
+                allowable_current: None,
+                voltage_drop_percent: None,
+                voltage_drop_limit,
+                short_circuit_capacity_ka: None,
+                recommended_raceway: None,
+                binding_constraint: None,
+                checks,
+                suggestions,
+            });
+        };
+
+        let binding = constraints
+            .iter()
+            .zip(&min_index)
+            .find(|(_, min)| **min == Some(index))
+            .map(|((name, _), _)| name.to_string());
+        Ok(CircuitDesignResult {
+            load_current: (load_current * 10.0).round() / 10.0,
+            breaker_rating: breaker_for(result.allowable_current),
+            size: Some(size.to_string()),
+            allowable_current: Some(result.allowable_current),
+            voltage_drop_percent: Some(*drop),
+            voltage_drop_limit,
+            short_circuit_capacity_ka: input.fault_current_ka.map(|_| *capacity),
+            recommended_raceway: Some(result.recommended_raceway.name.clone()),
+            binding_constraint: binding,
+            checks,
+            suggestions: Vec::new(),
+        })
+    };
+    compute().map_err(|key| localize_error(key, lang))
+}
+
+/// 분기회로 수별 수용률(부등률) - IEC 61439-1 정격 부등률 기준
+fn feeder_demand_factor(branch_count: usize) -> f64 {
+    match branch_count {
+        0 | 1 => 1.0,
+        2 | 3 => 0.9,
+        4 | 5 => 0.8,
+        6..=9 => 0.7,
+        _ => 0.6,
+    }
+}
+
+/// 간선 용량 검토 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeederCapacityResult {
+    pub feeder_allowable_current: f64,      // 간선 허용전류 (A, 보정 후)
+    pub connected_load: f64,                // 분기 부하전류 합 (A)
+    pub demand_factor: f64,                 // 적용 수용률
+    pub design_current: f64,                // 설계전류 = 부하 합 × 수용률 (A)
+    pub utilization: f64,                   // 간선 이용률 (%)
+    pub adequate: bool,                     // 간선이 감당 가능한지
+    pub spare_current: f64,                 // 추가 연결 가능 부하 (A, 수용률 적용 전 환산)
+    pub additional_circuits: u32,           // 평균 분기 부하 기준 추가 연결 가능 회로 수 (대략)
+    pub recommended_size: Option<String>,   // 과부하 시 상향 규격 (표준 규격 내 없으면 None)
+}
+
+/// 간선 허용전류 대비 분기회로 부하 검토
+/// 설계전류 = Σ분기 부하 × 수용률, 여유가 있으면 추가 연결 가능 부하·회로 수를,
+/// 과부하면 설계전류를 만족하는 최소 간선 규격을 제안
+pub fn check_feeder_capacity(feeder: &CableData, branches: &[f64]) -> Result<FeederCapacityResult, String> {
+    if branches.iter().any(|b| !b.is_finite() || *b < 0.0) {
+        return Err(localize_error(ERR_INVALID_BRANCH_LOAD, error_lang(feeder)));
+    }
+    let allowable = calculate_core(feeder)?.allowable_current;
+    let connected_load: f64 = branches.iter().sum();
+    let demand_factor = feeder_demand_factor(branches.len());
+    let design_current = connected_load * demand_factor;
+    let adequate = design_current <= allowable;
+
+    let (spare_current, additional_circuits, recommended_size) = if adequate {
+        // 추가 부하도 같은 수용률로 합산된다고 보고 수용률 적용 전 값으로 환산
+        let spare = (allowable - design_current) / demand_factor;
+        let average = if branches.is_empty() { 0.0 } else { connected_load / branches.len() as f64 };
+        let circuits = if average > 0.0 { (spare / average).floor() as u32 } else { 0 };
+        (spare, circuits, None)
+    } else {
+        let upgrade = STANDARD_SIZES
+            .iter()
+            .filter(|size| Size::try_from(feeder.size.as_str()).map_or(true, |current| Size(size).mm2() > current.mm2()))
+            .find(|size| {
+                let candidate = CableData { size: size.to_string(), ..feeder.clone() };
+                calculate_core(&candidate).is_ok_and(|r| r.allowable_current >= design_current)
+            })
+            .map(|size| size.to_string());
+        (0.0, 0, upgrade)
+    };
+
+    Ok(FeederCapacityResult {
+        feeder_allowable_current: allowable,
+        connected_load: (connected_load * 10.0).round() / 10.0,
+        demand_factor,
+        design_current: (design_current * 10.0).round() / 10.0,
+        utilization: if allowable > 0.0 { (design_current / allowable * 100.0 * 10.0).round() / 10.0 } else { 0.0 },
+        adequate,
+        spare_current: (spare_current * 10.0).round() / 10.0,
+        additional_circuits,
+        recommended_size,
+    })
+}
+
+/// 동일 규격·공사방법에서 XLPE 허용전류가 PVC 대비 몇 % 높은지 ((xlpe/pvc - 1) × 100) (lang: ko/en)
+pub fn insulation_current_ratio(size: &str, method: &str, loaded: u8, lang: &str) -> Result<f64, String> {
+    let ratio = || -> Result<f64, &'static str> {
+        if loaded != 2 && loaded != 3 {
+            return Err(ERR_INVALID_LOADED_CONDUCTORS);
+        }
+        let size = Size::try_from(size)?;
+        let method = InstallMethod::try_from(method)?;
+        let pvc = lookup_base_current(size, Insulation::Pvc, method, loaded)?;
+        let xlpe = lookup_base_current(size, Insulation::Xlpe, method, loaded)?;
+        Ok(((xlpe / pvc - 1.0) * 100.0 * 10.0).round() / 10.0)
+    };
+    ratio().map_err(|key| localize_error(key, lang))
+}
+
+/// 공사방법의 전체 표준 규격에 대한 XLPE/PVC 허용전류 비율(%) 평균 (lang: ko/en)
+pub fn average_insulation_current_ratio(method: &str, loaded: u8, lang: &str) -> Result<f64, String> {
+    let ratios: Vec<f64> = STANDARD_SIZES
+        .iter()
+        .filter_map(|size| insulation_current_ratio(size, method, loaded, lang).ok())
+        .collect();
+    if ratios.is_empty() {
+        // 공사방법/부하 도체 수 오류를 그대로 전달
+        return insulation_current_ratio(STANDARD_SIZES[0], method, loaded, lang);
+    }
+    let average = ratios.iter().sum::<f64>() / ratios.len() as f64;
+    Ok((average * 10.0).round() / 10.0)
+}
+
+/// 동일 규격·절연에서 공사방법별 허용전류를 기준 방법 대비 %로 비교 (기준 방법 = 100)
+/// 결과는 InstallMethod::ALL 순서, 데이터가 없는 방법은 제외 (lang: ko/en)
+pub fn method_current_ratios(size: &str, insulation: &str, loaded: u8, base_method: &str, lang: &str) -> Result<Vec<(String, f64)>, String> {
+    let ratios = || -> Result<Vec<(String, f64)>, &'static str> {
+        if loaded != 2 && loaded != 3 {
+            return Err(ERR_INVALID_LOADED_CONDUCTORS);
+        }
+        let size = Size::try_from(size)?;
+        let insulation = Insulation::try_from(insulation)?;
+        let base_method = InstallMethod::try_from(base_method)?;
+        let base = lookup_base_current(size, insulation, base_method, loaded)?;
+        Ok(InstallMethod::ALL
+            .into_iter()
+            .filter_map(|method| {
+                let current = lookup_base_current(size, insulation, method, loaded).ok()?;
+                Some((method.as_str().to_string(), (current / base * 100.0 * 10.0).round() / 10.0))
+            })
+            .collect())
+    };
+    ratios().map_err(|key| localize_error(key, lang))
+}
+
+/// 보간 허용전류 조회 결과
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct InterpolatedCurrent {
+    pub current: f64,        // 허용전류 (A, 보정 전 기본값)
+    pub interpolated: bool,  // 표준 규격 사이 추정값 여부 (true면 참고용)
+}
+
+/// 비표준 규격의 기본 허용전류 추정 (인접 표준 규격 사이 로그-로그 보간, 소수 첫째 자리)
+/// 허용전류는 단면적의 거듭제곱(I ∝ S^n)에 가까우므로 log I - log S 직선으로 보간.
+/// 표준 규격과 일치하면 표 값 그대로(interpolated = false),
+/// 1.5mm² 미만·최대 표준 규격(1000mm²) 초과, 잘못된 절연·공사방법·부하 도체 수, 인접 규격 데이터가 없으면 None
+pub fn get_allowable_current_interpolated(size_mm2: f64, insulation: &str, method: &str, loaded: u8) -> Option<InterpolatedCurrent> {
+    let insulation = Insulation::try_from(insulation).ok()?;
+    let method = InstallMethod::try_from(method).ok()?;
+    if loaded != 2 && loaded != 3 {
+        return None;
+    }
+    let lookup = |size: &'static str| lookup_base_current(Size(size), insulation, method, loaded).ok().map(Amperes::value);
+    if let Some(size) = STANDARD_SIZES.iter().find(|s| Size(s).mm2() == size_mm2) {
+        return lookup(size).map(|current| InterpolatedCurrent { current, interpolated: false });
+    }
+    let (lower, upper) = STANDARD_SIZES
+        .windows(2)
+        .find(|w| Size(w[0]).mm2() < size_mm2 && size_mm2 < Size(w[1]).mm2())
+        .map(|w| (w[0], w[1]))?;
+    let (s0, s1) = (Size(lower).mm2(), Size(upper).mm2());
+    let (i0, i1) = (lookup(lower)?, lookup(upper)?);
+    let exponent = (i1 / i0).ln() / (s1 / s0).ln();
+    let current = i0 * (size_mm2 / s0).powf(exponent);
+    Some(InterpolatedCurrent { current: (current * 10.0).round() / 10.0, interpolated: true })
+}
+
+/// 가닥수별 적용 가능한 공사방법 (단심: A1/B1/C/D1/E, 다심: A2/B2/C/D1/D2/F)
+fn install_methods_for_cores(cores: &str) -> &'static [InstallMethod] {
+    use InstallMethod::*;
+    match cores {
+        "1C" => &[A1, B1, C, D1, E],
+        "2C" | "3C" | "4C" => &[A2, B2, C, D1, D2, F],
+        _ => &[],
+    }
+}
+
+/// 데이터 완전성 점검 항목 (종류·규격·가닥수 조합 1개)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoverageEntry {
+    pub cable_type: String,
+    pub size: String,
+    pub cores: String,
+    pub has_outer_diameter: bool,
+    pub has_current: bool,              // 적용 가능한 모든 공사방법에 허용전류가 있는지
+    pub missing_methods: Vec<String>,   // 허용전류가 없는 공사방법
+}
+
+/// 데이터 완전성 점검 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoverageReport {
+    pub entries: Vec<CoverageEntry>,
+    pub total: usize,                   // 점검한 조합 수
+    pub missing_outer_diameter: usize,  // 외경 데이터가 없는 조합 수
+    pub missing_current: usize,         // 허용전류가 하나라도 없는 조합 수
+}
+
+/// 전선 종류 × 표준 규격 × 가닥수 전 조합의 외경·허용전류 데이터 존재 여부
+/// 가닥수는 종류별 제공 범위(HFIX 단심 전용), 허용전류는 활성 데이터셋(사용자 정의 포함) 기준
+pub fn data_coverage_report() -> CoverageReport {
+    let table = active_current_table();
+    let mut entries = Vec::new();
+    for info in get_cable_types() {
+        let insulation = get_insulation_type(&info.code);
+        for size in STANDARD_SIZES {
+            let cores_options: &[&str] = if info.code == "HFIX" { &["1C"] } else { &["1C", "2C", "3C", "4C"] };
+            for &cores in cores_options {
+                let missing_methods: Vec<String> = install_methods_for_cores(cores)
+                    .iter()
+                    .filter(|method| !table.contains_key(&(Size(size), insulation, **method)))
+                    .map(|method| method.as_str().to_string())
+                    .collect();
+                entries.push(CoverageEntry {
+                    cable_type: info.code.clone(),
+                    size: size.to_string(),
+                    cores: cores.to_string(),
+                    has_outer_diameter: get_cable_outer_diameter(&info.code, size, cores).is_some(),
+                    has_current: missing_methods.is_empty(),
+                    missing_methods,
+                });
+            }
+        }
+    }
+    CoverageReport {
+        total: entries.len(),
+        missing_outer_diameter: entries.iter().filter(|e| !e.has_outer_diameter).count(),
+        missing_current: entries.iter().filter(|e| !e.has_current).count(),
+        entries,
+    }
+}
+
+const MAX_SIZE_SUGGESTIONS: usize = 8;
+const NEAREST_SIZE_SUGGESTIONS: usize = 2;
+
+/// 규격 입력 자동완성/오타 제안
+/// 정확 일치 -> 접두 일치(수치 오름차순) -> 수치 거리상 가까운 규격 순, 최대 8개
+/// 빈 입력이면 전체 표준 규격 반환
+pub fn suggest_sizes(partial: &str) -> Vec<String> {
+    let input = partial
+        .trim()
+        .trim_end_matches("mm²")
+        .trim_end_matches("mm2")
+        .trim_end_matches("sq")
+        .trim();
+    if input.is_empty() {
+        return STANDARD_SIZES.iter().map(|s| s.to_string()).collect();
+    }
+
+    let mut suggestions: Vec<&str> = STANDARD_SIZES.iter().copied().filter(|s| *s == input).collect();
+    suggestions.extend(STANDARD_SIZES.iter().copied().filter(|s| *s != input && s.starts_with(input)));
+
+    if let Ok(value) = input.parse::<f64>() {
+        let mut by_distance: Vec<&str> = STANDARD_SIZES.to_vec();
+        by_distance.sort_by(|a, b| {
+            let da = (Size(a).mm2() - value).abs();
+            let db = (Size(b).mm2() - value).abs();
+            da.total_cmp(&db)
+        });
+        for size in by_distance.into_iter().take(NEAREST_SIZE_SUGGESTIONS) {
+            if !suggestions.contains(&size) {
+                suggestions.push(size);
+            }
+        }
+    }
+
+    suggestions.into_iter().take(MAX_SIZE_SUGGESTIONS).map(String::from).collect()
+}
+
+/// AWG/kcmil 규격 (표시명, 단면적 mm²)
+/// AWG: A = 0.012668 × 92^((36 - n) / 19.5) mm², kcmil: 1 kcmil = 0.5067 mm²
+const AWG_SIZES: &[(&str, f64)] = &[
+    ("14 AWG", 2.08), ("12 AWG", 3.31), ("10 AWG", 5.26), ("8 AWG", 8.37),
+    ("6 AWG", 13.3), ("4 AWG", 21.2), ("3 AWG", 26.7), ("2 AWG", 33.6),
+    ("1 AWG", 42.4), ("1/0 AWG", 53.5), ("2/0 AWG", 67.4), ("3/0 AWG", 85.0),
+    ("4/0 AWG", 107.2), ("250 kcmil", 126.7), ("300 kcmil", 152.0), ("350 kcmil", 177.3),
+    ("400 kcmil", 202.7), ("500 kcmil", 253.4), ("600 kcmil", 304.0), ("750 kcmil", 380.0),
+    ("1000 kcmil", 506.7),
+];
+
+/// AWG 입력 정규화 ("4", "4AWG", "4/0", "250kcmil", "250 MCM" -> 표시명)
+fn awg_label(input: &str) -> Option<&'static str> {
+    let compact: String = input.to_ascii_lowercase().chars().filter(|c| !c.is_whitespace()).collect();
+    let number = compact
+        .trim_end_matches("awg")
+        .trim_end_matches("kcmil")
+        .trim_end_matches("mcm");
+    AWG_SIZES
+        .iter()
+        .map(|(label, _)| *label)
+        .find(|label| label.split(' ').next() == Some(number))
+}
+
+/// AWG/kcmil 규격을 단면적(mm²)으로 변환
+pub fn awg_to_mm2(awg: &str) -> Option<f64> {
+    let label = awg_label(awg)?;
+    AWG_SIZES.iter().find(|(l, _)| *l == label).map(|(_, area)| *area)
+}
+
+/// 단면적(mm²)에 가장 가까운 AWG/kcmil 규격 (로그 거리 기준)
+pub fn mm2_to_awg(mm2: f64) -> Option<&'static str> {
+    if mm2 <= 0.0 {
+        return None;
+    }
+    AWG_SIZES
+        .iter()
+        .min_by(|a, b| {
+            let da = (a.1 / mm2).ln().abs();
+            let db = (b.1 / mm2).ln().abs();
+            da.total_cmp(&db)
+        })
+        .map(|(label, _)| *label)
+}
+
+/// AWG 규격을 계산용 표준 mm² 규격으로 변환
+/// AWG 단면적 이상인 가장 작은 표준 규격으로 올림 (실제 도체 단면적을 과소 평가하지 않음)
+fn awg_to_standard_size(awg: &str) -> Option<&'static str> {
+    standard_size_at_least(awg_to_mm2(awg)?).map(Size::as_str)
+}
+
+/// 전선관 호칭경(mm) -> 인치 트레이드 사이즈
+fn conduit_trade_size(nominal: u32) -> Option<&'static str> {
+    match nominal {
+        16 => Some("1/2\""),
+        22 => Some("3/4\""),
+        28 => Some("1\""),
+        36 => Some("1-1/4\""),
+        42 => Some("1-1/2\""),
+        54 => Some("2\""),
+        70 => Some("2-1/2\""),
+        82 => Some("3\""),
+        92 => Some("3-1/2\""),
+        104 => Some("4\""),
+        _ => None,
+    }
+}
+
+/// 바이너리 직렬화 스키마 버전 (구조체 필드 변경 시 증가)
+/// 바이너리 형식은 필드 순서에 의존하므로 버전이 다르면 디코딩하지 않음
+pub const BINARY_SCHEMA_VERSION: u8 = 29;
+
+/// [스키마 버전 1바이트] + bincode 본문
+fn encode_binary<T: Serialize>(value: &T) -> Vec<u8> {
+    let mut bytes = vec![BINARY_SCHEMA_VERSION];
+    bincode::serialize_into(&mut bytes, value).expect("메모리 버퍼 직렬화는 실패하지 않음");
+    bytes
+}
+
+fn decode_binary<T: for<'de> Deserialize<'de>>(bytes: &[u8]) -> Result<T, &'static str> {
+    match bytes.split_first() {
+        Some((&BINARY_SCHEMA_VERSION, body)) => bincode::deserialize(body).map_err(|_| ERR_BINARY_DECODE),
+        Some(_) => Err(ERR_BINARY_VERSION_MISMATCH),
+        None => Err(ERR_BINARY_DECODE),
+    }
+}
+
+/// 계산 결과 바이너리 인코딩 (JSON 대비 작고 빠름, 시스템 연동용)
+pub fn serialize_result_binary(result: &CalculationResult) -> Vec<u8> {
+    encode_binary(result)
+}
+
+/// 계산 결과 바이너리 디코딩 (스키마 버전 불일치 시 에러, lang: ko/en)
+pub fn deserialize_result_binary(bytes: &[u8], lang: &str) -> Result<CalculationResult, String> {
+    decode_binary(bytes).map_err(|key| localize_error(key, lang))
+}
+
+/// 입력 데이터 바이너리 인코딩
+pub fn serialize_data_binary(data: &CableData) -> Vec<u8> {
+    encode_binary(data)
+}
+
+/// 입력 데이터 바이너리 디코딩 (스키마 버전 불일치 시 에러, lang: ko/en)
+pub fn deserialize_data_binary(bytes: &[u8], lang: &str) -> Result<CableData, String> {
+    decode_binary(bytes).map_err(|key| localize_error(key, lang))
+}
+
+/// 프로젝트 파일(.kecproj) 스키마 버전 (이 값 이하의 파일만 가져오기 가능)
+pub const PROJECT_SCHEMA_VERSION: u32 = 1;
+
+/// 프로젝트 메타데이터
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProjectMetadata {
+    pub name: String,         // 프로젝트명
+    #[serde(default)]
+    pub author: String,       // 작성자
+    #[serde(default)]
+    pub description: String,  // 비고
+}
+
+/// 프로젝트 전체 (.kecproj, JSON)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectFile {
+    pub schema_version: u32,
+    pub created_at: u64,                    // 생성 시각 (Unix epoch 초)
+    pub metadata: ProjectMetadata,
+    pub circuits: Vec<CableData>,           // 회로 목록
+    pub data_version: String,               // 작성 당시 내장 데이터셋 버전
+    #[serde(default)]
+    pub settings: HashMap<String, String>,  // 사용자 설정
+}
+
+/// 프로젝트를 .kecproj(JSON) 문자열로 내보내기
+/// 스키마 버전·생성 시각·내장 데이터셋 버전을 함께 기록
+pub fn export_project_file(metadata: ProjectMetadata, circuits: Vec<CableData>, settings: HashMap<String, String>) -> Result<String, String> {
+    let created_at = unix_now().as_secs();
+    let project = ProjectFile {
+        schema_version: PROJECT_SCHEMA_VERSION,
+        created_at,
+        metadata,
+        circuits,
+        data_version: DATA_VERSION.to_string(),
+        settings,
+    };
+    serde_json::to_string_pretty(&project).map_err(|e| e.to_string())
+}
+
+/// .kecproj(JSON) 문자열에서 프로젝트 가져오기
+/// 스키마 버전을 먼저 확인해 손상된 파일과 지원하지 않는 버전을 구분 (lang: ko/en)
+pub fn import_project_file(json: &str, lang: &str) -> Result<ProjectFile, String> {
+    let import = || -> Result<ProjectFile, &'static str> {
+        let value: serde_json::Value = serde_json::from_str(json).map_err(|_| ERR_PROJECT_CORRUPT)?;
+        let version = value
+            .get("schema_version")
+            .and_then(|v| v.as_u64())
+            .ok_or(ERR_PROJECT_CORRUPT)?;
+        if version == 0 || version > PROJECT_SCHEMA_VERSION as u64 {
+            return Err(ERR_PROJECT_VERSION_UNSUPPORTED);
+        }
+        let project: ProjectFile = serde_json::from_value(value).map_err(|_| ERR_PROJECT_CORRUPT)?;
+        if project.data_version != DATA_VERSION {
+            return Err(ERR_UNKNOWN_DATA_VERSION);
+        }
+        Ok(project)
+    };
+    import().map_err(|key| localize_error(key, lang))
+}
+
+/// 스트리밍 배치 계산 청크 (calc-chunk 이벤트 페이로드)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchChunk {
+    pub chunk_index: usize,
+    pub results: Vec<(usize, CalculationResult)>,  // (입력 인덱스, 결과), 에러 항목은 제외
+}
+
+/// 스트리밍 배치 계산 완료 요약 (calc-complete 이벤트 페이로드)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchComplete {
+    pub total: usize,
+    pub succeeded: usize,
+    pub errors: Vec<(usize, String)>,  // (입력 인덱스, 에러 메시지)
+}
+
+/// 청크 단위 배치 계산: 청크마다 on_chunk를 호출하고 에러는 모아 마지막에 반환
+/// 전체 결과를 한 번에 들고 있지 않으므로 수천 건 배치에도 메모리 부담이 청크 크기로 제한됨
+pub fn calculate_batch_chunked<E>(
+    cables: &[CableData],
+    chunk_size: usize,
+    mut on_chunk: impl FnMut(BatchChunk) -> Result<(), E>,
+) -> Result<BatchComplete, String>
+where
+    E: std::fmt::Display,
+{
+    if chunk_size == 0 {
+        return Err(localize_error(ERR_INVALID_CHUNK_SIZE, batch_lang(cables)));
+    }
+    let mut errors = Vec::new();
+    let mut succeeded = 0;
+    for (chunk_index, chunk) in cables.chunks(chunk_size).enumerate() {
+        let offset = chunk_index * chunk_size;
+        let mut results = Vec::with_capacity(chunk.len());
+        for (i, data) in chunk.iter().enumerate() {
+            match calculate_core(data) {
+                Ok(result) => results.push((offset + i, result)),
+                Err(e) => errors.push((offset + i, e)),
+            }
+        }
+        succeeded += results.len();
+        on_chunk(BatchChunk { chunk_index, results }).map_err(|e| e.to_string())?;
+    }
+    Ok(BatchComplete { total: cables.len(), succeeded, errors })
+}
+
+/// 배치 계산 요약 (분전반 집계용, 실패 항목 제외)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BatchSummary {
+    pub total: usize,                // 입력 항목 수
+    pub succeeded: usize,            // 계산 성공 항목 수
+    pub failed: usize,               // 계산 실패 항목 수
+    pub circuit_count: u32,          // 회로 수 합계 (단심은 회로당 가닥수로 환산)
+    pub total_conductor_area: f64,   // 도체 단면적 합계 (mm²)
+    pub total_cable_length: f64,     // 케이블 포설 길이 합계 (m, 물량 집계)
+    pub total_ground_wire_length: f64, // 접지선 길이 합계 (m, 물량 집계)
+}
+
+/// 배치 계산 결과와 요약
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchCalculation {
+    pub results: Vec<Result<CalculationResult, String>>,
+    pub summary: BatchSummary,
+}
+
+/// 여러 회로를 입력 순서대로 계산 (항목 실패와 무관하게 나머지 계속 계산)
+/// 에러 메시지는 "#번호 (종류 가닥수 규격mm²): 메시지" 형식으로 실패 항목 식별
+pub fn calculate_batch(items: &[CableData]) -> Vec<Result<CalculationResult, String>> {
+    items
+        .iter()
+        .enumerate()
+        .map(|(index, data)| {
+            calculate_core(data).map_err(|e| format!("#{} ({} {} {}mm²): {}", index + 1, data.cable_type, data.cores, data.size, e))
+        })
+        .collect()
+}
+
+/// 배치 계산 + 요약 (회로 수·도체 단면적·물량 합계)
+pub fn calculate_batch_with_summary(items: &[CableData]) -> BatchCalculation {
+    let results = calculate_batch(items);
+    let mut summary = BatchSummary { total: items.len(), ..Default::default() };
+    for (data, result) in items.iter().zip(&results) {
+        match result {
+            Ok(result) => {
+                summary.succeeded += 1;
+                summary.circuit_count += data_circuit_count(data).unwrap_or_default();
+                summary.total_conductor_area += result.conductor_area;
+                summary.total_cable_length += result.total_cable_length;
+                summary.total_ground_wire_length += result.ground_wire_length;
+            }
+            Err(_) => summary.failed += 1,
+        }
+    }
+    summary.total_conductor_area = (summary.total_conductor_area * 100.0).round() / 100.0;
+    summary.total_cable_length = (summary.total_cable_length * 10.0).round() / 10.0;
+    summary.total_ground_wire_length = (summary.total_ground_wire_length * 10.0).round() / 10.0;
+    BatchCalculation { results, summary }
+}
+
+/// 감사 로그 파일명 (app data 디렉터리 아래, JSON Lines)
+pub const AUDIT_LOG_FILE: &str = "audit_log.jsonl";
+
+static AUDIT_LOG_PATH: RwLock<Option<PathBuf>> = RwLock::new(None);
+static AUDIT_SEQUENCE: AtomicU64 = AtomicU64::new(0);
+/// 감사 로그 기록 스레드로 보내는 채널 (첫 기록 시 스레드 시작)
+static AUDIT_WRITER: OnceLock<mpsc::Sender<AuditMessage>> = OnceLock::new();
+
+/// 감사 로그 기록 스레드 메시지
+enum AuditMessage {
+    Append(PathBuf, Box<AuditEntry>),  // 경로의 로그 파일에 한 줄 추가
+    Flush(mpsc::Sender<()>),           // 앞서 보낸 항목을 모두 기록한 뒤 응답
+}
+
+/// 감사 로그 항목 (계산 1회 = 1줄)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEntry {
+    pub id: String,                     // 고유 ID (시각-순번)
+    pub timestamp: String,              // UTC, ISO 8601 (예: 2024-05-01T09:30:00Z)
+    pub app_version: String,
+    pub input: CableData,
+    pub result: CalculationResult,
+}
+
+/// Unix epoch 기준 현재 시각
+#[cfg(not(target_arch = "wasm32"))]
+fn unix_now() -> std::time::Duration {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+}
+
+/// Unix epoch 기준 현재 시각 (wasm32에는 시스템 시계가 없어 JS `Date.now()` 사용)
+#[cfg(target_arch = "wasm32")]
+fn unix_now() -> std::time::Duration {
+    std::time::Duration::from_millis(js_sys::Date::now() as u64)
+}
+
+/// 경과 시간(µs) 측정 시작, 반환된 함수가 시작 이후 경과 시간을 돌려줌
+/// 시스템 시계 변경에 영향받지 않도록 단조 시계(Instant) 사용, unix_now는 시각 표시에만 사용
+#[cfg(not(target_arch = "wasm32"))]
+fn start_stopwatch() -> impl Fn() -> u64 {
+    let started = std::time::Instant::now();
+    move || started.elapsed().as_micros() as u64
+}
+
+/// 경과 시간 측정 (wasm32 std에는 단조 시계가 없어 항상 0)
+#[cfg(target_arch = "wasm32")]
+fn start_stopwatch() -> impl Fn() -> u64 {
+    || 0
+}
+
+/// Unix epoch 초를 UTC ISO 8601 문자열로 변환 (civil-from-days)
+fn format_utc_timestamp(secs: u64) -> String {
+    let days = (secs / 86_400) as i64;
+    let rem = secs % 86_400;
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year, month, day, rem / 3_600, rem % 3_600 / 60, rem % 60
+    )
+}
+
+/// 감사 로그 파일 경로 설정 (None이면 기록 중지)
+pub fn set_audit_log_path(path: Option<PathBuf>) {
+    *AUDIT_LOG_PATH.write().unwrap_or_else(|e| e.into_inner()) = path;
+}
+
+/// 감사 로그 파일 열기 (디렉터리 생성, append-only)
+fn open_audit_file(path: &Path) -> std::io::Result<std::fs::File> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::OpenOptions::new().create(true).append(true).open(path)
+}
+
+/// 감사 로그 한 줄 추가
+fn append_audit_entry(file: &mut std::fs::File, entry: &AuditEntry) -> std::io::Result<()> {
+    use std::io::Write;
+    let line = serde_json::to_string(entry)?;
+    writeln!(file, "{}", line)
+}
+
+/// 감사 로그 기록 스레드 본문: 파일은 경로별로 한 번만 열어 재사용, 실패는 경고만 남김
+fn run_audit_writer(receiver: mpsc::Receiver<AuditMessage>) {
+    let mut open: Option<(PathBuf, std::fs::File)> = None;
+    for message in receiver {
+        match message {
+            AuditMessage::Append(path, entry) => {
+                if open.as_ref().is_none_or(|(current, _)| *current != path) {
+                    open = match open_audit_file(&path) {
+                        Ok(file) => Some((path, file)),
+                        Err(e) => {
+                            tracing::warn!(error = %e, path = %path.display(), "감사 로그 파일을 열 수 없음");
+                            None
+                        }
+                    };
+                }
+                if let Some((_, file)) = open.as_mut() {
+                    if let Err(e) = append_audit_entry(file, &entry) {
+                        tracing::warn!(error = %e, "감사 로그 기록 실패");
+                        open = None;
+                    }
+                }
+            }
+            AuditMessage::Flush(done) => {
+                let _ = done.send(());
+            }
+        }
+    }
+}
+
+/// 감사 로그 기록 스레드 채널 (없으면 스레드 시작, 시작 실패 시 None)
+fn audit_writer() -> Option<&'static mpsc::Sender<AuditMessage>> {
+    if let Some(sender) = AUDIT_WRITER.get() {
+        return Some(sender);
+    }
+    let (sender, receiver) = mpsc::channel();
+    let spawned = std::thread::Builder::new()
+        .name("kec-audit-log".to_string())
+        .spawn(move || run_audit_writer(receiver));
+    if let Err(e) = spawned {
+        tracing::warn!(error = %e, "감사 로그 기록 스레드를 시작할 수 없음");
+        return None;
+    }
+    // 동시에 시작된 경우 먼저 등록된 채널을 쓰고, 나머지 스레드는 송신자가 사라지며 종료
+    Some(AUDIT_WRITER.get_or_init(|| sender))
+}
+
+/// 대기 중인 감사 로그 항목을 모두 파일에 기록할 때까지 대기 (기록 스레드가 없으면 즉시 반환)
+fn flush_audit_log() {
+    let Some(sender) = AUDIT_WRITER.get() else {
+        return;
+    };
+    let (done, wait) = mpsc::channel();
+    if sender.send(AuditMessage::Flush(done)).is_ok() {
+        let _ = wait.recv();
+    }
+}
+
+/// 계산 결과를 감사 로그에 기록
+/// 파일 기록은 백그라운드 스레드에서 처리해 호출 스레드를 막지 않으며, 실패해도 계산에는 영향을 주지 않음.
+/// 경로가 설정되지 않았으면 아무것도 하지 않음
+pub fn record_audit(data: &CableData, result: &CalculationResult) {
+    let Some(path) = AUDIT_LOG_PATH.read().unwrap_or_else(|e| e.into_inner()).clone() else {
+        return;
+    };
+    let now = unix_now();
+    let entry = AuditEntry {
+        id: format!("{:x}-{:x}", now.as_nanos(), AUDIT_SEQUENCE.fetch_add(1, Ordering::Relaxed)),
+        timestamp: format_utc_timestamp(now.as_secs()),
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        input: data.clone(),
+        result: result.clone(),
+    };
+    if let Some(sender) = audit_writer() {
+        if sender.send(AuditMessage::Append(path, Box::new(entry))).is_err() {
+            tracing::warn!("감사 로그 기록 스레드가 종료됨");
+        }
+    }
+}
+
+/// 감사 로그 파일에서 날짜 범위(from~to, 양끝 포함)의 항목 읽기
+/// from/to는 "YYYY-MM-DD" 또는 ISO 8601 접두어, 빈 문자열이면 범위 제한 없음.
+/// 파일이 없거나 손상된 줄은 건너뜀
+fn read_audit_entries(path: &Path, from: &str, to: &str) -> Vec<AuditEntry> {
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    content
+        .lines()
+        .filter_map(|line| serde_json::from_str::<AuditEntry>(line).ok())
+        .filter(|entry| {
+            let ts = entry.timestamp.as_str();
+            let after_from = from.is_empty() || ts >= from;
+            // to와 같은 길이의 접두어로 비교해 "2024-05-01"이 그날 전체를 포함하도록
+            let before_to = to.is_empty() || ts.get(..to.len()).unwrap_or(ts) <= to;
+            after_from && before_to
+        })
+        .collect()
+}
+
+/// 설정된 감사 로그에서 날짜 범위 항목 조회 (로그 경로 미설정 시 빈 목록)
+/// 조회 전 대기 중인 기록을 먼저 반영
+pub fn get_audit_log(from: &str, to: &str) -> Vec<AuditEntry> {
+    flush_audit_log();
+    match AUDIT_LOG_PATH.read().unwrap_or_else(|e| e.into_inner()).as_deref() {
+        Some(path) => read_audit_entries(path, from.trim(), to.trim()),
+        None => Vec::new(),
+    }
+}
+
+/// 부하전류가 보정 후 허용전류를 초과하는지 여부 (부하전류 미지정 시 false)
+pub fn is_overloaded(data: &CableData, result: &CalculationResult) -> bool {
+    data.load_current
+        .is_some_and(|load| load > result.allowable_current)
+}
+
+/// 마크다운 표 셀 이스케이프 (파이프, 줄바꿈: CRLF·CR·LF 모두 공백 하나로)
+fn escape_markdown_cell(value: &str) -> String {
+    value.replace('|', "\\|").replace("\r\n", " ").replace(['\r', '\n'], " ")
+}
+
+/// 내보내기용 선택 항목 표시 (빈 값이면 "미지정")
+fn display_or_unspecified(value: Option<&str>) -> String {
+    match value.map(str::trim) {
+        Some(v) if !v.is_empty() => v.to_string(),
+        _ => "미지정".to_string(),
+    }
+}
+
+/// 계산 결과 목록을 GitHub 호환 마크다운 표로 변환
+/// 과부하(부하전류 > 허용전류)와 점유율 초과 셀에는 ⚠️ 표시
+pub fn export_results_markdown(rows: &[(CableData, CalculationResult)]) -> String {
+    let mut out = String::new();
+    out.push_str("| 전선종류 | 가닥수 | 규격(mm²) | 수량 | 전압방식 | 공사방법 | 부하전류(A) | 허용전류(A) | 추천 전선관 | 점유율(%) | 제조사 | 모델 |\n");
+    out.push_str("|---|---|---:|---:|---|---|---:|---:|---|---:|---|---|\n");
+
+    for (data, result) in rows {
+        let load_current = match data.load_current {
+            Some(load) => format!("{:.1}", load),
+            None => "-".to_string(),
+        };
+        let mut allowable_current = format!("{:.1}", result.allowable_current);
+        if is_overloaded(data, result) {
+            allowable_current.push_str(" ⚠️");
+        }
+        let mut fill_rate = format!("{:.1}", result.fill_rate);
+        if result.recommended_raceway.status != "정상" {
+            fill_rate.push_str(" ⚠️");
+        }
+
+        let cells = [
+            escape_markdown_cell(&data.cable_type),
+            escape_markdown_cell(&data.cores),
+            escape_markdown_cell(&data.size),
+            data.quantity.to_string(),
+            escape_markdown_cell(&data.system),
+            escape_markdown_cell(&data.install_method),
+            load_current,
+            allowable_current,
+            escape_markdown_cell(&result.recommended_raceway.name),
+            fill_rate,
+            escape_markdown_cell(&display_or_unspecified(data.manufacturer.as_deref())),
+            escape_markdown_cell(&display_or_unspecified(data.model.as_deref())),
+        ];
+        out.push_str("| ");
+        out.push_str(&cells.join(" | "));
+        out.push_str(" |\n");
+    }
+    out
+}
+
+/// CSV 필드 이스케이프 (RFC 4180: 쉼표·따옴표·줄바꿈이 있으면 따옴표로 감싸고 따옴표는 두 번)
+fn escape_csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// 계산 결과 목록을 CSV로 변환 (UTF-8 BOM 포함, 엑셀 한글 깨짐 방지)
+/// items와 results는 같은 순서·같은 길이여야 함
+pub fn export_results_csv(items: &[CableData], results: &[CalculationResult]) -> Result<String, String> {
+    if items.len() != results.len() {
+        return Err(localize_error(ERR_EXPORT_LENGTH_MISMATCH, batch_lang(items)));
+    }
+    let mut out = String::from("\u{feff}");
+    out.push_str("전선종류,가닥수,규격(mm²),수량,전압방식,공사방법,접지선,부하전류(A),허용전류(A),추천전선관,전선관 상태,점유율(%),제조사,모델\r\n");
+    for (data, result) in items.iter().zip(results) {
+        let cells = [
+            data.cable_type.clone(),
+            data.cores.clone(),
+            data.size.clone(),
+            data.quantity.to_string(),
+            data.system.clone(),
+            data.install_method.clone(),
+            data.ground_wire.clone(),
+            data.load_current.map_or(String::new(), |load| format!("{:.1}", load)),
+            format!("{:.1}", result.allowable_current),
+            result.recommended_raceway.name.clone(),
+            result.recommended_raceway.status.clone(),
+            format!("{:.1}", result.fill_rate),
+            data.manufacturer.clone().unwrap_or_default(),
+            data.model.clone().unwrap_or_default(),
+        ];
+        let row: Vec<String> = cells.iter().map(|cell| escape_csv_field(cell)).collect();
+        out.push_str(&row.join(","));
+        out.push_str("\r\n");
+    }
+    Ok(out)
+}
+
+/// HTML 텍스트·속성 이스케이프
+fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+/// 계산서 표 한 행 (항목, 값, 강조 여부)
+fn html_report_row(out: &mut String, label: &str, value: &str, alert: bool) {
+    let class = if alert { " class=\"alert\"" } else { "" };
+    out.push_str(&format!("<tr><th>{}</th><td{}>{}</td></tr>\n", escape_html(label), class, escape_html(value)));
+}
+
+/// 계산서 인라인 스타일 (단일 파일 완결)
+const REPORT_CSS: &str = "body{font-family:'Malgun Gothic','Apple SD Gothic Neo',sans-serif;margin:24px;color:#1f2937}\
+h1{font-size:20px;margin-bottom:4px}h2{font-size:16px;margin-top:24px;border-bottom:2px solid #2563eb;padding-bottom:4px}\
+table{border-collapse:collapse;width:100%;max-width:720px}th,td{border:1px solid #d1d5db;padding:6px 10px;text-align:left}\
+th{background:#f3f4f6;width:40%}td.alert{background:#fee2e2;color:#b91c1c;font-weight:bold}\
+.meta{color:#6b7280;font-size:13px}.warnings li{color:#b45309}.formulas li{font-family:Consolas,monospace;font-size:13px}footer{margin-top:32px;font-size:12px;color:#6b7280}";
+
+/// 적용 규정별 계산서 출처 조항
+fn report_source_clauses(regulation: &str) -> &'static [&'static str] {
+    match regulation {
+        "NEC" => &[
+            "NEC 310.16 (도체 ampacity)",
+            "NEC 310.15(C)(1) (통전 도체 수 조정계수)",
+            "NEC Chapter 9 Table 1 (전선관 점유율)",
+        ],
+        "IEC" => &[
+            "IEC 60364-5-52 Table B.52.4/B.52.5 (허용전류)",
+            "IEC 60364-5-52 Table B.52.17 (집합 보정계수)",
+            "IEC 60364-5-54 Table 54.2 (보호도체 단면적)",
+        ],
+        _ => &[
+            "KEC 232.2 (전선관 점유율 1/3 이하)",
+            "IEC 60364-5-52 Table B.52.4/B.52.5 (허용전류)",
+            "KEC Table B.52.17 (집합 보정계수)",
+        ],
+    }
+}
+
+/// 계산 결과를 인라인 CSS가 포함된 단일 HTML 계산서로 렌더링
+/// 입력 조건·적용 기준·보정·결과를 표로 배치하고 과부하·점유율 초과 셀은 강조,
+/// 경고·가정(적합성 감점 사유 등)이 있으면 별도 섹션, 출처 조항은 푸터에 표시
+/// 계산 시각은 결과의 data_provenance 값만 쓰므로 같은 입력·결과면 출력이 같음
+/// PDF 출력은 지원하지 않음 (필요하면 이 HTML을 웹뷰·브라우저 인쇄로 PDF 저장)
+pub fn render_report_html(data: &CableData, result: &CalculationResult, meta: Option<&ProjectMetadata>) -> String {
+    let overloaded = is_overloaded(data, result);
+    let fill_exceeded = result.recommended_raceway.status != "정상";
+
+    let mut out = String::new();
+    out.push_str("<!DOCTYPE html>\n<html lang=\"ko\">\n<head>\n<meta charset=\"UTF-8\">\n");
+    out.push_str("<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n");
+    out.push_str("<title>전선관 및 허용전류 계산서</title>\n");
+    out.push_str(&format!("<style>{}</style>\n</head>\n<body>\n", REPORT_CSS));
+    out.push_str("<h1>전선관 및 허용전류 계산서</h1>\n");
+    if let Some(meta) = meta {
+        out.push_str(&format!("<p class=\"meta\">프로젝트: {}", escape_html(&meta.name)));
+        if !meta.author.trim().is_empty() {
+            out.push_str(&format!(" · 작성자: {}", escape_html(&meta.author)));
+        }
+        if !meta.description.trim().is_empty() {
+            out.push_str(&format!("<br>{}", escape_html(&meta.description)));
+        }
+        out.push_str("</p>\n");
+    }
+
+    out.push_str("<h2>입력 조건</h2>\n<table>\n");
+    html_report_row(&mut out, "전선 종류", &data.cable_type, false);
+    html_report_row(&mut out, "가닥수", &data.cores, false);
+    let material = resolve_material(data).unwrap_or(Material::Copper);
+    html_report_row(&mut out, "도체 재질", if material == Material::Aluminium { "알루미늄 (Al)" } else { "동 (Cu)" }, false);
+    html_report_row(&mut out, "규격", &result.size_label, false);
+    html_report_row(&mut out, "수량", &data.quantity.to_string(), false);
+    html_report_row(&mut out, "전압 방식", &data.system, false);
+    let ground_wire = match result.ground_wire_size.as_str() {
+        "" => data.ground_wire.clone(),
+        size => format!("{} {} mm²", data.ground_wire, size),
+    };
+    html_report_row(&mut out, "접지선", &ground_wire, false);
+    html_report_row(&mut out, "공사방법", &data.install_method, false);
+    let load_current = data.load_current.map_or("-".to_string(), |load| format!("{:.1} A", load));
+    html_report_row(&mut out, "부하전류", &load_current, false);
+    let ambient_temp = data.ambient_temp.map_or("기준온도".to_string(), |temp| format!("{} °C", temp));
+    html_report_row(&mut out, "주변온도", &ambient_temp, false);
+    html_report_row(&mut out, "용도", &display_or_unspecified(data.usage.as_deref()), false);
+    html_report_row(&mut out, "제조사", &display_or_unspecified(data.manufacturer.as_deref()), false);
+    html_report_row(&mut out, "모델", &display_or_unspecified(data.model.as_deref()), false);
+    out.push_str("</table>\n");
+
+    out.push_str("<h2>적용 기준</h2>\n<table>\n");
+    html_report_row(&mut out, "적용 규정", &result.regulation, false);
+    html_report_row(&mut out, "도체 최고허용온도", &format!("{} °C", result.conductor_max_temp), false);
+    if result.applied_temp_rating > 0 && result.applied_temp_rating != result.conductor_max_temp {
+        html_report_row(&mut out, "적용 온도 등급 (단자 제한)", &format!("{} °C", result.applied_temp_rating), false);
+    }
+    html_report_row(&mut out, "최소 규격", result.minimum_size.as_deref().unwrap_or("-"), false);
+    let provenance = &result.data_provenance;
+    if !provenance.table_version.is_empty() {
+        html_report_row(&mut out, "데이터 버전", &provenance.table_version, false);
+        html_report_row(&mut out, "데이터 체크섬", &provenance.data_checksum, false);
+        html_report_row(&mut out, "계산 시각", &provenance.calculated_at, false);
+    }
+    out.push_str("</table>\n");
+
+    out.push_str("<h2>보정</h2>\n<table>\n");
+    if let Ok(num_circuits) = grouping_circuit_count(data) {
+        html_report_row(&mut out, "회로 수", &num_circuits.to_string(), false);
+        html_report_row(&mut out, "집합 보정계수", &format!("{:.2}", get_grouping_factor(num_circuits, data.arrangement.unwrap_or_default())), false);
+    }
+    html_report_row(&mut out, "공사방법·보정 내역", &result.install_method_desc, false);
+    out.push_str("</table>\n");
+
+    out.push_str("<h2>계산 결과</h2>\n<table>\n");
+    html_report_row(&mut out, "총 단면적", &format!("{:.2} mm²", result.total_area), false);
+    html_report_row(&mut out, "실효 점유 면적", &format!("{:.2} mm²", result.effective_area), false);
+    html_report_row(&mut out, "도체 단면적", &format!("{:.2} mm²", result.conductor_area), false);
+    html_report_row(&mut out, "허용전류", &format!("{:.1} A", result.allowable_current), overloaded);
+    let conduit = if fill_exceeded {
+        format!("{} ({})", result.recommended_raceway.name, result.recommended_raceway.status)
+    } else {
+        result.recommended_raceway.name.clone()
+    };
+    html_report_row(&mut out, "추천 전선관", &conduit, fill_exceeded);
+    html_report_row(&mut out, "점유율", &format!("{:.1} %", result.fill_rate), fill_exceeded);
+    if let (Some(ok), Some(min_size)) = (result.short_circuit_ok, result.short_circuit_min_size) {
+        let verdict = if ok { "적합" } else { "부족" };
+        html_report_row(&mut out, "단락 열적 허용", &format!("{} (최소 {:.2} mm²)", verdict, min_size), !ok);
+    }
+    out.push_str("</table>\n");
+
+    if !result.formulas.is_empty() {
+        out.push_str("<h2>계산 근거</h2>\n<ul class=\"formulas\">\n");
+        for formula in &result.formulas {
+            out.push_str(&format!("<li>{}</li>\n", escape_html(formula)));
+        }
+        out.push_str("</ul>\n");
+    }
+
+    let mut warnings: Vec<String> = Vec::new();
+    if overloaded {
+        warnings.push(format!(
+            "과부하: 부하전류 {:.1} A가 허용전류 {:.1} A를 초과",
+            data.load_current.unwrap_or_default(),
+            result.allowable_current
+        ));
+    }
+    if let Some(note) = &result.recommended_raceway.geometry_note {
+        warnings.push(note.clone());
+    }
+    if let (Some(false), Some(min_size)) = (result.short_circuit_ok, result.short_circuit_min_size) {
+        warnings.push(format!("단락 열적 허용 부족: 최소 {:.2} mm² 필요", min_size));
+    }
+    warnings.extend(evaluate_suitability(data, result, &SuitabilityContext::default()).deductions.into_iter().map(|(reason, _)| reason));
+    if !warnings.is_empty() {
+        out.push_str("<h2>경고 및 가정</h2>\n<ul class=\"warnings\">\n");
+        for warning in &warnings {
+            out.push_str(&format!("<li>{}</li>\n", escape_html(warning)));
+        }
+        out.push_str("</ul>\n");
+    }
+
+    out.push_str("<footer>출처: ");
+    out.push_str(&escape_html(&report_source_clauses(&result.regulation).join(" / ")));
+    out.push_str("</footer>\n</body>\n</html>\n");
+    out
+}
+
+/// 적합성 점수 감점 기준
+const SCORE_FILL_EXCEEDED: i32 = 20;      // 점유율 초과 (최대 전선관으로도 수용 불가)
+const SCORE_FILL_TIGHT: i32 = 5;          // 점유율 한계 근접 (한계 - 3%p 초과)
+const SCORE_GROUPING_SEVERE: i32 = 10;    // 집합계수 0.50 이하
+const SCORE_GROUPING_MODERATE: i32 = 5;   // 집합계수 0.70 이하
+const SCORE_METHOD_MISMATCH: i32 = 10;    // 가닥수와 맞지 않는 공사방법
+const SCORE_APPROX_DIAMETER: i32 = 5;     // 배율로 근사한 외경 사용
+const SCORE_VOLTAGE_DROP_EXCEEDED: i32 = 15; // 전압강하 허용치 초과
+const SCORE_BEND_TOO_TIGHT: i32 = 10;     // 경로 굴곡 반경 < 케이블 최소 굽힘 반경
+const SCORE_REVIEW_THRESHOLD: u32 = 70;   // 재검토 권장 기준
+const FILL_TIGHT_MARGIN: f64 = 3.0;       // 점유율 한계 근접 판정 여유 (%p)
+
+/// 계산 결과 밖의 적합성 평가 입력 (미지정 항목은 평가 생략)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SuitabilityContext {
+    #[serde(default)]
+    pub voltage_drop: Option<VoltageDropResult>, // 같은 회로의 전압강하 계산 결과
+    #[serde(default)]
+    pub bend_radius_mm: Option<f64>,             // 포설 경로의 최소 굴곡 반경 (mm, 관 곡관부·트레이 곡선부)
+}
+
+/// 입력 조합 적합성 평가 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuitabilityScore {
+    pub score: u32,                     // 0~100
+    pub grade: String,                  // 적합 / 양호 / 재검토 권장
+    pub deductions: Vec<(String, i32)>, // (감점 사유, 감점)
+}
+
+/// 다심 외경이 근사값인 조합인지 여부
+/// CV/TFR-CV 3·4심은 2심 외경 배율, FR-CV/TFR-8 다심은 제조사 카탈로그와 대조하지 않은 대표값(또는 그 환산)
+fn is_outer_diameter_approximated(cable_type: &str, cores: &str) -> bool {
+    matches!(
+        (cable_type, cores),
+        ("TFR-CV" | "CV", "3C" | "4C") | ("FR-CV" | "TFR-8", "2C" | "3C" | "4C")
+    )
+}
+
+/// 입력 조합의 종합 적합성 평가 (점유율, 집합계수, 공사방법 권장도, 데이터 품질,
+/// context에 주어진 경우 전압강하·경로 굴곡 반경)
+pub fn evaluate_suitability(data: &CableData, result: &CalculationResult, context: &SuitabilityContext) -> SuitabilityScore {
+    let mut deductions: Vec<(String, i32)> = Vec::new();
+
+    // 점유율
+    if result.recommended_raceway.status != "정상" {
+        deductions.push((
+            format!("점유율 초과 ({})", result.recommended_raceway.status),
+            SCORE_FILL_EXCEEDED,
+        ));
+    } else if result.fill_rate > result.recommended_raceway.fill_limit - FILL_TIGHT_MARGIN {
+        deductions.push((
+            format!("점유율 {:.1}% (한계 {:.0}% 근접)", result.fill_rate, result.recommended_raceway.fill_limit),
+            SCORE_FILL_TIGHT,
+        ));
+    }
+
+    // 허용전류 여유 (집합계수에 의한 감소)
+    let num_circuits = grouping_circuit_count(data).unwrap_or_default();
+    let grouping_factor = get_grouping_factor(num_circuits, data.arrangement.unwrap_or_default());
+    if grouping_factor <= 0.50 {
+        deductions.push((
+            format!("집합계수 {:.2} ({}회로) - 허용전류 대폭 감소", grouping_factor, num_circuits),
+            SCORE_GROUPING_SEVERE,
+        ));
+    } else if grouping_factor <= 0.70 {
+        deductions.push((
+            format!("집합계수 {:.2} ({}회로)", grouping_factor, num_circuits),
+            SCORE_GROUPING_MODERATE,
+        ));
+    }
+
+    // 공사방법 권장도 (단심/다심 전용 방법)
+    let method_mismatch = matches!(
+        (data.cores.as_str(), data.install_method.as_str()),
+        ("1C", "A2" | "B2" | "D2" | "F") | ("2C" | "3C" | "4C", "A1" | "B1" | "E")
+    );
+    if method_mismatch {
+        deductions.push((
+            format!("{} 케이블에 권장되지 않는 공사방법 ({})", data.cores, data.install_method),
+            SCORE_METHOD_MISMATCH,
+        ));
+    }
+
+    // 전압강하 (허용치는 calculate_voltage_drop의 설비 유형·회로 구분 판정 그대로)
+    if let Some(drop) = context.voltage_drop.as_ref().filter(|drop| !drop.within_limit) {
+        deductions.push((
+            format!("전압강하 {:.2}% (허용 {:.1}% 초과)", drop.total_drop_percent, drop.limit_percent),
+            SCORE_VOLTAGE_DROP_EXCEEDED,
+        ));
+    }
+
+    // 케이블 종류별 허용 곡률 (경로 굴곡 반경이 최소 굽힘 반경보다 작으면 감점)
+    if let Some(bend_radius) = context.bend_radius_mm {
+        if result.min_bending_radius_mm > 0.0 && bend_radius < result.min_bending_radius_mm {
+            deductions.push((
+                format!("경로 굴곡 반경 {:.0}mm < 최소 굽힘 반경 {:.0}mm", bend_radius, result.min_bending_radius_mm),
+                SCORE_BEND_TOO_TIGHT,
+            ));
+        }
+    }
+
+    // 데이터 품질
+    if is_outer_diameter_approximated(&data.cable_type, &data.cores) {
+        deductions.push(("외경 근사값 사용 (제조사 규격표 확인 필요)".to_string(), SCORE_APPROX_DIAMETER));
+    }
+
+    let total_deduction: i32 = deductions.iter().map(|(_, d)| d).sum();
+    let score = (100 - total_deduction).clamp(0, 100) as u32;
+    let grade = if score >= 90 {
+        "적합"
+    } else if score >= SCORE_REVIEW_THRESHOLD {
+        "양호"
+    } else {
+        "재검토 권장"
+    };
+
+    SuitabilityScore {
+        score,
+        grade: grade.to_string(),
+        deductions,
+    }
+}
+
+/// 입력 조합의 종합 적합성 점수 (0~100, 전압강하·경로 굴곡 입력 없이)
+pub fn compute_suitability_score(data: &CableData, result: &CalculationResult) -> u32 {
+    evaluate_suitability(data, result, &SuitabilityContext::default()).score
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(cores: &str, system: &str) -> CableData {
+        CableData {
+            cable_type: "CV".to_string(),
+            cores: cores.to_string(),
+            size: "16".to_string(),
+            quantity: 1,
+            system: system.to_string(),
+            ground_wire: "없음".to_string(),
+            install_method: String::new(),
+            ..Default::default()
+        }
+    }
+
+    /// 전역 사용자 허용전류 테이블을 읽거나 바꾸는 테스트 직렬화
+    /// 가드가 해제될 때 (패닉 포함) 내장 테이블로 복귀
+    struct CustomTableGuard {
+        _lock: std::sync::MutexGuard<'static, ()>,
+    }
+
+    impl Drop for CustomTableGuard {
+        fn drop(&mut self) {
+            reset_current_table();
+        }
+    }
+
+    fn lock_custom_table() -> CustomTableGuard {
+        static LOCK: Mutex<()> = Mutex::new(());
+        CustomTableGuard { _lock: LOCK.lock().unwrap_or_else(|e| e.into_inner()) }
+    }
+
+    #[test]
+    fn loaded_conductors_for_each_combination() {
+        assert_eq!(determine_loaded_conductors("1C", "1Φ"), 2);
+        assert_eq!(determine_loaded_conductors("1C", "3Φ"), 3);
+        assert_eq!(determine_loaded_conductors("2C", "1Φ"), 2);
+        assert_eq!(determine_loaded_conductors("2C", "3Φ"), 2);
+        assert_eq!(determine_loaded_conductors("3C", "1Φ"), 2);
+        assert_eq!(determine_loaded_conductors("3C", "3Φ"), 3);
+        assert_eq!(determine_loaded_conductors("4C", "1Φ"), 2);
+        assert_eq!(determine_loaded_conductors("4C", "3Φ"), 3);
+    }
+
+    #[test]
+    fn contradictory_combinations_are_rejected() {
+        assert!(validate_combination("2C", "3Φ").is_err());
+        assert!(validate_combination("4C", "1Φ").is_err());
+        assert!(validate_combination("2C", "1Φ").is_ok());
+        assert!(validate_combination("4C", "3Φ").is_ok());
+        assert!(calculate_core(&sample("2C", "3Φ")).is_err());
+    }
+
+    #[test]
+    fn table_column_follows_loaded_conductors() {
+        // XLPE B2 16mm²: 2부하 91A, 3부하 82A
+        assert_eq!(calculate_core(&sample("2C", "1Φ")).unwrap().allowable_current, 91.0);
+        assert_eq!(calculate_core(&sample("3C", "1Φ")).unwrap().allowable_current, 91.0);
+        assert_eq!(calculate_core(&sample("4C", "3Φ")).unwrap().allowable_current, 82.0);
+    }
+
+    #[test]
+    fn suitability_deducts_for_overfill_and_mismatch() {
+        let mut data = sample("1C", "3Φ");
+        data.install_method = "B1".to_string();
+        data.quantity = 60;
+        let result = calculate_core(&data).unwrap();
+        // 입력 검증을 거치지 않은 조합(단심 + 다심 전용 B2)도 점수에서 감점
+        data.install_method = "B2".to_string();
+        let score = evaluate_suitability(&data, &result, &SuitabilityContext::default());
+        assert!(score.deductions.iter().any(|(_, d)| *d == SCORE_FILL_EXCEEDED));
+        assert!(score.deductions.iter().any(|(_, d)| *d == SCORE_METHOD_MISMATCH));
+        assert!(score.score < SCORE_REVIEW_THRESHOLD);
+        assert_eq!(score.grade, "재검토 권장");
+
+        let mut clean = sample("2C", "1Φ");
+        clean.size = "4".to_string();
+        let result = calculate_core(&clean).unwrap();
+        assert_eq!(compute_suitability_score(&clean, &result), 100);
+
+        // 전압강하 허용치 초과 -15, 경로 굴곡 반경 부족 -10
+        let input = VoltageDropInput {
+            length: 200.0,
+            voltage: 220.0,
+            current: Some(30.0),
+            power_factor: None,
+            transformer: None,
+            installation_type: None,
+            is_branch: false,
+        };
+        let drop = calculate_voltage_drop(&clean, &input).unwrap();
+        assert!(!drop.within_limit);
+        let context = SuitabilityContext {
+            voltage_drop: Some(drop),
+            bend_radius_mm: Some(result.min_bending_radius_mm - 1.0),
+        };
+        let score = evaluate_suitability(&clean, &result, &context);
+        assert_eq!(score.score, 100 - (SCORE_VOLTAGE_DROP_EXCEEDED + SCORE_BEND_TOO_TIGHT) as u32);
+        assert!(score.deductions.iter().any(|(reason, _)| reason.starts_with("전압강하")));
+        // 굴곡 반경이 충분하면 감점 없음
+        let roomy = SuitabilityContext { bend_radius_mm: Some(result.min_bending_radius_mm), ..Default::default() };
+        assert_eq!(evaluate_suitability(&clean, &result, &roomy).score, 100);
+    }
+
+    #[test]
+    fn conduit_recommendation_is_structured() {
+        let limit = Regulation::Kec.conduit_fill_limit(2);
+        let small = recommend_conduit(ConduitType::Steel, 50.0, limit, 0.0);
+        assert_eq!((small.nominal, small.name.as_str(), small.status.as_str()), (16, "C16 (16mm)", "정상"));
+        assert_eq!((small.kind, small.inner_diameter), (RacewayKind::Conduit, Some(15.8)));
+
+        // C104 내경 101mm: 31% 한계 약 2484mm², 전체 단면적 약 8012mm²
+        let split = recommend_conduit(ConduitType::Steel, 3000.0, limit, 0.0);
+        assert_eq!((split.nominal, split.status.as_str()), (104, "분할 필요"));
+        assert_eq!(split.name, "C104 이상 검토 필요");
+        assert_eq!(recommend_conduit(ConduitType::Steel, 9000.0, limit, 0.0).status, "최대 초과");
+    }
+
+    #[test]
+    fn sensitivity_matrix_rows_are_temps_and_columns_are_circuits() {
+        // XLPE B2 16mm² 3부하 82A, 90°C 절연
+        let matrix = sensitivity_analysis(&sample("3C", "3Φ"), &[40, 30, 95], &[2, 1]).unwrap();
+        assert_eq!(matrix.len(), 3);
+        assert_eq!(matrix[0], vec![82.0, 65.6]); // 30°C
+        assert!(matrix[1][0] < 82.0); // 40°C
+        assert_eq!(matrix[2], vec![0.0, 0.0]); // 95°C > 90°C
+    }
+
+    #[test]
+    fn every_error_key_has_both_translations() {
+        let keys = [
+            ERR_2C_IN_THREE_PHASE,
+            ERR_4C_IN_SINGLE_PHASE,
+            ERR_UNSUPPORTED_SIZE,
+            ERR_CURRENT_DATA_NOT_FOUND,
+            ERR_INVALID_VERTICAL_LENGTH,
+            ERR_INVALID_SIZE,
+            ERR_INVALID_INSULATION,
+            ERR_INVALID_INSTALL_METHOD,
+            ERR_INVALID_LOADED_CONDUCTORS,
+            ERR_INVALID_UNIT_SYSTEM,
+            ERR_INVALID_AWG,
+            ERR_INVALID_CURRENT_TABLE,
+            ERR_UNKNOWN_DATA_VERSION,
+            ERR_INVALID_TRAY_WIDTH,
+            ERR_CABLE_EXCEEDS_TRAY,
+            ERR_INVALID_VOLTAGE_DROP_INPUT,
+            ERR_INVALID_TRANSFORMER,
+            ERR_UNKNOWN_CABLE_TYPE,
+            ERR_BINARY_VERSION_MISMATCH,
+            ERR_BINARY_DECODE,
+            ERR_PROJECT_CORRUPT,
+            ERR_PROJECT_VERSION_UNSUPPORTED,
+            ERR_INVALID_LOAD_POWER,
+            ERR_UNKNOWN_ZONE,
+            ERR_INVALID_BRANCH_LOAD,
+            ERR_UNKNOWN_REGULATION,
+            ERR_INVALID_FILL_LIMIT,
+            ERR_CABLE_EXCEEDS_CONDUIT,
+            ERR_CIRCUIT_COUNT_NOT_EXACT,
+            ERR_INVALID_MATERIAL,
+            ERR_INVALID_DURATION,
+            ERR_UNKNOWN_INSTALLATION_TYPE,
+            ERR_INVALID_CHUNK_SIZE,
+            ERR_UNKNOWN_USAGE,
+            ERR_BELOW_MINIMUM_SIZE,
+            ERR_INVALID_TERMINATION_TEMP,
+            ERR_INVALID_ENTRY_TYPE,
+            ERR_EMPTY_CABLE_LIST,
+            ERR_AMBIENT_TEMP_OUT_OF_RANGE,
+            ERR_INVALID_LOAD_CURRENT,
+            ERR_PARALLEL_CONDUCTORS_REQUIRED,
+            ERR_INVALID_FAULT_CURRENT,
+            ERR_EXPORT_LENGTH_MISMATCH,
+            ERR_INVALID_BURIAL_DEPTH,
+            ERR_INVALID_SOIL_RESISTIVITY,
+            ERR_INVALID_HARMONIC_PERCENT,
+            ERR_INVALID_PARALLEL_RUNS,
+            ERR_INVALID_QUANTITY,
+            ERR_INVALID_CORES,
+            ERR_INSTALL_METHOD_CORES_MISMATCH,
+            ERR_CABLE_UNDERSIZED_FOR_BREAKER,
+            ERR_INVALID_OUTER_DIAMETER,
+            ERR_CORES_IN_DC,
+            ERR_TRANSFORMER_IN_DC,
+            ERR_UNKNOWN_CONDUIT,
+            ERR_INVALID_PULL_SECTION,
+        ];
+        assert_eq!(ERROR_MESSAGES.len(), keys.len());
+        for key in keys {
+            let ko = localize_error(key, "ko");
+            let en = localize_error(key, "en");
+            assert_ne!(ko, key, "한국어 메시지 누락: {}", key);
+            assert_ne!(en, key, "영어 메시지 누락: {}", key);
+            assert_ne!(ko, en);
+        }
+    }
+
+    #[test]
+    fn calculate_errors_follow_requested_language() {
+        let mut data = sample("2C", "3Φ");
+        assert_eq!(calculate_core(&data).unwrap_err(), localize_error(ERR_2C_IN_THREE_PHASE, "ko"));
+        data.lang = Some("en".to_string());
+        assert_eq!(calculate_core(&data).unwrap_err(), localize_error(ERR_2C_IN_THREE_PHASE, "en"));
+        // 지원하지 않는 언어는 영어로 폴백
+        data.lang = Some("ja".to_string());
+        assert_eq!(calculate_core(&data).unwrap_err(), localize_error(ERR_2C_IN_THREE_PHASE, "en"));
+    }
+
+    #[test]
+    fn vertical_run_suggests_supports_when_exceeded() {
+        let data = sample("1C", "3Φ");
+        let skipped = check_vertical_run(&data, 0.0).unwrap();
+        assert!(!skipped.checked);
+
+        let limit = skipped.max_vertical_length;
+        let within = check_vertical_run(&data, limit * 0.5).unwrap();
+        assert!(within.checked && !within.exceeded);
+        assert_eq!(within.support_count, 0);
+
+        let over = check_vertical_run(&data, limit * 3.0).unwrap();
+        assert!(over.exceeded);
+        let spacing = over.support_spacing.unwrap();
+        assert!(spacing <= VERTICAL_SUPPORT_SPACING_MAX);
+        assert!(spacing * (over.support_count + 1) as f64 >= over.vertical_length);
+
+        // 내화 케이블은 같은 규격에서 허용 수직장이 더 짧음
+        let mut fr = data.clone();
+        fr.cable_type = "FR-CV".to_string();
+        assert!(check_vertical_run(&fr, 0.0).unwrap().max_vertical_length < limit);
+        assert!(check_vertical_run(&data, -1.0).is_err());
+    }
+
+    #[test]
+    fn pulling_tension_follows_capstan_model() {
+        let data = sample("3C", "3Φ");
+        let weight = get_cable_weight("CV", "16", "3C").unwrap();
+        let route = [
+            PullSection::Straight { length: 20.0 },
+            PullSection::Bend { angle_deg: 90.0 },
+            PullSection::Vertical { length: 5.0 },
+        ];
+        let result = calculate_pulling_tension(&data, &route, Some(0.4)).unwrap();
+        // 직선 W·μ·L → 굴곡 × e^(0.4 × π/2) → 수직 + W·L
+        let straight = weight * 0.4 * 20.0;
+        let bend = straight * (0.4 * std::f64::consts::FRAC_PI_2).exp();
+        let expected = bend + weight * 5.0;
+        assert_eq!(result.section_tensions.len(), 3);
+        assert!((result.total_tension - expected).abs() < 0.06, "{} {}", result.total_tension, expected);
+        // 도체 허용 장력 7kgf/mm² × 16mm² × 3심
+        assert_eq!(result.allowable_tension, 336.0);
+        assert!(!result.exceeded);
+
+        // 굴곡은 입구 장력에 곱해지므로 순서가 결과를 바꿈 (굴곡 먼저면 0 × e^(μθ) = 0)
+        let bend_first = [PullSection::Bend { angle_deg: 90.0 }, PullSection::Straight { length: 20.0 }];
+        assert!((calculate_pulling_tension(&data, &bend_first, Some(0.4)).unwrap().total_tension - straight).abs() < 0.06);
+
+        let long = [PullSection::Straight { length: 2000.0 }, PullSection::Bend { angle_deg: 180.0 }];
+        assert!(calculate_pulling_tension(&data, &long, None).unwrap().exceeded);
+        assert_eq!(
+            calculate_pulling_tension(&data, &[PullSection::Bend { angle_deg: 400.0 }], None).unwrap_err(),
+            localize_error(ERR_INVALID_PULL_SECTION, "ko")
+        );
+        assert!(calculate_pulling_tension(&data, &route, Some(0.0)).is_err());
+    }
+
+    #[test]
+    fn string_keys_convert_to_typed_keys() {
+        assert_eq!(InstallMethod::try_from("D2"), Ok(InstallMethod::D2));
+        assert_eq!(InstallMethod::try_from("B3"), Err(ERR_INVALID_INSTALL_METHOD));
+        assert_eq!(Insulation::try_from("XLPE"), Ok(Insulation::Xlpe));
+        assert_eq!(Insulation::try_from("EPR"), Err(ERR_INVALID_INSULATION));
+        assert_eq!(Size::try_from("2.5").map(Size::mm2), Ok(2.5));
+        assert_eq!(Size::try_from("2.50"), Err(ERR_INVALID_SIZE));
+
+        // 가능 규격 안내는 STANDARD_SIZES 전체 (630~1000 포함)
+        let message = localize_error(ERR_INVALID_SIZE, "en");
+        assert_eq!(message, format!("Unknown size. (valid: {})", STANDARD_SIZES.join(", ")));
+        assert!(message.ends_with("500, 630, 800, 1000)"));
+        assert!(localize_error(ERR_INVALID_INSTALL_METHOD, "ko").contains("A1, A2, B1, B2, C, D1, D2, E, F"));
+
+        let mut data = sample("3C", "3Φ");
+        data.install_method = "B3".to_string();
+        assert_eq!(calculate_core(&data).unwrap_err(), localize_error(ERR_INVALID_INSTALL_METHOD, "ko"));
+    }
+
+    #[test]
+    fn markdown_export_escapes_and_flags_overload() {
+        assert_eq!(export_results_markdown(&[]).lines().count(), 2);
+
+        let mut data = sample("3C", "3Φ");
+        data.cable_type = "CV|X".to_string();
+        data.load_current = Some(100.0);
+        let mut result = calculate_core(&sample("3C", "3Φ")).unwrap();
+        result.allowable_current = 82.0;
+        let table = export_results_markdown(&[(data, result)]);
+        let row = table.lines().nth(2).unwrap();
+        assert!(row.starts_with("| CV\\|X | 3C | 16 |"));
+        assert!(row.contains("| 100.0 | 82.0 ⚠️ |"));
+        assert!(row.ends_with("| 미지정 | 미지정 |"));
+
+        let mut tracked = sample("3C", "3Φ");
+        tracked.manufacturer = Some("LS전선".to_string());
+        tracked.model = Some(" ".to_string());
+        let result = calculate_core(&tracked).unwrap();
+        assert_eq!(result.allowable_current, calculate_core(&sample("3C", "3Φ")).unwrap().allowable_current);
+        let table = export_results_markdown(&[(tracked, result)]);
+        assert!(table.lines().nth(2).unwrap().ends_with("| LS전선 | 미지정 |"));
+
+        // 셀 안의 CR·CRLF도 행을 나누지 않음
+        assert_eq!(escape_markdown_cell("A\r\nB\rC\nD"), "A B C D");
+    }
+
+    #[test]
+    fn xlpe_ratio_over_pvc() {
+        // B1 2.5mm² 2부하: PVC 24A, XLPE 31A -> +29.2%
+        assert_eq!(insulation_current_ratio("2.5", "B1", 2, "ko"), Ok(29.2));
+        assert!(insulation_current_ratio("2.5", "B1", 4, "ko").is_err());
+        assert!(insulation_current_ratio("2.5", "G", 2, "ko").is_err());
+        assert_eq!(
+            insulation_current_ratio("2.5", "B1", 4, "en"),
+            Err(localize_error(ERR_INVALID_LOADED_CONDUCTORS, "en"))
+        );
+        let average = average_insulation_current_ratio("C", 3, "ko").unwrap();
+        assert!(average > 0.0 && average < 50.0);
+    }
+
+    #[test]
+    fn awg_round_trip_is_consistent() {
+        for (label, area) in AWG_SIZES {
+            assert_eq!(awg_to_mm2(label), Some(*area));
+            assert_eq!(mm2_to_awg(*area), Some(*label));
+        }
+        assert_eq!(awg_to_mm2("4/0"), Some(107.2));
+        assert_eq!(awg_to_mm2("250 MCM"), Some(126.7));
+        assert_eq!(mm2_to_awg(16.0), Some("6 AWG"));
+        // AWG 단면적 이상 표준 규격으로 올림: 4 AWG(21.2mm²) -> 25mm², 6 AWG(13.3mm²) -> 16mm²
+        assert_eq!(awg_to_standard_size("4"), Some("25"));
+        assert_eq!(awg_to_standard_size("6"), Some("16"));
+        assert_eq!(awg_to_standard_size("16"), None);
+        // 모든 AWG 규격은 단면적이 줄지 않는 표준 규격으로 매핑
+        assert!(AWG_SIZES.iter().all(|(label, area)| Size(awg_to_standard_size(label).unwrap()).mm2() >= *area));
+    }
+
+    #[test]
+    fn imperial_mode_wraps_metric_calculation() {
+        let metric = calculate_core(&sample("3C", "3Φ")).unwrap();
+        let mut data = sample("3C", "3Φ");
+        data.size = "6".to_string();
+        data.unit_system = Some("imperial".to_string());
+        let imperial = calculate_core(&data).unwrap();
+        assert_eq!(imperial.allowable_current, metric.allowable_current);
+        assert_eq!(imperial.size_label, "6 AWG (16 mm² 적용)");
+        // 케이블 1본(53% 상한): C36 -> 1-1/4"
+        assert_eq!(imperial.recommended_raceway.name, format!("1-1/4\" ({})", metric.recommended_raceway.name));
+        assert_eq!(metric.size_label, "16 mm²");
+
+        data.unit_system = Some("cubits".to_string());
+        assert_eq!(calculate_core(&data).unwrap_err(), localize_error(ERR_INVALID_UNIT_SYSTEM, "ko"));
+    }
+
+    #[test]
+    fn custom_table_invalidates_cached_results() {
+        let _table = lock_custom_table();
+        // 다른 테스트와 겹치지 않도록 XLPE D2 300mm²만 변경
+        let mut data = sample("3C", "3Φ");
+        data.size = "300".to_string();
+        data.install_method = "D2".to_string();
+        let builtin = calculate_cached(&data).unwrap();
+        assert_eq!(builtin.allowable_current, 436.0);
+        let checksum = get_data_checksum();
+
+        let json = r#"[{"size":"300","insulation":"XLPE","method":"D2","loaded_2":600,"loaded_3":520}]"#;
+        assert_eq!(load_custom_current_table(json, "ko"), Ok(1));
+        assert_ne!(get_data_checksum(), checksum);
+        let custom = calculate_cached(&data).unwrap();
+        assert_eq!(custom.allowable_current, 520.0);
+        assert_eq!(custom.reference, "사용자 정의 허용전류 테이블 (IEC 60364-5-52 Table B.52.5 / KEC 232 대체)");
+
+        reset_current_table();
+        assert_eq!(get_data_checksum(), checksum);
+        assert_eq!(calculate_cached(&data).unwrap().allowable_current, 436.0);
+        assert_eq!(calculate_cached(&data).unwrap().reference, "IEC 60364-5-52 Table B.52.5 / KEC 232");
+        assert!(invalidate_cache() >= 1);
+
+        assert!(load_custom_current_table(r#"[{"size":"17","insulation":"XLPE","method":"D2","loaded_2":1,"loaded_3":1}]"#, "ko").is_err());
+        assert_eq!(load_custom_current_table("not json", "en"), Err(localize_error(ERR_INVALID_CURRENT_TABLE, "en")));
+    }
+
+    #[test]
+    fn cable_types_match_insulation_temperature() {
+        for info in get_cable_types() {
+            let insulation = get_insulation_type(&info.code);
+            assert_eq!(insulation.as_str(), info.insulation, "{}", info.code);
+            assert_eq!(get_max_conductor_temp(insulation), info.max_temp, "{}", info.code);
+        }
+        assert_eq!(get_max_conductor_temp(get_insulation_type("HFIX")), 90);
+        assert_eq!(get_max_conductor_temp(Insulation::Pvc), 70);
+
+        let result = calculate_core(&sample("3C", "3Φ")).unwrap();
+        assert_eq!(result.conductor_max_temp, 90);
+        assert!(result.install_method_desc.ends_with("90°C 절연 기준"));
+    }
+
+    #[test]
+    fn tray_layout_packs_within_fill_and_circuit_limits() {
+        let mut large = sample("3C", "3Φ");
+        large.size = "240".to_string();
+        large.quantity = 4;
+        let mut huge = sample("1C", "3Φ");
+        huge.size = "500".to_string();
+        huge.quantity = 300;
+        let mut cables = vec![large, huge, sample("2C", "3Φ")];
+        cables.extend(std::iter::repeat_n(sample("3C", "3Φ"), 10));
+
+        let plan = optimize_tray_layout(&cables, &[600.0, 300.0, 200.0]).unwrap();
+        let placed: usize = plan.trays.iter().map(|t| t.cables.len()).sum();
+        assert_eq!(placed, 11);
+        let unplaced: Vec<usize> = plan.unplaced.iter().map(|(i, _)| *i).collect();
+        assert_eq!(unplaced, vec![1, 2]);
+        for tray in &plan.trays {
+            assert!(tray.occupied_width <= tray.width * TRAY_WIDTH_FILL_LIMIT);
+            assert!(tray.fill_rate <= TRAY_WIDTH_FILL_LIMIT * 100.0);
+            assert!(tray.circuits <= max_circuits_per_tray());
+            assert!(tray.grouping_factor >= TRAY_MIN_GROUPING_FACTOR);
+        }
+        // 11회로 / 단당 9회로 -> 2단
+        assert_eq!(plan.trays.len(), 2);
+
+        assert!(optimize_tray_layout(&cables, &[]).is_err());
+        assert!(optimize_tray_layout(&cables, &[300.0, 0.0]).is_err());
+        let english: Vec<CableData> = cables.iter().map(|c| CableData { lang: Some("en".to_string()), ..c.clone() }).collect();
+        assert_eq!(optimize_tray_layout(&english, &[]).unwrap_err(), localize_error(ERR_INVALID_TRAY_WIDTH, "en"));
+    }
+
+    #[test]
+    fn partial_recalculation_matches_full() {
+        let base = sample("3C", "3Φ");
+        let base_result = calculate_core(&base).unwrap();
+        let with = |change: fn(&mut CableData)| {
+            let mut data = base.clone();
+            change(&mut data);
+            data
+        };
+        let variants: Vec<(CableData, &[&str])> = vec![
+            (with(|d| d.ground_wire = "HFIX".to_string()), &["점유율(전선관)", "물량(길이)"]),
+            (with(|d| d.install_method = "C".to_string()), &["허용전류"]),
+            (with(|d| d.system = "1Φ".to_string()), &["허용전류"]),
+            (with(|d| d.quantity = 3), &["점유율(전선관)", "허용전류", "물량(길이)"]),
+            (with(|d| d.length = 120.0), &["물량(길이)"]),
+            (with(|d| d.load_current = Some(50.0)), &[]),
+        ];
+        for (new_data, expected) in variants {
+            let partial = recalculate_partial(&base, &base_result, &new_data).unwrap();
+            let mut full = calculate_core(&new_data).unwrap();
+            // 계산 시각은 호출마다 다를 수 있음
+            full.data_provenance.calculated_at = partial.result.data_provenance.calculated_at.clone();
+            assert_eq!(format!("{:?}", partial.result), format!("{:?}", full));
+            assert_eq!(partial.recalculated, expected);
+            assert_eq!(partial.recalculated.len() + partial.skipped.len(), 3);
+        }
+
+        let mut invalid = base.clone();
+        invalid.cores = "2C".to_string();
+        assert!(recalculate_partial(&base, &base_result, &invalid).is_err());
+    }
+
+    #[test]
+    fn voltage_drop_separates_transformer_contribution() {
+        // CV 3C 16mm² 3상 380V, 100m, 50A, 역률 0.9
+        // R = 1/58 / 16 × 1.2751 × 1000 × 0.1km = 0.1374Ω, X = 0.008Ω
+        // ΔV = √3 × 50 × (0.1374 × 0.9 + 0.008 × 0.4359) = 11.01V
+        let data = sample("3C", "3Φ");
+        let mut input = VoltageDropInput {
+            length: 100.0,
+            current: Some(50.0),
+            voltage: 380.0,
+            power_factor: None,
+            transformer: None,
+            installation_type: None,
+            is_branch: false,
+        };
+        let cable_only = calculate_voltage_drop(&data, &input).unwrap();
+        assert_eq!(cable_only.cable_drop, 11.01);
+        assert_eq!(cable_only.transformer_drop, 0.0);
+
+        // 500kVA %Z 5%: Z = 0.05 × 380² / 500000 = 0.01444Ω (X/R 5)
+        // ΔV_tr = √3 × 50 × (0.002832 × 0.9 + 0.01416 × 0.4359) = 0.76V
+        input.transformer = Some(TransformerInfo { kva: 500.0, impedance_percent: 5.0, x_r_ratio: None });
+        let with_tr = calculate_voltage_drop(&data, &input).unwrap();
+        assert_eq!(with_tr.cable_drop, cable_only.cable_drop);
+        assert_eq!(with_tr.transformer_drop, 0.76);
+        assert_eq!(with_tr.total_drop, 11.77);
+
+        input.transformer = Some(TransformerInfo { kva: 0.0, impedance_percent: 5.0, x_r_ratio: None });
+        assert!(calculate_voltage_drop(&data, &input).is_err());
+        input.transformer = None;
+        input.current = None;
+        assert!(calculate_voltage_drop(&data, &input).is_err());
+    }
+
+    #[test]
+    fn bulk_current_preserves_order_and_reports_errors() {
+        let types: Vec<String> = ["TFR-CV", "XYZ", "HFIX", "CV"].iter().map(|t| t.to_string()).collect();
+        let results = bulk_current_by_type(&types, "16", "B2", "3Φ", "ko");
+        let order: Vec<&str> = results.iter().map(|(t, _)| t.as_str()).collect();
+        assert_eq!(order, vec!["TFR-CV", "XYZ", "HFIX", "CV"]);
+        // 모두 XLPE -> B2 16mm² 3부하 82A
+        assert_eq!(results[0].1, Ok(82.0));
+        assert_eq!(results[1].1, Err(localize_error(ERR_UNKNOWN_CABLE_TYPE, "ko")));
+        assert_eq!(results[2].1, results[0].1);
+        assert_eq!(results[3].1, results[0].1);
+
+        let invalid = bulk_current_by_type(&types[..1], "17", "B2", "1Φ", "en");
+        assert_eq!(invalid[0].1, Err(localize_error(ERR_INVALID_SIZE, "en")));
+    }
+
+    #[test]
+    fn size_suggestions_rank_prefix_then_nearest() {
+        assert_eq!(suggest_sizes("2"), vec!["2.5", "25", "240", "1.5"]);
+        assert_eq!(suggest_sizes("3"), vec!["35", "300", "2.5", "4"]);
+        assert_eq!(suggest_sizes("16"), vec!["16", "10"]);
+        assert_eq!(suggest_sizes("2.6"), vec!["2.5", "1.5"]);
+        assert_eq!(suggest_sizes("1").len(), MAX_SIZE_SUGGESTIONS);
+        assert_eq!(suggest_sizes(" ").len(), STANDARD_SIZES.len());
+        assert!(suggest_sizes("abc").is_empty());
+    }
+
+    #[test]
+    fn binary_round_trip_checks_schema_version() {
+        let mut data = sample("3C", "3Φ");
+        data.load_current = Some(42.5);
+        let result = calculate_core(&data).unwrap();
+
+        let bytes = serialize_result_binary(&result);
+        assert_eq!(bytes[0], BINARY_SCHEMA_VERSION);
+        let decoded = deserialize_result_binary(&bytes, "ko").unwrap();
+        assert_eq!(format!("{:?}", decoded), format!("{:?}", result));
+
+        let data_bytes = serialize_data_binary(&data);
+        assert_eq!(format!("{:?}", deserialize_data_binary(&data_bytes, "ko").unwrap()), format!("{:?}", data));
+
+        let mut stale = bytes.clone();
+        stale[0] = BINARY_SCHEMA_VERSION + 1;
+        assert_eq!(deserialize_result_binary(&stale, "ko").unwrap_err(), localize_error(ERR_BINARY_VERSION_MISMATCH, "ko"));
+        assert_eq!(deserialize_result_binary(&[], "ko").unwrap_err(), localize_error(ERR_BINARY_DECODE, "ko"));
+        assert_eq!(deserialize_data_binary(&[], "en").unwrap_err(), localize_error(ERR_BINARY_DECODE, "en"));
+        assert!(deserialize_result_binary(&bytes[..bytes.len() / 2], "ko").is_err());
+    }
+
+    #[test]
+    fn core_colors_follow_kec_identification() {
+        let colors = |t, c| get_core_colors(t, c).into_iter().map(|(color, _)| color).collect::<Vec<_>>();
+        assert_eq!(colors("CV", "3C"), vec!["갈색", "흑색", "회색"]);
+        assert_eq!(colors("FR-CV", "4C"), vec!["갈색", "흑색", "회색", "청색"]);
+        assert_eq!(get_core_colors("TFR-CV", "2C")[1], ("청색".to_string(), "중성(N)".to_string()));
+        assert_eq!(get_core_colors("HFIX", "1C").len(), 1);
+        assert!(get_core_colors("HFIX", "3C").is_empty());
+        assert!(get_core_colors("XYZ", "1C").is_empty());
+    }
+
+    #[test]
+    fn derating_with_unit_factors_keeps_base_current() {
+        // XLPE B2 16mm² 3부하 82A, 30°C·1회로 -> 모든 계수 1.0
+        assert_eq!(apply_derating(Amperes(82.0), &[]), Amperes(82.0));
+        assert_eq!(apply_derating(Amperes(82.0), &[1.0, 1.0]), Amperes(82.0));
+        assert_eq!(temperature_factor(Insulation::Xlpe, 30, false), Ok(1.0));
+        assert_eq!(get_grouping_factor(1, Arrangement::Conduit), 1.0);
+        assert_eq!(calculate_core(&sample("3C", "3Φ")).unwrap().allowable_current, 82.0);
+        assert_eq!(sensitivity_analysis(&sample("3C", "3Φ"), &[30], &[1]).unwrap(), vec![vec![82.0]]);
+    }
+
+    #[test]
+    fn derating_factors_multiply_independently() {
+        // 40°C: 0.91 (Table B.52.14 XLPE), 2회로: 0.80
+        let temp_factor = temperature_factor(Insulation::Xlpe, 40, false).unwrap();
+        let both = apply_derating(Amperes(82.0), &[temp_factor, 0.8]);
+        assert!((both - apply_derating(Amperes(82.0), &[0.8, temp_factor])).value().abs() < 1e-9);
+        assert!((both.value() - 82.0 * temp_factor * 0.8).abs() < 1e-9);
+
+        // 82 × 0.91 = 74.6A, 82 × 0.91 × 0.8 = 59.7A
+        let matrix = sensitivity_analysis(&sample("3C", "3Φ"), &[40], &[1, 2]).unwrap();
+        assert_eq!(matrix, vec![vec![74.6, 59.7]]);
+        // 도체 최고온도(90°C) 이상은 0, 보정표 범위 밖(85°C)은 에러
+        assert_eq!(sensitivity_analysis(&sample("3C", "3Φ"), &[90], &[1]).unwrap(), vec![vec![0.0]]);
+        assert!(sensitivity_analysis(&sample("3C", "3Φ"), &[85], &[1]).is_err());
+    }
+
+    #[test]
+    fn grouping_factor_is_applied_once() {
+        // 3C 2조 = 2회로 -> 82 × 0.80 = 65.6A (0.80² 중복 적용 시 52.5A)
+        let mut data = sample("3C", "3Φ");
+        data.quantity = 2;
+        assert_eq!(calculate_core(&data).unwrap().allowable_current, 65.6);
+
+        // 1C 3가닥(3상) = 1회로 -> 보정 없음
+        let mut single = sample("1C", "3Φ");
+        single.quantity = 3;
+        single.install_method = "B1".to_string();
+        let result = calculate_core(&single).unwrap();
+        assert!(result.install_method_desc.contains("집합계수: 1.00 (1회로)"));
+    }
+
+    #[test]
+    fn buried_methods_use_ground_reference_temperature() {
+        // XLPE D1 16mm² 3부하 77A: 지중 기준 20°C -> 계수 1.0
+        let mut buried = sample("3C", "3Φ");
+        buried.install_method = "D1".to_string();
+        assert_eq!(sensitivity_analysis(&buried, &[20], &[1]).unwrap(), vec![vec![77.0]]);
+        // 지중 30°C: 0.93 (Table B.52.15 XLPE) -> 71.6A (공기 기준이면 77A 그대로)
+        assert_eq!(sensitivity_analysis(&buried, &[30], &[1]).unwrap(), vec![vec![71.6]]);
+
+        // 공기 중 20°C는 기준(30°C)보다 낮아 계수 > 1
+        assert!(temperature_factor(Insulation::Xlpe, 20, false).unwrap() > 1.0);
+        assert_eq!(temperature_factor(Insulation::Xlpe, 20, true), Ok(1.0));
+
+        // 기준온도: 지중 D1/D2만 20°C, 나머지 30°C, 기준온도에서는 보정표 계수 1.0
+        for method in InstallMethod::ALL {
+            let expected = if matches!(method, InstallMethod::D1 | InstallMethod::D2) { 20 } else { 30 };
+            assert_eq!(base_ambient_temp(method), expected, "{:?}", method);
+            for insulation in Insulation::ALL {
+                let reference = base_ambient_temp(method);
+                assert_eq!(temperature_factor(insulation, reference, method.is_buried()), Ok(1.0));
+            }
+        }
+    }
+
+    #[test]
+    fn project_file_round_trip_and_version_check() {
+        let metadata = ProjectMetadata {
+            name: "A동 전기설비".to_string(),
+            author: "설계팀".to_string(),
+            description: String::new(),
+        };
+        let circuits = vec![sample("3C", "3Φ"), sample("1C", "1Φ"), sample("4C", "3Φ")];
+        let settings = HashMap::from([("default_method".to_string(), "B2".to_string())]);
+        let json = export_project_file(metadata.clone(), circuits, settings).unwrap();
+
+        let project = import_project_file(&json, "ko").unwrap();
+        assert_eq!(project.schema_version, PROJECT_SCHEMA_VERSION);
+        assert!(project.created_at > 0);
+        assert_eq!(project.circuits.len(), 3);
+        assert_eq!(project.metadata, metadata);
+        assert_eq!(project.settings["default_method"], "B2");
+
+        let newer = json.replacen("\"schema_version\": 1", "\"schema_version\": 99", 1);
+        assert_eq!(import_project_file(&newer, "ko").unwrap_err(), localize_error(ERR_PROJECT_VERSION_UNSUPPORTED, "ko"));
+        assert_eq!(import_project_file(&json[..json.len() / 2], "ko").unwrap_err(), localize_error(ERR_PROJECT_CORRUPT, "ko"));
+        assert_eq!(import_project_file("{\"circuits\": []}", "ko").unwrap_err(), localize_error(ERR_PROJECT_CORRUPT, "ko"));
+        assert_eq!(import_project_file("{\"circuits\": []}", "en").unwrap_err(), localize_error(ERR_PROJECT_CORRUPT, "en"));
+        // 내장 데이터셋과 다른 버전으로 작성된 파일은 거부
+        let older = json.replacen(&format!("\"data_version\": \"{}\"", DATA_VERSION), "\"data_version\": \"2021\"", 1);
+        assert_eq!(import_project_file(&older, "ko").unwrap_err(), localize_error(ERR_UNKNOWN_DATA_VERSION, "ko"));
+    }
+
+    #[test]
+    fn lower_power_factor_needs_larger_size() {
+        // 30kW 3상 380V, CV(XLPE) B1 3부하: 6mm² 48A, 10mm² 66A, 16mm² 88A, 25mm² 117A
+        let rows = size_vs_power_factor(30.0, &[1.0, 0.9, 1.2, 0.6, 0.5, 0.0], "3Φ", 380.0, "CV", "B1", "ko").unwrap();
+        let sizes: Vec<(f64, &str)> = rows.iter().map(|(pf, _, size)| (*pf, size.as_str())).collect();
+        assert_eq!(sizes, vec![(1.0, "6"), (0.9, "10"), (0.6, "16"), (0.5, "25")]);
+        assert_eq!(rows[0].1, 45.6);
+
+        let huge = size_vs_power_factor(1000.0, &[0.8], "3Φ", 380.0, "CV", "B1", "ko").unwrap();
+        assert_eq!(huge[0].2, "500 초과 (병렬 검토)");
+        let huge = size_vs_power_factor(1000.0, &[0.8], "3Φ", 380.0, "CV", "B1", "en").unwrap();
+        assert_eq!(huge[0].2, "over 500 (consider parallel runs)");
+        assert!(size_vs_power_factor(0.0, &[0.9], "3Φ", 380.0, "CV", "B1", "ko").is_err());
+        assert_eq!(
+            size_vs_power_factor(0.0, &[0.9], "3Φ", 380.0, "CV", "B1", "en"),
+            Err(localize_error(ERR_INVALID_LOAD_POWER, "en"))
+        );
+        assert!(size_vs_power_factor(30.0, &[0.9], "3Φ", 380.0, "XYZ", "B1", "ko").is_err());
+    }
+
+    #[test]
+    fn total_cable_length_counts_strands_and_ground() {
+        // 3C 2조 50m, 접지선 HFIX -> 케이블 100m, 접지선 50m
+        let mut multi = sample("3C", "3Φ");
+        multi.quantity = 2;
+        multi.length = 50.0;
+        multi.ground_wire = "HFIX".to_string();
+        let result = calculate_core(&multi).unwrap();
+        assert_eq!((result.total_cable_length, result.ground_wire_length), (100.0, 50.0));
+
+        // 1C 3상 2회로(6가닥) 30m -> 180m, 접지선 없음
+        let mut single = sample("1C", "3Φ");
+        single.quantity = 6;
+        single.length = 30.0;
+        single.install_method = "B1".to_string();
+        let result = calculate_core(&single).unwrap();
+        assert_eq!((result.total_cable_length, result.ground_wire_length), (180.0, 0.0));
+
+        // 2런 병렬은 런마다 접지선 1가닥 -> 케이블 200m, 접지선 100m
+        multi.parallel_runs = 2;
+        let result = calculate_core(&multi).unwrap();
+        assert_eq!((result.total_cable_length, result.ground_wire_length), (200.0, 100.0));
+
+        // 길이 미입력 -> 0
+        let result = calculate_core(&sample("3C", "3Φ")).unwrap();
+        assert_eq!((result.total_cable_length, result.ground_wire_length), (0.0, 0.0));
+    }
+
+    #[test]
+    fn effective_area_reflects_packing_voids() {
+        let circle = calculate_cable_area(20.0).value();
+        assert_eq!(effective_occupied_area(&[(20.0, 1)]), circle);
+        assert_eq!(effective_occupied_area(&[]), 0.0);
+
+        // 동일 외경 3가닥: 단순 합 대비 1/0.9069 ≈ 10.3% 증가
+        let uniform = effective_occupied_area(&[(20.0, 3)]);
+        assert!((uniform - 3.0 * circle / UNIFORM_PACKING_DENSITY).abs() < 1e-9);
+        assert!(uniform > 3.0 * circle * 1.10);
+
+        // 혼합 외경은 더 보수적 (1/0.8 = 25% 증가)
+        let mixed_sum = 2.0 * circle + calculate_cable_area(10.0).value();
+        let mixed = effective_occupied_area(&[(20.0, 2), (10.0, 1)]);
+        assert!((mixed - mixed_sum / MIXED_PACKING_DENSITY).abs() < 1e-9);
+
+        // 계산 결과: 실효 면적은 참고값, 점유율은 상한과 같은 단순 합 기준 (공극 이중 반영 없음)
+        let mut data = sample("3C", "3Φ");
+        data.quantity = 3;
+        let result = calculate_core(&data).unwrap();
+        assert!(result.effective_area > result.total_area);
+        let inner = result.recommended_raceway.inner_diameter.unwrap();
+        let expected = result.total_area / calculate_cable_area(inner).value() * 100.0;
+        assert!((result.fill_rate - expected).abs() < 0.1);
+    }
+
+    #[test]
+    fn every_result_field_has_a_unit() {
+        let result = calculate_core(&sample("3C", "3Φ")).unwrap();
+        let value = serde_json::to_value(&result).unwrap();
+        let mut fields = Vec::new();
+        for (key, child) in value.as_object().unwrap() {
+            fields.push(key.clone());
+            if let Some(nested) = child.as_object() {
+                fields.extend(nested.keys().map(|k| format!("{}.{}", key, k)));
+            }
+        }
+        let units = result_field_units();
+        for field in &fields {
+            assert!(units.contains_key(field), "단위 누락: {}", field);
+        }
+        assert_eq!(units.len(), fields.len(), "존재하지 않는 필드의 단위가 있음");
+        assert_eq!(units["allowable_current"], "A");
+        assert_eq!(units["total_area"], "mm²");
+        assert_eq!(units["fill_rate"], "%");
+    }
+
+    #[test]
+    fn fire_compliance_by_zone() {
+        let emergency = check_fire_compliance("CV", "비상전원", "ko").unwrap();
+        assert!(!emergency.compliant);
+        assert_eq!(emergency.alternatives, vec!["FR-CV"]);
+        assert!(check_fire_compliance("FR-CV", "비상전원", "ko").unwrap().compliant);
+
+        let general = check_fire_compliance("CV", "일반", "ko").unwrap();
+        assert!(general.compliant);
+        assert_eq!(general.alternatives.len(), get_cable_types().len());
+
+        assert!(check_fire_compliance("HFIX", "방재", "ko").unwrap().compliant);
+        assert!(!check_fire_compliance("HFIX", "피난", "ko").unwrap().compliant);
+        assert!(check_fire_compliance("TFR-8", "피난", "ko").unwrap().compliant);
+        assert_eq!(check_fire_compliance("CV", "옥상", "ko").unwrap_err(), localize_error(ERR_UNKNOWN_ZONE, "ko"));
+        assert_eq!(check_fire_compliance("CV", "옥상", "en").unwrap_err(), localize_error(ERR_UNKNOWN_ZONE, "en"));
+        assert!(check_fire_compliance("XYZ", "일반", "ko").is_err());
+    }
+
+    #[test]
+    fn pipeline_stages_are_independent() {
+        let mut data = sample("3C", "3Φ");
+        data.size = " 16 ".to_string();
+        let normalized = normalize(&data).unwrap();
+        assert_eq!(normalized.data.size, "16");
+        assert!(validate(&normalized).is_ok());
+        let resolved = derive_defaults(normalized).unwrap();
+        assert_eq!(resolved.install_method, InstallMethod::B2);
+        assert_eq!(compute(&resolved, 0).unwrap().allowable_current, 82.0);
+
+        let mut imperial = sample("3C", "3Φ");
+        imperial.size = "4".to_string();
+        imperial.unit_system = Some("imperial".to_string());
+        let normalized = normalize(&imperial).unwrap();
+        assert_eq!((normalized.data.size.as_str(), normalized.awg), ("25", Some("4 AWG")));
+    }
+
+    #[test]
+    fn pipeline_reports_failing_stage() {
+        let stage = |data: &CableData| CalculationPipeline::new(data).run().unwrap_err().stage;
+
+        let mut bad_unit = sample("3C", "3Φ");
+        bad_unit.unit_system = Some("cubits".to_string());
+        assert_eq!(stage(&bad_unit), PipelineStage::Normalize);
+        assert_eq!(stage(&sample("2C", "3Φ")), PipelineStage::Validate);
+        let mut bad_method = sample("3C", "3Φ");
+        bad_method.install_method = "G".to_string();
+        assert_eq!(stage(&bad_method), PipelineStage::DeriveDefaults);
+
+        let failure = CalculationPipeline::new(&bad_method).run().unwrap_err();
+        assert_eq!(failure.error_key, ERR_INVALID_INSTALL_METHOD);
+        assert_eq!(calculate_core(&bad_method).unwrap_err(), failure.message);
+        assert_eq!(PipelineStage::DeriveDefaults.label(), "기본값 도출");
+    }
+
+    #[test]
+    fn feeder_capacity_margin_and_upgrade() {
+        // CV 3C 16mm² B2 3상 82A, 분기 4회로(수용률 0.8)
+        let feeder = sample("3C", "3Φ");
+        let ok = check_feeder_capacity(&feeder, &[20.0, 20.0, 20.0, 20.0]).unwrap();
+        assert_eq!((ok.design_current, ok.demand_factor), (64.0, 0.8));
+        assert!(ok.adequate);
+        // 여유 18A / 0.8 = 22.5A -> 평균 20A 분기 1회로 추가 가능
+        assert_eq!((ok.spare_current, ok.additional_circuits), (22.5, 1));
+        assert_eq!(ok.recommended_size, None);
+
+        // 분기 6회로 × 25A × 0.7 = 105A > 82A -> 25mm²(107A) 제안
+        let over = check_feeder_capacity(&feeder, &[25.0; 6]).unwrap();
+        assert!(!over.adequate);
+        assert_eq!(over.design_current, 105.0);
+        assert_eq!(over.recommended_size.as_deref(), Some("25"));
+
+        assert!(check_feeder_capacity(&feeder, &[-1.0]).is_err());
+    }
+
+    #[test]
+    fn audit_log_appends_and_filters_by_date() {
+        assert_eq!(format_utc_timestamp(0), "1970-01-01T00:00:00Z");
+        assert_eq!(format_utc_timestamp(1_709_251_199), "2024-02-29T23:59:59Z");
+
+        let path = std::env::temp_dir().join(format!("kec_audit_test_{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let data = sample("3C", "3Φ");
+        let result = calculate_core(&data).unwrap();
+        let mut file = open_audit_file(&path).unwrap();
+        for (i, ts) in ["2024-04-30T23:59:59Z", "2024-05-01T09:00:00Z", "2024-05-02T00:00:00Z"].iter().enumerate() {
+            let entry = AuditEntry {
+                id: i.to_string(),
+                timestamp: ts.to_string(),
+                app_version: env!("CARGO_PKG_VERSION").to_string(),
+                input: data.clone(),
+                result: result.clone(),
+            };
+            append_audit_entry(&mut file, &entry).unwrap();
+        }
+        let ids = |from: &str, to: &str| -> Vec<String> {
+            read_audit_entries(&path, from, to).into_iter().map(|e| e.id).collect()
+        };
+        assert_eq!(ids("2024-05-01", "2024-05-01"), vec!["1"]);
+        assert_eq!(ids("2024-05-01", ""), vec!["1", "2"]);
+        assert_eq!(ids("", ""), vec!["0", "1", "2"]);
+        std::fs::remove_file(&path).unwrap();
+        assert!(read_audit_entries(&path, "", "").is_empty());
+    }
+
+    #[test]
+    fn audit_log_is_written_by_background_thread() {
+        let dir = std::env::temp_dir().join(format!("kec_audit_writer_{}", std::process::id()));
+        let path = dir.join(AUDIT_LOG_FILE);
+        let _ = std::fs::remove_dir_all(&dir);
+        let data = sample("3C", "3Φ");
+        let result = calculate_core(&data).unwrap();
+
+        set_audit_log_path(Some(path.clone()));
+        record_audit(&data, &result);
+        record_audit(&data, &result);
+        // 조회는 대기 중인 기록을 먼저 반영 (디렉터리도 기록 스레드가 생성)
+        let entries = get_audit_log("", "");
+        set_audit_log_path(None);
+        assert!(entries.len() >= 2);
+        assert!(entries.iter().all(|entry| entry.app_version == env!("CARGO_PKG_VERSION")));
+        assert_ne!(entries[0].id, entries[1].id);
+
+        // 기록할 수 없는 경로여도 계산 호출은 실패하지 않음
+        let blocked = dir.join(AUDIT_LOG_FILE).join("nested.jsonl");
+        set_audit_log_path(Some(blocked));
+        record_audit(&data, &result);
+        flush_audit_log();
+        set_audit_log_path(None);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn regulation_changes_fill_ground_and_current_rules() {
+        // KEC와 IEC는 같은 허용전류 테이블, 점유율 상한은 3본 이상이면 같음 (40%)
+        let mut data = sample("1C", "3Φ");
+        data.size = "95".to_string();
+        data.quantity = 3;
+        data.ground_wire = "HFIX".to_string();
+        let kec = calculate_core(&data).unwrap();
+        assert_eq!(kec.regulation, "KEC");
+        data.regulation = Some("iec".to_string());
+        let iec = calculate_core(&data).unwrap();
+        assert_eq!(iec.regulation, "IEC");
+        assert_eq!(iec.allowable_current, kec.allowable_current);
+        assert!(iec.recommended_raceway.nominal <= kec.recommended_raceway.nominal);
+        // 접지선은 규정과 무관하게 KEC 542.3 표 적용
+        assert_eq!(iec.ground_wire_size, kec.ground_wire_size);
+        assert_eq!(iec.total_area, kec.total_area);
+
+        // NEC: 16mm² -> 6 AWG(13.3mm²), XLPE 90°C 열 75A, 통전 도체 3가닥
+        let mut nec = sample("3C", "3Φ");
+        nec.regulation = Some("NEC".to_string());
+        let result = calculate_core(&nec).unwrap();
+        assert_eq!((result.allowable_current, result.regulation.as_str()), (75.0, "NEC"));
+        assert_eq!(result.size_label, "16 mm² (6 AWG 적용)");
+        // 2회로 = 통전 도체 6가닥 -> 80%
+        nec.quantity = 2;
+        assert_eq!(calculate_core(&nec).unwrap().allowable_current, 60.0);
+        assert_eq!(Regulation::Nec.conduit_fill_limit(2), 0.31);
+
+        nec.regulation = Some("JIS".to_string());
+        assert!(calculate_core(&nec).is_err());
+    }
+
+    #[test]
+    fn distributes_cables_across_conduits_within_fill_limit() {
+        let circuit = |size: &str, quantity: u32| CableData {
+            cable_type: "CV".to_string(),
+            cores: "1C".to_string(),
+            size: size.to_string(),
+            quantity,
+            system: "3Φ".to_string(),
+            ground_wire: "없음".to_string(),
+            ..Default::default()
+        };
+        let cables = vec![circuit("240", 3), circuit("240", 3), circuit("16", 3)];
+
+        let groups = distribute_cables_to_conduits(&cables, 0.33, true).unwrap();
+        let placed: u32 = groups.iter().flat_map(|g| g.cables.iter().map(|(_, n)| n)).sum();
+        assert_eq!(placed, 9);
+        assert!(groups.iter().all(|g| g.conduit.status == "정상" && g.conduit.fill_rate <= 33.0));
+        // 회로 단위 분배: 한 회로의 3가닥이 여러 관에 나뉘지 않음
+        assert!(groups.iter().all(|g| g.cables.iter().all(|(_, n)| *n == 3)));
+
+        // 가닥 단위 분배는 관 개수가 회로 단위보다 많지 않음
+        let split = distribute_cables_to_conduits(&cables, 0.33, false).unwrap();
+        assert!(split.len() <= groups.len());
+
+        assert_eq!(distribute_cables_to_conduits(&cables, 0.0, true).unwrap_err(), localize_error(ERR_INVALID_FILL_LIMIT, "ko"));
+        let english: Vec<CableData> = cables.iter().map(|c| CableData { lang: Some("en".to_string()), ..c.clone() }).collect();
+        assert_eq!(distribute_cables_to_conduits(&english, 0.0, true).unwrap_err(), localize_error(ERR_INVALID_FILL_LIMIT, "en"));
+        // 500mm² 단심 10가닥 묶음은 최대 관에도 들어가지 않음
+        let err = distribute_cables_to_conduits(&[circuit("500", 10)], 0.33, true).unwrap_err();
+        assert!(err.starts_with("#1: "));
+        assert!(distribute_cables_to_conduits(&[circuit("500", 10)], 0.33, false).is_ok());
+    }
+
+    #[test]
+    fn circuit_count_policies() {
+        // 3상 단심 7가닥: 올림 3회로, 내림 2회로, 정확히 나눔은 에러
+        assert_eq!(count_circuits("1C", "3Φ", 7, CircuitCountPolicy::CeilUp), Ok(3));
+        assert_eq!(count_circuits("1C", "3Φ", 7, CircuitCountPolicy::FloorDown), Ok(2));
+        assert_eq!(count_circuits("1C", "3Φ", 7, CircuitCountPolicy::RequireExact), Err(ERR_CIRCUIT_COUNT_NOT_EXACT));
+        // 나누어떨어지면 세 정책 모두 같음
+        for policy in [CircuitCountPolicy::CeilUp, CircuitCountPolicy::FloorDown, CircuitCountPolicy::RequireExact] {
+            assert_eq!(count_circuits("1C", "1Φ", 4, policy), Ok(2));
+            // 다심은 정책과 무관하게 수량 = 회로 수
+            assert_eq!(count_circuits("3C", "3Φ", 7, policy), Ok(7));
+        }
+
+        let mut data = sample("1C", "3Φ");
+        data.quantity = 7;
+        assert!(calculate_core(&data).unwrap().install_method_desc.contains("(3회로) / 회로 수 산정: 올림"));
+        data.circuit_count_policy = Some(CircuitCountPolicy::FloorDown);
+        assert!(calculate_core(&data).unwrap().install_method_desc.contains("(2회로) / 회로 수 산정: 내림"));
+        data.circuit_count_policy = Some(CircuitCountPolicy::RequireExact);
+        assert_eq!(calculate_core(&data).unwrap_err(), localize_error(ERR_CIRCUIT_COUNT_NOT_EXACT, "ko"));
+        assert!(!calculate_core(&sample("3C", "3Φ")).unwrap().install_method_desc.contains("회로 수 산정"));
+    }
+
+    #[test]
+    fn short_circuit_current_scales_with_size_and_duration() {
+        // 동 XLPE 16mm² 1초: 143 × 16 = 2288A
+        assert_eq!(max_short_circuit_current("16", "XLPE", "Cu", None, "ko"), Ok(2.29));
+        // 0.25초는 1초 대비 2배
+        assert_eq!(max_short_circuit_current("16", "XLPE", "동", Some(0.25), "ko"), Ok(4.58));
+        assert_eq!(max_short_circuit_current("16", "PVC", "al", None, "ko"), Ok(1.22));
+
+        let currents: Vec<f64> = STANDARD_SIZES
+            .iter()
+            .map(|size| max_short_circuit_current(size, "XLPE", "Cu", None, "ko").unwrap())
+            .collect();
+        assert!(currents.windows(2).all(|w| w[0] < w[1]));
+
+        assert_eq!(max_short_circuit_current("16", "XLPE", "Fe", None, "ko"), Err(localize_error(ERR_INVALID_MATERIAL, "ko")));
+        assert_eq!(max_short_circuit_current("16", "XLPE", "Fe", None, "en"), Err(localize_error(ERR_INVALID_MATERIAL, "en")));
+        assert!(max_short_circuit_current("16", "XLPE", "Cu", Some(0.0), "ko").is_err());
+        assert!(max_short_circuit_current("17", "XLPE", "Cu", None, "ko").is_err());
+    }
+
+    #[test]
+    fn method_ratios_are_relative_to_base_method() {
+        let ratios = method_current_ratios("16", "XLPE", 3, "C", "ko").unwrap();
+        let ratio = |method: &str| ratios.iter().find(|(m, _)| m == method).map(|(_, r)| *r);
+        assert_eq!(ratio("C"), Some(100.0));
+        // 테이블값 비율과 일치, 트레이(F)는 전선관(B1)보다 높음
+        let b1 = lookup_base_current(Size("16"), Insulation::Xlpe, InstallMethod::B1, 3).unwrap();
+        let c = lookup_base_current(Size("16"), Insulation::Xlpe, InstallMethod::C, 3).unwrap();
+        assert_eq!(ratio("B1"), Some((b1 / c * 1000.0).round() / 10.0));
+        assert!(ratio("F").unwrap() > ratio("B1").unwrap());
+
+        assert!(method_current_ratios("16", "XLPE", 3, "G", "ko").is_err());
+        assert!(method_current_ratios("16", "XLPE", 4, "C", "ko").is_err());
+        assert_eq!(method_current_ratios("16", "XLPE", 4, "C", "en"), Err(localize_error(ERR_INVALID_LOADED_CONDUCTORS, "en")));
+    }
+
+    #[test]
+    fn data_coverage_report_finds_missing_combinations() {
+        let report = data_coverage_report();
+        assert_eq!(report.total, report.entries.len());
+        // KEC 2021 수록 범위(300mm² 이하)는 외경·허용전류 모두 있어야 함
+        for entry in report.entries.iter().filter(|e| e.size.parse::<f64>().unwrap() <= 300.0) {
+            assert!(entry.has_outer_diameter, "{} {} {}", entry.cable_type, entry.size, entry.cores);
+            assert!(entry.has_current, "{} {} {} {:?}", entry.cable_type, entry.size, entry.cores, entry.missing_methods);
+        }
+        // 400/500mm² 다심 CV는 선택지에 있으나 외경 데이터 없음
+        let cv_400_3c = report.entries.iter().find(|e| e.cable_type == "CV" && e.size == "400" && e.cores == "3C").unwrap();
+        assert!(!cv_400_3c.has_outer_diameter);
+        assert_eq!(report.missing_outer_diameter, report.entries.iter().filter(|e| !e.has_outer_diameter).count());
+        assert!(report.missing_outer_diameter > 0);
+    }
+
+    #[test]
+    fn voltage_drop_limits_follow_kec_table() {
+        assert_eq!(get_voltage_drop_limit("저압수전 조명", false, false, "ko"), Ok(3.0));
+        assert_eq!(get_voltage_drop_limit("고압수전 기타", false, false, "ko"), Ok(8.0));
+        // 고압수전 최종회로는 A 유형 값
+        assert_eq!(get_voltage_drop_limit("고압수전 조명", true, false, "ko"), Ok(3.0));
+        assert_eq!(get_voltage_drop_limit("저압수전 기타", false, true, "ko"), Ok(5.5));
+        assert!(get_voltage_drop_limit("주택", false, false, "ko").is_err());
+        assert_eq!(
+            get_voltage_drop_limit("주택", false, false, "en").unwrap_err(),
+            localize_error(ERR_UNKNOWN_INSTALLATION_TYPE, "en")
+        );
+        // 150m: 50m × 0.005% = 0.25% 추가
+        assert_eq!(voltage_drop_limit("저압수전 조명", true, Meters(150.0)).map(|(l, _)| l), Ok(3.25));
+
+        // 16mm² 100m 50A 380V 강하 2.9% -> 조명 3% 이내, 기타(기본) 5% 이내
+        let data = sample("3C", "3Φ");
+        let mut input = VoltageDropInput {
+            length: 100.0,
+            current: Some(50.0),
+            voltage: 380.0,
+            power_factor: None,
+            transformer: None,
+            installation_type: None,
+            is_branch: true,
+        };
+        let result = calculate_voltage_drop(&data, &input).unwrap();
+        assert_eq!((result.limit_percent, result.within_limit), (5.0, true));
+        assert!(result.limit_basis.starts_with("KEC 232.3.9"));
+        input.current = Some(60.0);
+        input.installation_type = Some("저압수전 조명".to_string());
+        let result = calculate_voltage_drop(&data, &input).unwrap();
+        assert_eq!((result.limit_percent, result.within_limit), (3.0, false));
+    }
+
+    #[test]
+    fn batch_chunks_stream_results_and_collect_errors() {
+        let mut cables = vec![sample("3C", "3Φ"); 5];
+        cables[3].cores = "2C".to_string();
+        let mut chunks = Vec::new();
+        let complete = calculate_batch_chunked(&cables, 2, |chunk| {
+            chunks.push(chunk);
+            Ok::<(), String>(())
+        })
+        .unwrap();
+        assert_eq!(chunks.len(), 3);
+        let indices: Vec<Vec<usize>> = chunks.iter().map(|c| c.results.iter().map(|(i, _)| *i).collect()).collect();
+        assert_eq!(indices, vec![vec![0, 1], vec![2], vec![4]]);
+        assert_eq!((complete.total, complete.succeeded), (5, 4));
+        assert_eq!(complete.errors, vec![(3, localize_error(ERR_2C_IN_THREE_PHASE, "ko"))]);
+
+        assert!(calculate_batch_chunked(&cables, 0, |_| Ok::<(), String>(())).is_err());
+        let english = vec![CableData { lang: Some("en".to_string()), ..sample("3C", "3Φ") }];
+        assert_eq!(
+            calculate_batch_chunked(&english, 0, |_| Ok::<(), String>(())).unwrap_err(),
+            localize_error(ERR_INVALID_CHUNK_SIZE, "en")
+        );
+        // 전달 실패는 중단
+        assert_eq!(calculate_batch_chunked(&cables, 2, |_| Err("closed")).unwrap_err(), "closed");
+    }
+
+    #[test]
+    fn minimum_size_by_usage_and_system() {
+        assert_eq!(get_minimum_size("동력 간선", "3Φ", "ko"), Ok("4"));
+        assert_eq!(get_minimum_size("동력 간선", "1Φ", "ko"), Ok("2.5"));
+        assert_eq!(get_minimum_size("전등 분기", "1Φ", "ko"), Ok("1.5"));
+        assert!(get_minimum_size("난방", "1Φ", "ko").is_err());
+        assert_eq!(get_minimum_size("난방", "1Φ", "en"), Err(localize_error(ERR_UNKNOWN_USAGE, "en")));
+
+        let mut data = sample("3C", "3Φ");
+        assert_eq!(calculate_core(&data).unwrap().minimum_size, None);
+        data.usage = Some("동력 간선".to_string());
+        assert_eq!(calculate_core(&data).unwrap().minimum_size.as_deref(), Some("동력 간선 최소 4 mm²"));
+        data.size = "2.5".to_string();
+        assert_eq!(calculate_core(&data).unwrap_err(), localize_error(ERR_BELOW_MINIMUM_SIZE, "ko"));
+        data.usage = Some("전등 분기".to_string());
+        assert!(calculate_core(&data).is_ok());
+
+        data.lang = Some("en".to_string());
+        assert_eq!(calculate_core(&data).unwrap().minimum_size.as_deref(), Some("Lighting branch minimum 1.5 mm²"));
+    }
+
+    #[test]
+    fn nearest_available_prefers_closest_current() {
+        // 400mm²가 없으면 같은 방법의 가장 가까운 규격(300)부터
+        let mut table = get_allowable_current_table().clone();
+        table.retain(|(size, _, _), _| size.mm2() <= 300.0);
+        let nearest = nearest_available(&table, Size("400"), Insulation::Xlpe, InstallMethod::C);
+        assert_eq!(nearest.first(), Some(&(Size("300"), InstallMethod::C)));
+        assert!(nearest.len() <= MAX_NEAREST_SUGGESTIONS);
+        assert!(nearest.iter().all(|(_, m)| *m == InstallMethod::C));
+
+        // 같은 규격에 다른 방법 데이터가 있으면 그 평균에 가까운 조합 순
+        let mut partial = get_allowable_current_table().clone();
+        partial.remove(&(Size("16"), Insulation::Xlpe, InstallMethod::B2));
+        let nearest = nearest_available(&partial, Size("16"), Insulation::Xlpe, InstallMethod::B2);
+        let currents: Vec<f64> = nearest
+            .iter()
+            .map(|(s, m)| partial.get(&(*s, Insulation::Xlpe, *m)).unwrap().1)
+            .collect();
+        let same_size: Vec<f64> = InstallMethod::ALL
+            .iter()
+            .filter_map(|m| partial.get(&(Size("16"), Insulation::Xlpe, *m)).map(|c| c.1))
+            .collect();
+        let reference = same_size.iter().sum::<f64>() / same_size.len() as f64;
+        assert!(currents.windows(2).all(|w| (w[0] - reference).abs() <= (w[1] - reference).abs()));
+
+        assert!(nearest_available(&CurrentTable::new(), Size("16"), Insulation::Xlpe, InstallMethod::B2).is_empty());
+        let invalid = suggest_nearest_available("17", "XLPE", "C", "ko");
+        assert!(invalid.suggestions.is_empty() && invalid.reason.is_some());
+        let invalid_en = suggest_nearest_available("17", "XLPE", "C", "en");
+        assert!(!contains_hangul(invalid_en.reason.as_deref().unwrap()));
+
+        // 계산 에러 힌트도 언어 표기를 정규화 (공백 포함 "en"도 영문)
+        let hint = nearest_available_hint(&sample("3C", "3Φ"), InstallMethod::B2, " en ").unwrap();
+        assert!(hint.starts_with("nearest available combination: "), "{}", hint);
+    }
+
+    #[test]
+    fn geometric_fit_uniform_and_mixed_diameters() {
+        // 동일 외경: 3가닥 삼각 배열은 외경의 약 2.155배 내경 필요
+        assert!(!can_fit_geometrically(21.0, vec![10.0; 3]));
+        assert!(can_fit_geometrically(22.0, vec![10.0; 3]));
+        // 4가닥 정사각 (2.414배), 7가닥 육각+중심 (3배)
+        assert!(!can_fit_geometrically(24.0, vec![10.0; 4]));
+        assert!(can_fit_geometrically(24.2, vec![10.0; 4]));
+        assert!(can_fit_geometrically(30.0, vec![10.0; 7]));
+        assert!(!can_fit_geometrically(29.9, vec![10.0; 7]));
+        // 다수 가닥은 근사식이 7가닥 배열보다 보수적
+        assert!(!can_fit_geometrically(30.0, vec![10.0; 8]));
+
+        // 혼합 외경: 큰 가닥 하나 + 작은 가닥 여럿은 배열 가능
+        assert!(can_fit_geometrically(30.0, vec![5.0, 20.0, 5.0]));
+        // 가장 큰 두 가닥이 나란히 들어가지 않으면 불가
+        assert!(!can_fit_geometrically(39.0, vec![20.0, 5.0, 20.0]));
+        assert!(can_fit_geometrically(45.0, vec![20.0, 5.0, 20.0]));
+        // 외경 0 이하 항목은 무시
+        assert!(can_fit_geometrically(10.0, vec![10.0, 0.0]));
+
+        // 면적은 통과하나 배열 불가: 외경 20mm 3가닥, 점유율 상한 100%
+        let diameters = vec![20.0; 3];
+        let area = effective_occupied_area(&[(20.0, 3)]);
+        let by_area = recommend_conduit(ConduitType::Steel, area, 1.0, 0.0);
+        assert_eq!(by_area.name, "C42 (42mm)");
+        let refit = recommend_conduit_for_cables(ConduitType::Steel, area, FillLimit::Fixed(1.0), &diameters);
+        assert_eq!(refit.name, "C54 (54mm)");
+        assert_eq!(refit.status, "정상");
+        assert!(refit.geometry_note.as_deref().unwrap().contains("C42"));
+        // KEC 3본 40% 상한에서는 면적 기준으로 충분해 재추천 없음
+        let kec = recommend_conduit_for_cables(ConduitType::Steel, area, FillLimit::Regulation(Regulation::Kec), &diameters);
+        assert!(kec.geometry_note.is_none());
+    }
+
+    #[test]
+    fn html_report_is_self_contained_and_highlights_problems() {
+        let mut data = sample("1C", "3Φ");
+        data.load_current = Some(10_000.0);
+        data.manufacturer = Some("<LS>".to_string());
+        let result = calculate_core(&data).unwrap();
+        let meta = ProjectMetadata {
+            name: "본관 & 별관".to_string(),
+            author: "홍길동".to_string(),
+            description: String::new(),
+        };
+
+        let html = render_report_html(&data, &result, Some(&meta));
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<meta charset=\"UTF-8\">"));
+        assert!(html.contains("<style>"));
+        assert!(html.contains("본관 &amp; 별관"));
+        assert!(html.contains("&lt;LS&gt;"));
+        // 과부하 허용전류 셀 강조 + 경고 섹션
+        assert!(html.contains(&format!("<td class=\"alert\">{:.1} A</td>", result.allowable_current)));
+        assert!(html.contains("경고 및 가정"));
+        assert!(html.contains("<footer>출처: KEC 232.2"));
+
+        // 문제 없는 입력은 강조·경고 없음, 메타 없이도 렌더링
+        let mut ok = sample("1C", "3Φ");
+        ok.load_current = Some(1.0);
+        let ok_result = calculate_core(&ok).unwrap();
+        let html = render_report_html(&ok, &ok_result, None);
+        assert!(!html.contains("class=\"alert\""));
+        assert!(!html.contains("프로젝트:"));
+    }
+
+    #[test]
+    fn round_up_to_standard_size_boundaries() {
+        // 표준 규격과 정확히 일치하면 그대로
+        assert_eq!(round_up_to_standard_size(16.0).as_deref(), Some("16"));
+        assert_eq!(round_up_to_standard_size(1.5).as_deref(), Some("1.5"));
+        assert_eq!(round_up_to_standard_size(500.0).as_deref(), Some("500"));
+        // 사이 값은 다음 표준 규격으로 올림
+        assert_eq!(round_up_to_standard_size(16.01).as_deref(), Some("25"));
+        assert_eq!(round_up_to_standard_size(2.6).as_deref(), Some("4"));
+        assert_eq!(round_up_to_standard_size(0.0).as_deref(), Some("1.5"));
+        // 최대 규격 초과·비정상 값
+        assert_eq!(round_up_to_standard_size(500.1).as_deref(), Some("630"));
+        assert_eq!(round_up_to_standard_size(1000.1), None);
+        assert_eq!(round_up_to_standard_size(f64::NAN), None);
+    }
+
+    #[test]
+    fn result_carries_data_provenance() {
+        let _table = lock_custom_table();
+        let mut data = sample("3C", "3Φ");
+        data.regulation = Some("IEC".to_string());
+        let result = calculate_core(&data).unwrap();
+        let provenance = &result.data_provenance;
+        assert_eq!(provenance.table_version, DATA_VERSION);
+        assert_eq!(provenance.regulation, "IEC");
+        assert_eq!(provenance.data_checksum.len(), 16);
+        assert!(provenance.calculated_at.ends_with('Z'));
+
+        let html = render_report_html(&data, &result, None);
+        assert!(html.contains(&provenance.data_checksum));
+
+        // 계산 시각을 주입하면 결과·계산서가 결정적
+        let at = |secs| CalculationPipeline::new(&data).calculated_at(secs).run().unwrap();
+        let fixed = at(1_709_251_199);
+        assert_eq!(fixed.data_provenance.calculated_at, "2024-02-29T23:59:59Z");
+        assert_eq!(render_report_html(&data, &fixed, None), render_report_html(&data, &at(1_709_251_199), None));
+        assert!(render_report_html(&data, &fixed, None).contains("2024-02-29T23:59:59Z"));
+
+        // 출처 필드가 없는 이전 결과 JSON도 읽을 수 있음
+        let mut json = serde_json::to_value(&result).unwrap();
+        json.as_object_mut().unwrap().remove("data_provenance");
+        let legacy: CalculationResult = serde_json::from_value(json).unwrap();
+        assert_eq!(legacy.data_provenance, DataProvenance::default());
+    }
+
+    #[test]
+    fn system_change_preview_warns_on_three_phase() {
+        let mut data = sample("3C", "1Φ");
+        data.install_method = "C".to_string();
+        data.load_current = Some(80.0);
+        let preview = preview_system_change(&data).unwrap();
+        assert_eq!(preview.entries.len(), 2);
+        let (single, three) = (&preview.entries[0], &preview.entries[1]);
+        assert!(single.selected && !three.selected);
+        assert_eq!((single.loaded_conductors, three.loaded_conductors), (2, 3));
+        assert!(three.allowable_current < single.allowable_current);
+        assert!(preview.difference_percent.unwrap() < 0.0);
+        assert!(preview.warning.as_deref().unwrap().contains("감소"));
+        let english = preview_system_change(&CableData { lang: Some("en".to_string()), ..data.clone() }).unwrap();
+        let warning = english.warning.unwrap();
+        assert!(warning.starts_with("Switching to 3Φ raises loaded conductors from 2 to 3"), "{}", warning);
+        assert!(!contains_hangul(&warning));
+        // 3부하가 낮으므로 추천 규격은 같거나 커짐
+        let mm2 = |e: &SystemPreviewEntry| e.recommended_size.as_deref().unwrap().parse::<f64>().unwrap();
+        assert!(mm2(three) >= mm2(single));
+
+        // 2C는 3Φ 조합 불가 -> 1Φ만
+        let preview = preview_system_change(&sample("2C", "1Φ")).unwrap();
+        assert_eq!(preview.entries.len(), 1);
+        assert_eq!(preview.difference_percent, None);
+        assert!(preview.entries[0].recommended_size.is_none());
+
+        let mut invalid = sample("3C", "1Φ");
+        invalid.size = "7".to_string();
+        assert!(preview_system_change(&invalid).is_err());
+    }
+
+    #[test]
+    fn termination_temperature_limits_allowable_current() {
+        assert_eq!(apply_termination_temp_limit(90, 75), 75);
+        assert_eq!(apply_termination_temp_limit(90, 90), 90);
+        assert_eq!(apply_termination_temp_limit(90, 80), 75);
+        assert_eq!(apply_termination_temp_limit(70, 75), 70);
+        assert_eq!(apply_termination_temp_limit(70, 60), 60);
+        assert_eq!(apply_termination_temp_limit(60, 90), 60);
+
+        let data = sample("3C", "3Φ");
+        let plain = calculate_core(&data).unwrap();
+        assert_eq!(plain.applied_temp_rating, 90);
+
+        let mut limited = data.clone();
+        limited.termination_temp = Some(75);
+        let result = calculate_core(&limited).unwrap();
+        assert_eq!(result.applied_temp_rating, 75);
+        assert_eq!(result.conductor_max_temp, 90);
+        // 공기 중 기준 30°C: √(45/60) ≈ 0.87
+        assert!(result.allowable_current < plain.allowable_current);
+        assert!(result.install_method_desc.contains("단자 등급 75°C"));
+
+        // 단자 등급이 케이블 등급 이상이면 영향 없음
+        limited.termination_temp = Some(90);
+        assert_eq!(calculate_core(&limited).unwrap().allowable_current, plain.allowable_current);
+
+        // NEC는 해당 온도 열 선택: 4 AWG 75°C 열 85A
+        let mut nec = sample("3C", "3Φ");
+        nec.regulation = Some("NEC".to_string());
+        nec.unit_system = Some("imperial".to_string());
+        nec.size = "4 AWG".to_string();
+        nec.termination_temp = Some(75);
+        let result = calculate_core(&nec).unwrap();
+        assert_eq!((result.applied_temp_rating, result.allowable_current), (75, 85.0));
+
+        limited.termination_temp = Some(50);
+        assert_eq!(calculate_core(&limited).unwrap_err(), localize_error(ERR_INVALID_TERMINATION_TEMP, "ko"));
+    }
+
+    #[test]
+    fn pull_box_dimensions_by_entry_type() {
+        let single = vec![sample("3C", "3Φ")];
+        let nominal = calculate_core(&single[0]).unwrap().recommended_raceway.nominal as f64;
+
+        let straight = recommend_pull_box(&single, "직선").unwrap();
+        assert_eq!(straight.min_length, nominal * 8.0);
+        assert!(straight.length >= straight.min_length && straight.width >= straight.min_width);
+        assert!(PULL_BOX_STANDARD_SIZES.contains(&straight.length));
+
+        // 직각 인입은 두 번째 관경까지 더하고 직선보다 큰 폭
+        let mut second = sample("3C", "3Φ");
+        second.size = "4".to_string();
+        let small = calculate_core(&second).unwrap().recommended_raceway.nominal as f64;
+        let angle = recommend_pull_box(&[single[0].clone(), second], "angle").unwrap();
+        assert!(angle.min_length >= nominal * 6.0 + small);
+        assert!(angle.min_width > straight.min_width);
+
+        assert_eq!(round_up_box_size(1250.0), 1300.0);
+        assert_eq!(recommend_pull_box(&single, "사선").unwrap_err(), localize_error(ERR_INVALID_ENTRY_TYPE, "ko"));
+        assert_eq!(recommend_pull_box(&[], "직선").unwrap_err(), localize_error(ERR_EMPTY_CABLE_LIST, "ko"));
+        let mut english = single[0].clone();
+        english.lang = Some("en".to_string());
+        assert_eq!(recommend_pull_box(&[english], "사선").unwrap_err(), localize_error(ERR_INVALID_ENTRY_TYPE, "en"));
+        let mut invalid = sample("2C", "3Φ");
+        invalid.quantity = 1;
+        assert!(recommend_pull_box(&[invalid], "직선").unwrap_err().starts_with("#1: "));
+    }
+
+    #[test]
+    fn unit_conversions_keep_dimensions() {
+        // Ω/km × m: km 환산은 곱셈 한 곳에서만
+        assert_eq!(OhmsPerKm(1.15) * Meters(1000.0), Ohms(1.15));
+        assert!(((OhmsPerKm(0.08) * Meters(250.0)).value() - 0.02).abs() < 1e-12);
+        assert_eq!(Ohms(0.5) * Amperes(10.0), Volts(5.0));
+        assert!((Meters::from_feet(1000.0).value() - 304.8).abs() < 1e-9);
+        assert_eq!(Meters::from_km(1.5), Meters(1500.0));
+        assert_eq!(Meters(2500.0).km(), 2.5);
+
+        // 같은 단위 연산과 비율
+        assert_eq!(Amperes(82.0) - Amperes(2.0), Amperes(80.0));
+        assert_eq!(Amperes(40.0) / Amperes(80.0), 0.5);
+        assert_eq!([Millimeters2(1.5), Millimeters2(2.5)].into_iter().sum::<Millimeters2>(), Millimeters2(4.0));
+        assert_eq!(Amperes(82.5).to_string(), "82.5 A");
+
+        // 전압강하: 16mm² XLPE 3C 3Φ 100m 50A, 역률 1 -> √3 × 50 × R(Ω/km) × 0.1km
+        let data = sample("3C", "3Φ");
+        let input = VoltageDropInput {
+            length: 100.0,
+            voltage: 380.0,
+            current: Some(50.0),
+            power_factor: Some(1.0),
+            transformer: None,
+            installation_type: None,
+            is_branch: false,
+        };
+        let r = conductor_resistance_per_km(Millimeters2(16.0), Insulation::Xlpe, Material::Copper) * Meters(100.0);
+        let expected = (r * Amperes(50.0) * 3f64.sqrt()).value();
+        let result = calculate_voltage_drop(&data, &input).unwrap();
+        assert_eq!(result.cable_drop, (expected * 100.0).round() / 100.0);
+    }
+
+    #[test]
+    fn result_includes_adjacent_size_alternatives() {
+        let mut data = sample("3C", "3Φ");
+        data.load_current = Some(70.0);
+        let result = calculate_core(&data).unwrap();
+        let sizes: Vec<&str> = result.alternatives.iter().map(|a| a.size.as_str()).collect();
+        assert_eq!(sizes, vec!["10", "25"]);
+        let (below, above) = (&result.alternatives[0], &result.alternatives[1]);
+        assert!(below.allowable_current < result.allowable_current);
+        assert!(above.allowable_current > result.allowable_current);
+        // 70A: 10mm²는 과부하, 25mm²는 적합
+        assert_eq!(below.overloaded, Some(true));
+        assert!(!below.suitable);
+        assert_eq!(above.overloaded, Some(false));
+        assert!(above.suitable);
+
+        // 최소 규격은 위쪽만, 부하전류 미지정 시 과부하 None
+        let mut smallest = sample("3C", "3Φ");
+        smallest.cable_type = "HFIX".to_string();
+        smallest.cores = "1C".to_string();
+        smallest.quantity = 3;
+        smallest.size = "1.5".to_string();
+        let alternatives = calculate_core(&smallest).unwrap().alternatives;
+        assert_eq!(alternatives.len(), 1);
+        assert_eq!(alternatives[0].size, "2.5");
+        assert_eq!(alternatives[0].overloaded, None);
+    }
+
+    #[test]
+    fn cached_conduit_table_matches_raw_data() {
+        let table = conduit_table(ConduitType::Steel);
+        assert!(std::ptr::eq(table, conduit_table(ConduitType::Steel)));
+        assert_eq!(table.len(), CONDUIT_DATA.len());
+        for (spec, &(name, inner_diameter)) in table.iter().zip(CONDUIT_DATA) {
+            assert_eq!(spec.name, name);
+            assert_eq!(spec.nominal, conduit_nominal(name));
+            assert_eq!(spec.area, calculate_cable_area(inner_diameter).value());
+        }
+        // 한계는 조회 시 곱으로 적용: 경계 면적은 해당 규격에 정확히 들어감
+        let c28 = &table[2];
+        assert_eq!(recommend_conduit(ConduitType::Steel, c28.area * 0.31, 0.31, 0.0).name, c28.name);
+        assert_eq!(recommend_conduit(ConduitType::Steel, c28.area * 0.4, 0.4, 0.0).name, c28.name);
+        assert_eq!(recommend_conduit(ConduitType::Steel, c28.area * 0.4 + 0.01, 0.4, 0.0).name, table[3].name);
+    }
+
+    #[test]
+    fn load_ratio_table_by_size() {
+        let table = load_ratio_table("CV", "B2", "3Φ", 120.0);
+        assert_eq!(table.first().map(|(size, ..)| size.as_str()), Some(STANDARD_SIZES[0]));
+        // 규격 오름차순이면 허용전류 증가, 부하율 감소
+        assert!(table.windows(2).all(|w| w[0].1 < w[1].1 && w[0].2 > w[1].2));
+        let (_, current_16, ratio_16) = table.iter().find(|(size, ..)| size == "16").unwrap();
+        assert_eq!(*current_16, 82.0);
+        assert_eq!(*ratio_16, 146.3);
+        assert!(*ratio_16 > OVERLOAD_RATIO_PERCENT);
+        assert!(table.iter().any(|(_, _, ratio)| *ratio <= 80.0));
+
+        assert!(load_ratio_table("XYZ", "B2", "3Φ", 120.0).is_empty());
+        assert!(load_ratio_table("CV", "G", "3Φ", 120.0).is_empty());
+        assert!(load_ratio_table("CV", "B2", "3Φ", 0.0).is_empty());
+    }
+
+    #[test]
+    fn oversized_single_cable_skips_conduit() {
+        assert_eq!(max_single_cable_diameter(28), Some(21.3));
+        assert_eq!(max_single_cable_diameter(104), Some(80.8));
+        assert_eq!(max_single_cable_diameter(30), None);
+
+        // 외경 50mm 단일 케이블, 점유율 상한 100%: 면적으로는 C54(내경 53mm)지만 한계 42.4mm 초과
+        let area = calculate_cable_area(50.0).value();
+        let by_area = recommend_conduit(ConduitType::Steel, area, 1.0, 0.0);
+        assert_eq!(by_area.name, "C54 (54mm)");
+        let single = recommend_conduit_for_cables(ConduitType::Steel, area, FillLimit::Fixed(1.0), &[50.0]);
+        assert_eq!((single.name.as_str(), single.status.as_str()), ("C70 (70mm)", "정상"));
+        assert!(single.geometry_note.as_deref().unwrap().contains("C54"));
+
+        // 최대 관 한계(80.8mm)도 넘으면 분할로 해결할 수 없으므로 최대 초과
+        let huge = recommend_conduit_for_cables(ConduitType::Steel, calculate_cable_area(90.0).value(), FillLimit::Fixed(1.0), &[90.0]);
+        assert_eq!(huge.status, "최대 초과");
+        assert!(huge.geometry_note.is_some());
+    }
+
+    #[test]
+    fn formulas_show_substituted_values() {
+        let single = calculate_core(&sample("3C", "3Φ")).unwrap();
+        assert_eq!(single.formulas[0], format!("허용전류 = {}A = {:.1}A", single.allowable_current, single.allowable_current));
+        assert!(single.formulas[1].starts_with("점유율 = "));
+        assert!(single.formulas[1].ends_with(&format!("= {:.1}%", single.fill_rate)));
+
+        // 2회로: 집합 보정계수만 수식에 포함
+        let grouped = calculate_core(&CableData { quantity: 2, ..sample("3C", "3Φ") }).unwrap();
+        let factor = get_grouping_factor(2, Arrangement::Conduit);
+        assert_eq!(
+            grouped.formulas[0],
+            format!("허용전류 = {}A × {:.2}(집합) = {:.1}A", single.allowable_current, factor, grouped.allowable_current)
+        );
+        assert!(render_report_html(&sample("3C", "3Φ"), &grouped, None).contains("(집합)"));
+
+        // 전압강하는 계산을 요청했을 때만 수식 생성
+        let input = VoltageDropInput {
+            length: 100.0,
+            current: Some(50.0),
+            voltage: 380.0,
+            power_factor: None,
+            transformer: None,
+            installation_type: None,
+            is_branch: false,
+        };
+        let drop = calculate_voltage_drop(&sample("3C", "3Φ"), &input).unwrap();
+        assert_eq!(drop.formulas.len(), 2);
+        assert_eq!(drop.formulas[0], "전압강하 = √3 × 50A × 0.100km × (1.374Ω/km × 0.90 + 0.080Ω/km × 0.44) = 11.01V");
+        assert_eq!(drop.formulas[1], format!("전압강하율 = 11.01V ÷ 380V × 100 = {:.2}%", drop.total_drop_percent));
+
+        // 영문 입력은 수식 항목·계수 이름도 영문
+        let english = CableData { quantity: 2, lang: Some("en".to_string()), ..sample("3C", "3Φ") };
+        let result = calculate_core(&english).unwrap();
+        assert_eq!(
+            result.formulas[0],
+            format!("Ampacity = {}A × {:.2}(Grouping) = {:.1}A", single.allowable_current, factor, grouped.allowable_current)
+        );
+        assert!(result.formulas[1].starts_with("Fill rate = "));
+        assert!(result.formulas.iter().all(|f| !contains_hangul(f)), "{:?}", result.formulas);
+        let drop = calculate_voltage_drop(&english, &input).unwrap();
+        assert!(drop.formulas[0].starts_with("Voltage drop = √3 × 50A"));
+        assert!(drop.formulas[1].starts_with("Voltage drop ratio = "));
+    }
+
+    #[test]
+    fn cross_validation_compares_kec_and_iec() {
+        // 50mm² 케이블+접지 2본 점유율 상한 31% vs 40%
+        let data = CableData { size: "50".to_string(), ground_wire: "HFIX".to_string(), ..sample("3C", "3Φ") };
+        let result = cross_validate_regulations(&data).unwrap();
+        assert!(result.kec.is_some() && result.iec.is_some() && result.note.is_none());
+        let item = |name: &str| result.differences.iter().find(|d| d.item == name).unwrap().clone();
+        assert_eq!(item("허용전류").difference_percent, Some(0.0));
+        assert!(!item("허용전류").significant);
+        let fill_limit = item("점유율 상한");
+        assert_eq!((fill_limit.kec.as_str(), fill_limit.iec.as_str()), ("31 %", "40 %"));
+        assert_eq!(fill_limit.difference_percent, Some(29.0));
+        assert!(fill_limit.significant);
+        assert!(result.differences.iter().all(|d| d.item != "접지선 규격"));
+
+        // 영문 입력은 항목 이름도 영문
+        let english = cross_validate_regulations(&CableData { lang: Some("en".to_string()), ..data }).unwrap();
+        let items: Vec<&str> = english.differences.iter().map(|d| d.item.as_str()).collect();
+        assert_eq!(items, ["Ampacity", "Fill limit", "Recommended conduit", "Fill rate"]);
+
+        // 두 규정 모두 불가하면 에러
+        assert!(cross_validate_regulations(&CableData { size: "999".to_string(), ..sample("3C", "3Φ") }).is_err());
+    }
+
+    #[test]
+    fn explore_solutions_lists_valid_combinations() {
+        let all = explore_solutions("CV", "3Φ", 85.0, &SolutionConstraints::default());
+        assert!(!all.is_empty());
+        assert!(all.iter().all(|s| s.satisfies && s.allowable_current >= 85.0));
+        assert!(all.windows(2).all(|w| w[0].margin_percent <= w[1].margin_percent));
+        assert!(all.iter().any(|s| s.install_method == "B2") && all.iter().any(|s| s.install_method == "F"));
+
+        let narrowed = SolutionConstraints {
+            max_size: Some("25".to_string()),
+            methods: vec!["B2".to_string(), "F".to_string()],
+            cores: None,
+        };
+        let limited = explore_solutions("CV", "3Φ", 85.0, &narrowed);
+        assert!(!limited.is_empty());
+        assert!(limited.iter().all(|s| ["B2", "F"].contains(&s.install_method.as_str())));
+        assert!(limited.iter().all(|s| Size::try_from(s.size.as_str()).unwrap().mm2() <= 25.0));
+
+        // 만족하는 조합이 없으면 가장 근접한 조합 하나
+        let nearest = explore_solutions("CV", "3Φ", 85.0, &SolutionConstraints { max_size: Some("4".to_string()), ..narrowed });
+        assert_eq!(nearest.len(), 1);
+        assert!(!nearest[0].satisfies && nearest[0].margin_percent < 0.0);
+        assert!(explore_solutions("XYZ", "3Φ", 85.0, &SolutionConstraints::default()).is_empty());
+    }
+
+    #[test]
+    fn design_circuit_reports_binding_constraint() {
+        let input = CircuitDesignInput {
+            cable_type: "CV".to_string(),
+            cores: None,
+            system: "3Φ".to_string(),
+            voltage: 380.0,
+            load_current: None,
+            load_kw: Some(30.0),
+            power_factor: Some(0.9),
+            length: 30.0,
+            install_method: "B2".to_string(),
+            installation_type: None,
+            is_branch: false,
+            fault_current_ka: None,
+            fault_duration_s: None,
+            breaker_type: BreakerType::Household,
+            conductor_material: None,
+            lang: None,
+        };
+        // 30kW / (√3 × 380V × 0.9) = 50.6A → 차단기 63A, 허용전류만으로는 10mm²지만 63A ≤ Iz 조건으로 16mm²
+        let short = design_circuit(&input).unwrap();
+        assert_eq!((short.load_current, short.breaker_rating), (50.6, Some(63)));
+        assert_eq!(short.size.as_deref(), Some("16"));
+        assert_eq!(short.binding_constraint.as_deref(), Some("보호협조"));
+        assert_eq!(short.checks[0].min_size.as_deref(), Some("10"));
+        assert!(short.suggestions.is_empty());
+
+        // 400m: 전압강하가 규격 결정 (허용치 5% + 100m 초과분 0.5%)
+        let long = design_circuit(&CircuitDesignInput { length: 400.0, ..input.clone() }).unwrap();
+        assert_eq!((long.size.as_deref(), long.binding_constraint.as_deref()), (Some("50"), Some("전압강하")));
+        assert!(long.voltage_drop_percent.unwrap() <= long.voltage_drop_limit);
+
+        // 단락전류 10kA / 1초: k = 143 → 70mm² (10.01kA)
+        let fault = design_circuit(&CircuitDesignInput { fault_current_ka: Some(10.0), ..input.clone() }).unwrap();
+        assert_eq!((fault.size.as_deref(), fault.binding_constraint.as_deref()), (Some("70"), Some("단락용량")));
+        assert_eq!(fault.short_circuit_capacity_ka, Some(10.01));
+
+        // 해가 없으면 병렬 포설·거리 단축 제안
+        let huge = design_circuit(&CircuitDesignInput { load_kw: Some(1500.0), ..input.clone() }).unwrap();
+        assert!(huge.size.is_none() && huge.breaker_rating.is_none());
+        assert!(huge.suggestions.iter().any(|s| s.starts_with("병렬 포설")));
+        let far = design_circuit(&CircuitDesignInput { length: 3000.0, ..input.clone() }).unwrap();
+        assert!(far.size.is_none());
+        assert!(far.suggestions.iter().any(|s| s.starts_with("거리 단축")));
+
+        // 영문 입력은 제약 이름·제안도 영문
+        let english = CircuitDesignInput { lang: Some("en".to_string()), ..input.clone() };
+        let result = design_circuit(&english).unwrap();
+        assert_eq!(result.binding_constraint.as_deref(), Some("Protection coordination"));
+        let constraints: Vec<&str> = result.checks.iter().map(|c| c.constraint.as_str()).collect();
+        assert_eq!(constraints, ["Ampacity", "Protection coordination", "Voltage drop"]);
+        let huge = design_circuit(&CircuitDesignInput { load_kw: Some(1500.0), ..english }).unwrap();
+        assert!(!huge.suggestions.is_empty());
+        assert!(huge.suggestions.iter().all(|s| !contains_hangul(s)), "{:?}", huge.suggestions);
+
+        // 알루미늄은 k = 94 (XLPE)로 단락용량 검토: 10kA / 1초 → 120mm² (11.28kA)
+        let aluminium = CircuitDesignInput {
+            fault_current_ka: Some(10.0),
+            conductor_material: Some("Al".to_string()),
+            ..input.clone()
+        };
+        let result = design_circuit(&aluminium).unwrap();
+        assert_eq!((result.size.as_deref(), result.binding_constraint.as_deref()), (Some("120"), Some("단락용량")));
+        assert_eq!(result.short_circuit_capacity_ka, Some(11.28));
+
+        assert!(design_circuit(&CircuitDesignInput { load_kw: None, ..input.clone() }).is_err());
+        assert!(design_circuit(&CircuitDesignInput { install_method: "G".to_string(), ..input }).is_err());
+    }
+
+    #[test]
+    fn temperature_factor_table_with_interpolation() {
+        assert_eq!(get_temperature_factor("XLPE", 30, false, "ko"), Ok(1.0));
+        assert_eq!(get_temperature_factor("PVC", 40, false, "ko"), Ok(0.87));
+        assert_eq!(get_temperature_factor("XLPE", 20, true, "ko"), Ok(1.0));
+        assert_eq!(get_temperature_factor("XLPE", 30, true, "ko"), Ok(0.93));
+        // 37°C: 0.96 + (0.91 - 0.96) × 2/5 = 0.94
+        assert_eq!(get_temperature_factor("XLPE", 37, false, "ko"), Ok(0.94));
+        // PVC 표는 60°C까지, XLPE는 80°C까지
+        assert!(get_temperature_factor("PVC", 65, false, "ko").is_err());
+        assert_eq!(get_temperature_factor("XLPE", 65, false, "ko"), Ok(0.65));
+        assert!(get_temperature_factor("XLPE", 81, false, "ko").is_err());
+        assert!(get_temperature_factor("XLPE", 5, false, "ko").is_err());
+        assert!(get_temperature_factor("EPR", 30, false, "ko").is_err());
+        assert_eq!(
+            get_temperature_factor("XLPE", 81, false, "en"),
+            Err(localize_error(ERR_AMBIENT_TEMP_OUT_OF_RANGE, "en"))
+        );
+
+        // XLPE B2 16mm² 82A × 0.91(40°C) = 74.6A, D1은 지중 표 적용 77A × 0.85 = 65.5A
+        let hot = CableData { ambient_temp: Some(40), ..sample("3C", "3Φ") };
+        let result = calculate_core(&hot).unwrap();
+        assert_eq!(result.allowable_current, 74.6);
+        assert!(result.formulas[0].contains("0.91(온도)"));
+        let buried = CableData { install_method: "D1".to_string(), ..hot.clone() };
+        assert_eq!(calculate_core(&buried).unwrap().allowable_current, 65.5);
+        assert_eq!(
+            calculate_core(&CableData { ambient_temp: Some(90), ..hot }).unwrap_err(),
+            localize_error(ERR_AMBIENT_TEMP_OUT_OF_RANGE, "ko")
+        );
+    }
+
+    #[test]
+    fn recommend_cable_size_from_load_current() {
+        // XLPE B1 3부하: 16mm² 88A < 100A ≤ 25mm² 117A
+        let data = recommend_cable_size(100.0, "CV", "1C", "3Φ", "B1", 30, "ko").unwrap();
+        assert_eq!((data.size.as_str(), data.quantity), ("25", 3));
+        assert_eq!(data.load_current, Some(100.0));
+        assert!(calculate_core(&data).unwrap().allowable_current >= 100.0);
+
+        // 주변온도 50°C(0.82)면 25mm² 95.9A로 부족해 한 단계 위
+        assert_eq!(recommend_cable_size(100.0, "CV", "1C", "3Φ", "B1", 50, "ko").unwrap().size, "35");
+
+        assert_eq!(
+            recommend_cable_size(5000.0, "CV", "1C", "3Φ", "B1", 30, "ko").unwrap_err(),
+            localize_error(ERR_PARALLEL_CONDUCTORS_REQUIRED, "ko")
+        );
+        assert_eq!(
+            recommend_cable_size(0.0, "CV", "1C", "3Φ", "B1", 30, "ko").unwrap_err(),
+            localize_error(ERR_INVALID_LOAD_CURRENT, "ko")
+        );
+        assert_eq!(
+            recommend_cable_size(100.0, "CV", "1C", "3Φ", "B1", 95, "ko").unwrap_err(),
+            localize_error(ERR_AMBIENT_TEMP_OUT_OF_RANGE, "ko")
+        );
+        assert_eq!(
+            recommend_cable_size(5000.0, "CV", "1C", "3Φ", "B1", 30, "en").unwrap_err(),
+            localize_error(ERR_PARALLEL_CONDUCTORS_REQUIRED, "en")
+        );
+        assert_eq!(
+            recommend_cable_size(100.0, "CV", "1C", "3Φ", "B1", 95, "en").unwrap_err(),
+            localize_error(ERR_AMBIENT_TEMP_OUT_OF_RANGE, "en")
+        );
+    }
+
+    #[test]
+    fn aluminium_conductor_uses_reduced_table() {
+        let copper = calculate_core(&sample("3C", "3Φ")).unwrap();
+        let aluminium = CableData { conductor_material: Some("Al".to_string()), ..sample("3C", "3Φ") };
+        // XLPE B2 16mm² 3부하: 동 82A → 알루미늄 64A (Table B.52.5 알루미늄 열)
+        let result = calculate_core(&aluminium).unwrap();
+        assert_eq!(result.allowable_current, 64.0);
+        assert!(result.allowable_current < copper.allowable_current);
+        assert!(result.install_method_desc.contains("알루미늄"));
+        assert_eq!(result.reference, "IEC 60364-5-52 Table B.52.5 / KEC 232 (알루미늄)");
+        assert!(!copper.reference.contains("알루미늄"));
+        // 재질 누락·빈 값은 동으로 처리
+        let blank = CableData { conductor_material: Some(" ".to_string()), ..sample("3C", "3Φ") };
+        assert_eq!(calculate_core(&blank).unwrap().allowable_current, copper.allowable_current);
+
+        // 알루미늄은 16mm² 미만 데이터 없음, 알 수 없는 재질은 에러
+        let small = CableData { size: "10".to_string(), ..aluminium.clone() };
+        let error = calculate_core(&small).unwrap_err();
+        assert!(error.starts_with(&localize_error(ERR_CURRENT_DATA_NOT_FOUND, "ko")));
+        assert!(error.contains("16mm²"));
+        let unknown = CableData { conductor_material: Some("Fe".to_string()), ..sample("3C", "3Φ") };
+        assert_eq!(calculate_core(&unknown).unwrap_err(), localize_error(ERR_INVALID_MATERIAL, "ko"));
+
+        // 전압강하는 알루미늄 고유저항(1/35) 적용으로 동보다 큼
+        let input = VoltageDropInput {
+            length: 100.0,
+            current: Some(50.0),
+            voltage: 380.0,
+            power_factor: None,
+            transformer: None,
+            installation_type: None,
+            is_branch: false,
+        };
+        let cu_drop = calculate_voltage_drop(&sample("3C", "3Φ"), &input).unwrap();
+        let al_drop = calculate_voltage_drop(&aluminium, &input).unwrap();
+        assert!(al_drop.cable_drop > cu_drop.cable_drop * 1.5);
+    }
+
+    #[test]
+    fn aluminium_table_matches_published_columns() {
+        let table = get_allowable_current_table_al();
+        let get = |size, insulation, method| table.get(&(Size(size), insulation, method)).copied();
+        // Table B.52.2/B.52.4 (PVC) 알루미늄 열
+        assert_eq!(get("16", Insulation::Pvc, InstallMethod::A1), Some((48.0, 43.0)));
+        assert_eq!(get("300", Insulation::Pvc, InstallMethod::D1), Some((296.0, 247.0)));
+        // Table B.52.3/B.52.5 (XLPE): C 25mm² 이상은 동 대비 비율이 일정하지 않음 (정수배 환산 불가)
+        assert_eq!(get("25", Insulation::Xlpe, InstallMethod::C), Some((101.0, 90.0)));
+        assert_eq!(get("95", Insulation::Xlpe, InstallMethod::B2), Some((210.0, 188.0)));
+        // Table B.52.11/B.52.13 자유 공기 중, F 단심은 25mm²부터
+        assert_eq!(get("240", Insulation::Xlpe, InstallMethod::E), Some((470.0, 384.0)));
+        assert_eq!(get("25", Insulation::Pvc, InstallMethod::F), Some((98.0, 84.0)));
+        assert_eq!(get("16", Insulation::Xlpe, InstallMethod::F), None);
+        // 알루미늄 열이 없는 규격
+        assert_eq!(get("10", Insulation::Xlpe, InstallMethod::B2), None);
+        assert_eq!(get("400", Insulation::Xlpe, InstallMethod::B2), None);
+
+        let al_tray = CableData {
+            conductor_material: Some("Al".to_string()),
+            install_method: "F".to_string(),
+            size: "240".to_string(),
+            ..sample("3C", "3Φ")
+        };
+        assert_eq!(calculate_core(&al_tray).map(|r| r.reference), Ok( "IEC 60364-5-52 Table B.52.13 / KEC 232 (알루미늄)".to_string()));
+    }
+
+    #[test]
+    fn kec_fill_limit_depends_on_cable_count() {
+        assert_eq!(Regulation::Kec.conduit_fill_limit(1), 0.53);
+        assert_eq!(Regulation::Kec.conduit_fill_limit(2), 0.31);
+        assert_eq!(Regulation::Kec.conduit_fill_limit(3), 0.4);
+        assert_eq!(Regulation::Kec.conduit_fill_limit(7), 0.4);
+
+        // 1본·2본 역전: 2본은 1본보다 상한이 낮아 같은 면적이라도 더 큰 관이 필요
+        let c28 = &conduit_table(ConduitType::Steel)[2];
+        let area = c28.area * 0.4;
+        assert_eq!(recommend_conduit(ConduitType::Steel, area, Regulation::Kec.conduit_fill_limit(1), 0.0).name, c28.name);
+        assert_ne!(recommend_conduit(ConduitType::Steel, area, Regulation::Kec.conduit_fill_limit(2), 0.0).name, c28.name);
+
+        // 결과의 점유율은 적용 상한 이하이고, 상한은 접지선 포함 본수로 결정
+        let single = calculate_core(&sample("3C", "3Φ")).unwrap();
+        assert_eq!(single.recommended_raceway.fill_limit, 53.0);
+        let with_ground = calculate_core(&CableData { ground_wire: "HFIX".to_string(), ..sample("3C", "3Φ") }).unwrap();
+        assert_eq!(with_ground.recommended_raceway.fill_limit, 31.0);
+        let three = calculate_core(&CableData { quantity: 3, ..sample("1C", "3Φ") }).unwrap();
+        assert_eq!(three.recommended_raceway.fill_limit, 40.0);
+        for result in [&single, &with_ground, &three] {
+            assert!(result.fill_rate <= result.recommended_raceway.fill_limit);
+        }
+    }
+
+    #[test]
+    fn protective_conductor_follows_kec_542_3() {
+        // S ≤ 16 -> S, 16 < S ≤ 35 -> 16, S > 35 -> S/2 (표준 규격 올림)
+        assert_eq!(get_protective_conductor_size("10", "Cu"), "10");
+        assert_eq!(get_protective_conductor_size("16", "Cu"), "16");
+        assert_eq!(get_protective_conductor_size("25", "Cu"), "16");
+        assert_eq!(get_protective_conductor_size("35", "Cu"), "16");
+        assert_eq!(get_protective_conductor_size("95", "Cu"), "50");
+        assert_eq!(get_protective_conductor_size("400", "Cu"), "240");
+        assert_eq!(get_protective_conductor_size("500", "Cu"), "300");
+        // 알루미늄 상도체: 동 보호도체로 환산 (240 -> 120 × 94/143 ≈ 78.9 -> 95)
+        assert_eq!(get_protective_conductor_size("16", "Al"), "16");
+        assert_eq!(get_protective_conductor_size("240", "al"), "95");
+        assert_eq!(get_protective_conductor_size("abc", "Cu"), "");
+        assert_eq!(get_protective_conductor_size("16", "Fe"), "");
+
+        let grounded = calculate_core(&CableData { size: "95".to_string(), ground_wire: "HFIX".to_string(), ..sample("3C", "3Φ") }).unwrap();
+        assert_eq!(grounded.ground_wire_size, "50");
+        assert_eq!(calculate_core(&sample("3C", "3Φ")).unwrap().ground_wire_size, "");
+    }
+
+    #[test]
+    fn short_circuit_withstand_checks_i2t() {
+        // 16mm² 동 XLPE: 1초 허용 143 × 16 = 2288A
+        assert_eq!(check_short_circuit_withstand("16", "Cu", "XLPE", 2.28, 1.0, "ko"), Ok(true));
+        assert_eq!(check_short_circuit_withstand("16", "Cu", "XLPE", 2.3, 1.0, "ko"), Ok(false));
+        assert_eq!(check_short_circuit_withstand("16", "Cu", "PVC", 2.0, 1.0, "ko"), Ok(false));
+        assert_eq!(check_short_circuit_withstand("16", "Cu", "PVC", 2.0, 0.5, "ko"), Ok(true));
+        assert_eq!(check_short_circuit_withstand("16", "Al", "XLPE", 2.0, 1.0, "ko"), Ok(false));
+        assert_eq!(
+            check_short_circuit_withstand("16", "Cu", "XLPE", 0.0, 1.0, "ko").unwrap_err(),
+            localize_error(ERR_INVALID_FAULT_CURRENT, "ko")
+        );
+        assert!(check_short_circuit_withstand("16", "Cu", "XLPE", 2.0, 6.0, "ko").is_err());
+        assert_eq!(
+            check_short_circuit_withstand("16", "Cu", "XLPE", 0.0, 1.0, "en").unwrap_err(),
+            localize_error(ERR_INVALID_FAULT_CURRENT, "en")
+        );
+
+        // 결과에는 단락전류 지정 시에만 포함
+        let plain = calculate_core(&sample("3C", "3Φ")).unwrap();
+        assert_eq!((plain.short_circuit_ok, plain.short_circuit_min_size), (None, None));
+        let faulted = CableData { fault_current: Some(5.0), clearing_time: Some(0.04), ..sample("3C", "3Φ") };
+        let result = calculate_core(&faulted).unwrap();
+        assert_eq!((result.short_circuit_ok, result.short_circuit_min_size), (Some(true), Some(6.99)));
+        assert!(result.formulas.contains(&"단락 최소 단면적 = 5000A × √0.04s ÷ 143 = 6.99mm²".to_string()));
+
+        // 차단 시간만 바뀌어도 재검증
+        let slow = CableData { clearing_time: Some(1.0), ..faulted.clone() };
+        let partial = recalculate_partial(&faulted, &result, &slow).unwrap();
+        assert_eq!(partial.result.short_circuit_ok, Some(false));
+        assert_eq!(partial.result.short_circuit_min_size, Some(34.97));
+    }
+
+    #[test]
+    fn calculate_result_matches_core_without_gui() {
+        let data = sample("3C", "3Φ");
+        let result = calculate_result(&data).unwrap();
+        let core = calculate_core(&data).unwrap();
+        assert_eq!((result.allowable_current, result.fill_rate), (core.allowable_current, core.fill_rate));
+        assert_eq!(result.recommended_raceway.name, core.recommended_raceway.name);
+        let error = calculate_result(&CableData { size: "999".to_string(), ..data }).unwrap_err();
+        assert_eq!(error.code, CalcErrorCode::UnsupportedSize);
+        assert_eq!(error.message, localize_error(ERR_UNSUPPORTED_SIZE, "ko"));
+    }
+
+    #[test]
+    fn lookup_tables_are_built_once() {
+        assert!(std::ptr::eq(get_allowable_current_table(), get_allowable_current_table()));
+        assert!(std::ptr::eq(get_allowable_current_table_al(), get_allowable_current_table_al()));
+        assert!(std::ptr::eq(outer_diameter_tables(), outer_diameter_tables()));
+        assert_eq!(get_cable_outer_diameter("CV", "16", "3C"), Some(20.0 * 1.15));
+
+        // 활성 테이블은 내장 테이블 복사본이며 버전 변경 시에만 재구성
+        let active = active_current_table();
+        assert_eq!(active.get(&(Size("16"), Insulation::Xlpe, InstallMethod::B2)), Some(&(91.0, 82.0)));
+        assert_eq!(active_current_table_for(Material::Aluminium).get(&(Size("16"), Insulation::Xlpe, InstallMethod::B2)), Some(&(72.0, 64.0)));
+    }
+
+    #[test]
+    fn batch_keeps_order_and_reports_failed_items() {
+        let single = CableData { quantity: 3, ..sample("1C", "3Φ") };
+        let items = vec![
+            sample("3C", "3Φ"),
+            CableData { size: "999".to_string(), ..sample("3C", "3Φ") },
+            single,
+        ];
+        let results = calculate_batch(&items);
+        assert_eq!(results.len(), 3);
+        assert!(results[0].is_ok() && results[2].is_ok());
+        assert_eq!(
+            results[1].as_ref().unwrap_err(),
+            &format!("#2 (CV 3C 999mm²): {}", localize_error(ERR_UNSUPPORTED_SIZE, "ko"))
+        );
+
+        // 단심 3가닥은 1회로, 도체 단면적은 16 + 16×3
+        let batch = calculate_batch_with_summary(&items);
+        assert_eq!(
+            batch.summary,
+            BatchSummary { total: 3, succeeded: 2, failed: 1, circuit_count: 2, total_conductor_area: 64.0, ..Default::default() }
+        );
+        // 물량 합계는 회로별 compute_lengths 결과의 합 (2런 접지선은 런마다 1가닥)
+        let lengths = vec![
+            CableData { length: 20.0, ground_wire: "HFIX".to_string(), parallel_runs: 2, ..sample("3C", "3Φ") },
+            CableData { length: 10.0, quantity: 3, ..sample("1C", "3Φ") },
+        ];
+        let summary = calculate_batch_with_summary(&lengths).summary;
+        assert_eq!((summary.total_cable_length, summary.total_ground_wire_length), (70.0, 40.0));
+        assert!(calculate_batch(&[]).is_empty());
+    }
+
+    #[test]
+    fn results_csv_has_bom_and_escapes_fields() {
+        assert_eq!(escape_csv_field("CV"), "CV");
+        assert_eq!(escape_csv_field("A, B"), "\"A, B\"");
+        assert_eq!(escape_csv_field("12\" 관"), "\"12\"\" 관\"");
+        assert_eq!(escape_csv_field("A\rB"), "\"A\rB\"");
+        assert_eq!(escape_csv_field("A\nB"), "\"A\nB\"");
+
+        let data = CableData {
+            install_method: "B2".to_string(),
+            manufacturer: Some("대한, 전선".to_string()),
+            load_current: Some(50.0),
+            ..sample("3C", "3Φ")
+        };
+        let result = calculate_core(&data).unwrap();
+        let csv = export_results_csv(std::slice::from_ref(&data), std::slice::from_ref(&result)).unwrap();
+        assert!(csv.starts_with("\u{feff}전선종류,가닥수,규격(mm²),"));
+        let lines: Vec<&str> = csv.trim_end().split("\r\n").collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[1].starts_with("CV,3C,16,1,3Φ,B2,없음,50.0,"));
+        assert!(lines[1].ends_with(",\"대한, 전선\","));
+
+        assert_eq!(
+            export_results_csv(std::slice::from_ref(&data), &[]).unwrap_err(),
+            localize_error(ERR_EXPORT_LENGTH_MISMATCH, "ko")
+        );
+        let english = CableData { lang: Some("en".to_string()), ..data };
+        assert_eq!(
+            export_results_csv(&[english], &[]).unwrap_err(),
+            localize_error(ERR_EXPORT_LENGTH_MISMATCH, "en")
+        );
+    }
+
+    #[test]
+    fn calc_error_serializes_code_and_message() {
+        // 모든 에러 키는 고유 코드로 왕복 변환
+        for (key, _, _) in ERROR_MESSAGES {
+            let code = CalcErrorCode::from_key(key);
+            assert_ne!(code, CalcErrorCode::Unknown, "{}", key);
+            assert_eq!(code.key(), *key);
+        }
+        assert_eq!(CalcErrorCode::from_key("no_such_key"), CalcErrorCode::Unknown);
+
+        let error = calculate_result(&CableData { cores: "2C".to_string(), ..sample("2C", "3Φ") }).unwrap_err();
+        assert_eq!(error.code, CalcErrorCode::Cores2CInThreePhase);
+        let json = serde_json::to_value(&error).unwrap();
+        assert_eq!(json["code"], "cores_2c_in_three_phase");
+        assert_eq!(json["message"], localize_error(ERR_2C_IN_THREE_PHASE, "ko"));
+        assert_eq!(serde_json::from_value::<CalcError>(json).unwrap(), error);
+
+        let english = CalcError::new(ERR_UNSUPPORTED_SIZE, "en");
+        assert_eq!(english.to_string(), localize_error(ERR_UNSUPPORTED_SIZE, "en"));
+    }
+
+    #[test]
+    fn grouping_factor_depends_on_arrangement() {
+        // 대표값: 2·4·9회로
+        let factors = |arrangement| [2, 4, 9].map(|n| get_grouping_factor(n, arrangement));
+        assert_eq!(factors(Arrangement::Conduit), [0.80, 0.65, 0.50]);
+        assert_eq!(factors(Arrangement::SingleLayerTray), [0.88, 0.77, 0.72]);
+        assert_eq!(factors(Arrangement::MultiLayerTray), [0.86, 0.71, 0.61]);
+        assert_eq!(factors(Arrangement::Spaced), [1.00, 0.95, 0.91]);
+        assert_eq!(get_grouping_factor(20, Arrangement::MultiLayerTray), 0.38);
+        for arrangement in [Arrangement::Conduit, Arrangement::SingleLayerTray, Arrangement::MultiLayerTray, Arrangement::Spaced] {
+            assert_eq!(get_grouping_factor(1, arrangement), 1.0);
+        }
+
+        // 3C 2회로 XLPE 82A: 묶음 0.80 -> 65.6A, 간격 유지 1.00 -> 82A
+        let grouped = CableData { quantity: 2, ..sample("3C", "3Φ") };
+        assert_eq!(calculate_core(&grouped).unwrap().allowable_current, 65.6);
+        let spaced = CableData { arrangement: Some(Arrangement::Spaced), ..grouped.clone() };
+        let result = calculate_core(&spaced).unwrap();
+        assert_eq!(result.allowable_current, 82.0);
+        assert!(result.install_method_desc.contains("배치: 간격 유지"));
+        let tray = CableData { arrangement: Some(Arrangement::SingleLayerTray), ..grouped };
+        assert_eq!(calculate_core(&tray).unwrap().allowable_current, 72.2);
+    }
+
+    #[test]
+    fn burial_depth_is_reported_but_not_applied() {
+        // 깊이 보정표는 출처가 없어 적용하지 않음: XLPE D1 16mm² 3부하 77A 그대로
+        let buried = CableData { install_method: "D1".to_string(), ..sample("3C", "3Φ") };
+        assert_eq!(calculate_core(&buried).unwrap().allowable_current, 77.0);
+        let deep = CableData { burial_depth_m: Some(1.5), ..buried.clone() };
+        let result = calculate_core(&deep).unwrap();
+        assert_eq!(result.allowable_current, 77.0);
+        assert!(!result.formulas[0].contains("매설 깊이"));
+        assert!(result.install_method_desc.contains("매설 깊이 1.5m (보정 미적용, 0.7m 기준 표 값)"));
+
+        // 공기 중 공사방법은 깊이를 무시하고 설명에 표시
+        let air = CableData { burial_depth_m: Some(1.5), ..sample("3C", "3Φ") };
+        let result = calculate_core(&air).unwrap();
+        assert_eq!(result.allowable_current, calculate_core(&sample("3C", "3Φ")).unwrap().allowable_current);
+        assert!(result.install_method_desc.contains("매설 깊이 무시"));
+
+        assert_eq!(
+            calculate_core(&CableData { burial_depth_m: Some(0.0), ..buried }).unwrap_err(),
+            localize_error(ERR_INVALID_BURIAL_DEPTH, "ko")
+        );
+    }
+
+    #[test]
+    fn soil_resistivity_factor_by_method() {
+        // 기준 2.5 K·m/W는 1.0, D1(덕트)·D2(직매) 열 구분
+        assert_eq!(get_soil_resistivity_factor(2.5, "D1"), 1.0);
+        assert_eq!(get_soil_resistivity_factor(2.5, "D2"), 1.0);
+        assert_eq!(get_soil_resistivity_factor(1.0, "D1"), 1.18);
+        assert_eq!(get_soil_resistivity_factor(1.0, "D2"), 1.5);
+        assert_eq!(get_soil_resistivity_factor(3.0, "D2"), 0.9);
+        assert_eq!(get_soil_resistivity_factor(2.75, "D1"), 0.98);
+        assert_eq!(get_soil_resistivity_factor(0.5, "D2"), 1.5);
+        assert_eq!(get_soil_resistivity_factor(1.0, "B2"), 1.0);
+        assert_eq!(get_soil_resistivity_factor(1.0, "Z9"), 1.0);
+
+        let buried = CableData { install_method: "D1".to_string(), ..sample("3C", "3Φ") };
+        let standard = CableData { soil_resistivity: Some(2.5), ..buried.clone() };
+        assert_eq!(calculate_core(&standard).unwrap().allowable_current, 77.0);
+
+        // 지중 온도(25°C 0.96)·토양(1.5 K·m/W 1.10) 계수는 각각 한 번씩만 곱하고 깊이는 보정하지 않음
+        // 77A × 0.96 × 1.10 = 81.3A
+        let combined = CableData {
+            ambient_temp: Some(25),
+            burial_depth_m: Some(1.0),
+            soil_resistivity: Some(1.5),
+            ..buried.clone()
+        };
+        let result = calculate_core(&combined).unwrap();
+        assert_eq!(result.allowable_current, 81.3);
+        for label in ["(온도)", "(토양)"] {
+            assert_eq!(result.formulas[0].matches(label).count(), 1, "{}", result.formulas[0]);
+        }
+
+        // 공기 중 공사방법은 토양 열저항률을 무시
+        let air = CableData { soil_resistivity: Some(1.0), ..sample("3C", "3Φ") };
+        let result = calculate_core(&air).unwrap();
+        assert_eq!(result.allowable_current, calculate_core(&sample("3C", "3Φ")).unwrap().allowable_current);
+        assert!(result.install_method_desc.contains("토양 열저항률 무시"));
+
+        assert_eq!(
+            calculate_core(&CableData { soil_resistivity: Some(-1.0), ..buried }).unwrap_err(),
+            localize_error(ERR_INVALID_SOIL_RESISTIVITY, "ko")
+        );
+    }
+
+    #[test]
+    fn harmonic_factor_for_four_core_three_phase() {
+        assert_eq!(get_harmonic_factor(10.0), (1.0, false));
+        assert_eq!(get_harmonic_factor(15.0), (1.0, false));
+        assert_eq!(get_harmonic_factor(25.0), (0.86, false));
+        assert_eq!(get_harmonic_factor(40.0), (0.86, true));
+        assert_eq!(get_harmonic_factor(50.0), (1.0, true));
+
+        // XLPE B2 16mm² 3부하 82A × 0.86 = 70.5A (상전류 기준)
+        let four_core = sample("4C", "3Φ");
+        let phase = CableData { third_harmonic_percent: Some(25.0), ..four_core.clone() };
+        let result = calculate_core(&phase).unwrap();
+        assert_eq!(result.allowable_current, 70.5);
+        assert!(result.install_method_desc.contains("3고조파 25% (계수 0.86)"));
+        assert!(result.formulas[0].contains("0.86(고조파)"));
+
+        // 45% 초과는 중성선 기준: 중성선 전류 = 상전류 × 1.5 → 82A ÷ 1.5 = 54.7A
+        let neutral = CableData { third_harmonic_percent: Some(50.0), ..four_core.clone() };
+        let result = calculate_core(&neutral).unwrap();
+        assert_eq!(result.allowable_current, 54.7);
+        assert!(result.install_method_desc.contains("중성선 기준"));
+        assert!(result.formulas[0].contains("(중성선 환산)"));
+
+        // 4C 3상이 아니면 무시
+        let three_core = CableData { third_harmonic_percent: Some(50.0), ..sample("3C", "3Φ") };
+        let result = calculate_core(&three_core).unwrap();
+        assert_eq!(result.allowable_current, 82.0);
+        assert!(result.install_method_desc.contains("고조파 보정 무시"));
+
+        assert_eq!(
+            calculate_core(&CableData { third_harmonic_percent: Some(120.0), ..four_core }).unwrap_err(),
+            localize_error(ERR_INVALID_HARMONIC_PERCENT, "ko")
+        );
+    }
+
+    #[test]
+    fn parallel_runs_multiply_capacity_and_conductors() {
+        let single = calculate_core(&sample("3C", "3Φ")).unwrap();
+        // 2런 병렬: 집합 2회로 0.80 적용 후 2배, 82A × 0.80 × 2 = 131.2A
+        let parallel = CableData { parallel_runs: 2, length: 50.0, ..sample("3C", "3Φ") };
+        let result = calculate_core(&parallel).unwrap();
+        assert_eq!(result.allowable_current, 131.2);
+        assert!(result.formulas[0].contains("2.00(병렬)"));
+        assert!(result.install_method_desc.contains("병렬 2런 (각 런의 규격·길이 동일 필요)"));
+        assert_eq!(result.conductor_area, single.conductor_area * 2.0);
+        assert!(result.effective_area > single.effective_area);
+        assert_eq!(result.total_cable_length, 100.0);
+
+        // 전압강하는 임피던스 1/n
+        let input = VoltageDropInput {
+            length: 100.0,
+            voltage: 380.0,
+            current: Some(60.0),
+            power_factor: None,
+            transformer: None,
+            installation_type: None,
+            is_branch: false,
+        };
+        let one = calculate_voltage_drop(&sample("3C", "3Φ"), &input).unwrap();
+        let two = calculate_voltage_drop(&parallel, &input).unwrap();
+        assert!((two.cable_drop - one.cable_drop / 2.0).abs() < 0.01);
+        // 다심 수량은 별도 회로 수이므로 병렬 런만 반영 (2회로 × 2런도 1/2)
+        let grouped = CableData { quantity: 2, ..parallel.clone() };
+        let grouped_drop = calculate_voltage_drop(&grouped, &input).unwrap();
+        assert!((grouped_drop.cable_drop - one.cable_drop / 2.0).abs() < 0.01);
+        // 1C는 상별 묶음(3Φ 6가닥 = 상당 2가닥) × 병렬 런
+        let single_core = CableData { quantity: 6, parallel_runs: 2, ..sample("1C", "3Φ") };
+        let single_core_one = CableData { quantity: 3, ..sample("1C", "3Φ") };
+        let ratio = calculate_voltage_drop(&single_core, &input).unwrap().cable_drop
+            / calculate_voltage_drop(&single_core_one, &input).unwrap().cable_drop;
+        assert!((ratio - 0.25).abs() < 0.01);
+
+        // 기본값은 1, 0은 에러
+        let parsed: CableData = serde_json::from_str(
+            r#"{"cable_type":"CV","cores":"3C","size":"16","quantity":1,"system":"3Φ","ground_wire":"없음","install_method":""}"#,
+        )
+        .unwrap();
+        assert_eq!(parsed.parallel_runs, 1);
+        assert_eq!(
+            calculate_core(&CableData { parallel_runs: 0, ..sample("3C", "3Φ") }).unwrap_err(),
+            localize_error(ERR_INVALID_PARALLEL_RUNS, "ko")
+        );
+    }
+
+    #[test]
+    fn cable_data_validation_rejects_bad_input() {
+        assert!(validate_cable_data(&sample("3C", "3Φ")).is_ok());
+        assert!(validate_cable_data(&CableData { size: "2.5".to_string(), ..sample("3C", "3Φ") }).is_ok());
+        let code = |data: CableData| validate_cable_data(&data).unwrap_err().code;
+        assert_eq!(code(CableData { quantity: 0, ..sample("3C", "3Φ") }), CalcErrorCode::InvalidQuantity);
+        assert_eq!(code(CableData { cable_type: "XYZ".to_string(), ..sample("3C", "3Φ") }), CalcErrorCode::UnknownCableType);
+        assert_eq!(code(sample("5C", "3Φ")), CalcErrorCode::InvalidCores);
+        assert_eq!(code(CableData { size: "abc".to_string(), ..sample("3C", "3Φ") }), CalcErrorCode::InvalidSize);
+        assert_eq!(code(CableData { size: "".to_string(), ..sample("3C", "3Φ") }), CalcErrorCode::InvalidSize);
+        assert_eq!(code(CableData { size: "3".to_string(), ..sample("3C", "3Φ") }), CalcErrorCode::UnsupportedSize);
+        assert_eq!(
+            code(CableData { install_method: "B2".to_string(), ..sample("1C", "3Φ") }),
+            CalcErrorCode::InstallMethodCoresMismatch
+        );
+        assert_eq!(
+            code(CableData { install_method: "B1".to_string(), ..sample("3C", "3Φ") }),
+            CalcErrorCode::InstallMethodCoresMismatch
+        );
+        assert!(validate_cable_data(&CableData { install_method: "D1".to_string(), ..sample("1C", "3Φ") }).is_ok());
+        // 트레이: 단심은 E, 다심은 F만 허용 (install_methods_for_cores와 같은 기준)
+        for (cores, method) in [("1C", "F"), ("1C", "D2"), ("3C", "E")] {
+            let data = CableData { install_method: method.to_string(), ..sample(cores, "3Φ") };
+            assert_eq!(code(data), CalcErrorCode::InstallMethodCoresMismatch, "{} {}", cores, method);
+        }
+        assert_eq!(
+            code(CableData { install_method: "G".to_string(), ..sample("3C", "3Φ") }),
+            CalcErrorCode::InvalidInstallMethod
+        );
+
+        // calculate도 같은 검증을 거침
+        assert_eq!(
+            calculate_core(&CableData { quantity: 0, ..sample("3C", "3Φ") }).unwrap_err(),
+            localize_error(ERR_INVALID_QUANTITY, "ko")
+        );
+    }
+
+    #[test]
+    fn table_sizes_always_parse_as_area() {
+        // 외경·허용전류 테이블에 있는 규격이 숫자로 해석되지 않으면 도체 단면적이 틀어지므로 불일치 차단
+        assert!(STANDARD_SIZES.iter().all(|size| size.parse::<f64>().is_ok_and(|mm2| mm2 > 0.0)));
+        let tables = outer_diameter_tables();
+        for table in [
+            &tables.tfr_cv_1c,
+            &tables.tfr_cv_2c,
+            &tables.hfix_1c,
+            &tables.cv_1c,
+            &tables.cv_2c,
+            &tables.fr_cv_1c,
+            &tables.fr_cv_2c,
+            &tables.fr_cv_3c,
+            &tables.fr_cv_4c,
+            &tables.tfr_8_1c,
+            &tables.tfr_8_2c,
+            &tables.tfr_8_3c,
+            &tables.tfr_8_4c,
+        ] {
+            for size in table.keys() {
+                assert!(STANDARD_SIZES.contains(size), "{}", size);
+            }
+        }
+
+        // 해석할 수 없는 규격은 0mm²가 아니라 에러
+        let garbled = CableData { size: "16mm".to_string(), ..sample("3C", "3Φ") };
+        assert_eq!(compute_fill(&garbled, Regulation::Kec).unwrap_err(), ERR_INVALID_SIZE);
+        assert_eq!(calculate_core(&garbled).unwrap_err(), localize_error(ERR_INVALID_SIZE, "ko"));
+        assert!(check_vertical_run(&garbled, 10.0).is_err());
+        assert_eq!(calculate_core(&sample("3C", "3Φ")).unwrap().conductor_area, 16.0);
+    }
+
+    #[test]
+    fn tray_methods_recommend_tray_width() {
+        assert_eq!(recommend_cable_tray(80.0, 4), ("트레이 100mm (단층 4본)".to_string(), 80.0));
+        assert_eq!(recommend_cable_tray(250.0, 10), ("트레이 300mm (단층 10본)".to_string(), 83.3));
+        assert_eq!(recommend_cable_tray(100.0, 5).0, "트레이 100mm (단층 5본)");
+        assert_eq!(recommend_cable_tray(720.0, 20), ("트레이 600mm 초과 (단층 20본)".to_string(), 120.0));
+
+        // CV 3C 16mm² 외경 2C × 1.15, 6가닥이면 외경 합으로 트레이 폭 선정
+        let od = get_cable_outer_diameter("CV", "16", "3C").unwrap();
+        let data = CableData { install_method: "F".to_string(), quantity: 6, ..sample("3C", "3Φ") };
+        let result = calculate_core(&data).unwrap();
+        let (name, fill) = recommend_cable_tray(od * 6.0, 6);
+        assert_eq!(result.recommended_raceway.kind, RacewayKind::Tray);
+        assert_eq!(result.recommended_raceway.inner_diameter, None);
+        assert_eq!((result.recommended_raceway.name.as_str(), result.fill_rate), (name.as_str(), fill));
+        assert!(result.formulas.iter().any(|f| f.contains("(트레이 폭)")));
+
+        // 전선관 공사는 기존대로 후강전선관
+        let conduit = calculate_core(&CableData { quantity: 6, ..sample("3C", "3Φ") }).unwrap();
+        assert_eq!(conduit.recommended_raceway.kind, RacewayKind::Conduit);
+        assert!(conduit.recommended_raceway.name.starts_with('C'));
+    }
+
+    #[test]
+    fn conduit_type_selects_inner_diameter_table() {
+        assert_eq!(get_conduit_data(ConduitType::Steel).len(), CONDUIT_DATA.len());
+        assert_eq!(get_conduit_data(ConduitType::Cd)[1], ("CD16", 16.0));
+        assert_eq!(get_conduit_data(ConduitType::Pf)[1], ("PF16", 16.0));
+        for conduit_type in ConduitType::ALL {
+            let data = get_conduit_data(conduit_type);
+            assert!(data.windows(2).all(|w| w[0].1 < w[1].1), "{:?}", conduit_type);
+            assert_eq!(conduit_table(conduit_type).len(), data.len());
+        }
+
+        // 같은 케이블도 관종 호칭으로 추천
+        let steel = calculate_core(&sample("3C", "3Φ")).unwrap();
+        assert!(steel.recommended_raceway.name.starts_with('C'));
+        let cd = calculate_core(&CableData { conduit_type: Some(ConduitType::Cd), ..sample("3C", "3Φ") }).unwrap();
+        assert!(cd.recommended_raceway.name.starts_with("CD"));
+        assert_eq!(cd.recommended_raceway.nominal, conduit_nominal(&cd.recommended_raceway.name));
+        let pf = calculate_core(&CableData { conduit_type: Some(ConduitType::Pf), ..sample("3C", "3Φ") }).unwrap();
+        assert!(pf.recommended_raceway.name.starts_with("PF"));
+
+        // 최대 규격 초과 안내도 관종 호칭
+        assert_eq!(recommend_conduit(ConduitType::Cd, 3000.0, 0.4, 0.0).name, "CD42 이상 검토 필요");
+        let parsed: CableData = serde_json::from_str(
+            r#"{"cable_type":"CV","cores":"3C","size":"16","quantity":1,"system":"3Φ","ground_wire":"없음","install_method":"","conduit_type":"thin-steel"}"#,
+        )
+        .unwrap();
+        assert_eq!(parsed.conduit_type, Some(ConduitType::ThinSteel));
+        assert!(calculate_core(&parsed).unwrap().recommended_raceway.name.starts_with('E'));
+    }
+
+    #[test]
+    fn candidates_cover_supported_sizes_in_order() {
+        let data = CableData { load_current: Some(80.0), ..sample("3C", "3Φ") };
+        let candidates = calculate_candidates(data.clone());
+        // CV 2C 외경 테이블은 300mm²까지
+        let sizes: Vec<&str> = candidates.iter().map(|c| c.size.as_str()).collect();
+        assert_eq!(sizes.first(), Some(&"1.5"));
+        assert_eq!(sizes.last(), Some(&"300"));
+        assert!(candidates.windows(2).all(|w| w[0].size.parse::<f64>().unwrap() < w[1].size.parse::<f64>().unwrap()));
+
+        // 선택 규격과 같은 계산, 16mm² 82A는 80A 대비 2.5% 여유, 10mm²는 부족
+        let selected = candidates.iter().find(|c| c.size == "16").unwrap();
+        assert_eq!(selected.allowable_current, calculate_core(&data).unwrap().allowable_current);
+        assert_eq!(selected.load_margin_percent, Some(2.5));
+        assert!(candidates.iter().find(|c| c.size == "10").unwrap().load_margin_percent.unwrap() < 0.0);
+        assert!(calculate_candidates(sample("3C", "3Φ")).iter().all(|c| c.load_margin_percent.is_none()));
+        assert!(calculate_candidates(CableData { cable_type: "XYZ".to_string(), ..data }).is_empty());
+    }
+
+    #[test]
+    fn interpolated_current_between_standard_sizes() {
+        // 표준 규격은 표 값 그대로
+        let exact = get_allowable_current_interpolated(16.0, "XLPE", "B2", 2).unwrap();
+        assert_eq!(exact, InterpolatedCurrent { current: 91.0, interpolated: false });
+
+        // 20mm²는 16mm²와 25mm² 사이 로그-로그 보간
+        let lower = lookup_base_current(Size("16"), Insulation::Xlpe, InstallMethod::B2, 2).unwrap().value();
+        let upper = lookup_base_current(Size("25"), Insulation::Xlpe, InstallMethod::B2, 2).unwrap().value();
+        let middle = get_allowable_current_interpolated(20.0, "XLPE", "B2", 2).unwrap();
+        assert!(middle.interpolated);
+        assert!(lower < middle.current && middle.current < upper);
+        let exponent = (upper / lower).ln() / (25f64 / 16.0).ln();
+        assert_eq!(middle.current, ((lower * (20f64 / 16.0).powf(exponent)) * 10.0).round() / 10.0);
+
+        assert!(get_allowable_current_interpolated(1.0, "XLPE", "B2", 2).is_none());
+        assert!(get_allowable_current_interpolated(600.0, "XLPE", "B2", 2).is_none());
+        assert!(get_allowable_current_interpolated(20.0, "EPR", "B2", 2).is_none());
+        assert!(get_allowable_current_interpolated(20.0, "XLPE", "B2", 4).is_none());
+    }
+
+    #[test]
+    fn recommend_cable_size_from_motor_power() {
+        assert!((power_to_current(10.0, 220.0, 1.0, "1Φ") - 45.4545).abs() < 1e-3);
+        assert!((power_to_current(15.0, 380.0, 0.8, "3Φ") - 28.487).abs() < 1e-3);
+
+        // 15kW 3상 380V 모터, 역률 미지정(0.8) → 28.5A
+        let rec = recommend_cable_size_from_power(15.0, 380.0, None, "CV", "3C", "3Φ", "B2", 30, "ko").unwrap();
+        assert_eq!(rec.load_current, 28.5);
+        assert_eq!(rec.basis, "15kW / (√3 × 380V × 0.8) = 28.5A");
+        let expected = recommend_cable_size(28.5, "CV", "3C", "3Φ", "B2", 30, "ko").unwrap();
+        assert_eq!((rec.data.size.as_str(), rec.data.load_current), (expected.size.as_str(), Some(28.5)));
+
+        for (kw, pf) in [(0.0, Some(0.8)), (-5.0, None), (15.0, Some(0.0)), (15.0, Some(-0.5)), (15.0, Some(1.2))] {
+            assert_eq!(
+                recommend_cable_size_from_power(kw, 380.0, pf, "CV", "3C", "3Φ", "B2", 30, "ko").unwrap_err(),
+                localize_error(ERR_INVALID_LOAD_POWER, "ko")
+            );
+        }
+        assert_eq!(
+            recommend_cable_size_from_power(0.0, 380.0, None, "CV", "3C", "3Φ", "B2", 30, "en").unwrap_err(),
+            localize_error(ERR_INVALID_LOAD_POWER, "en")
+        );
+        assert_eq!(
+            recommend_cable_size_from_power(5000.0, 380.0, None, "CV", "3C", "3Φ", "B2", 30, "en").unwrap_err(),
+            localize_error(ERR_PARALLEL_CONDUCTORS_REQUIRED, "en")
+        );
+    }
+
+    #[test]
+    fn breaker_coordinates_with_load_and_allowable_current() {
+        let household = |load: f64, iz: f64| recommend_breaker(load, iz, BreakerType::Household, "ko");
+        assert_eq!(household(45.0, 91.0), Ok(50));
+        assert_eq!(household(50.0, 91.0), Ok(50));
+        assert_eq!(household(90.0, 91.0), Err(localize_error(ERR_CABLE_UNDERSIZED_FOR_BREAKER, "ko")));
+        assert_eq!(household(95.0, 91.0), Err(localize_error(ERR_CABLE_UNDERSIZED_FOR_BREAKER, "ko")));
+        assert_eq!(localize_error(ERR_CABLE_UNDERSIZED_FOR_BREAKER, "ko"), "전선 규격 상향 필요");
+        assert!(household(0.0, 91.0).is_err());
+        assert_eq!(
+            recommend_breaker(0.0, 91.0, BreakerType::Household, "en"),
+            Err(localize_error(ERR_INVALID_LOAD_CURRENT, "en"))
+        );
+
+        // Iz 52A, 부하 45A → 50A: 배선차단기는 I2 = 1.45·50 / 1.3·50 ≤ 1.45·52,
+        // gG 퓨즈는 I2 = 1.6 × 50 = 80A > 75.4A라 협조 불가
+        assert_eq!(household(45.0, 52.0), Ok(50));
+        assert_eq!(recommend_breaker(45.0, 52.0, BreakerType::Industrial, "ko"), Ok(50));
+        assert!(recommend_breaker(45.0, 52.0, BreakerType::FuseGg, "ko").is_err());
+        assert_eq!(recommend_breaker(45.0, 56.0, BreakerType::FuseGg, "ko"), Ok(50));
+
+        // XLPE B2 2부하 16mm² 91A, 부하 45A → 50A
+        let mut data = sample("2C", "1Φ");
+        assert_eq!(calculate_core(&data).unwrap().recommended_breaker_a, None);
+        data.load_current = Some(45.0);
+        assert_eq!(calculate_core(&data).unwrap().recommended_breaker_a, Some(50));
+        data.load_current = Some(90.0);
+        assert_eq!(calculate_core(&data).unwrap().recommended_breaker_a, None);
+    }
+
+    #[test]
+    #[cfg(not(target_arch = "wasm32"))]
+    fn calculation_history_round_trip() {
+        use crate::history::{delete_calculation, list_calculations, save_calculation, set_history_db_path};
+
+        let path = std::env::temp_dir().join(format!("kec-history-test-{}.sqlite3", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        set_history_db_path(Some(path.clone()));
+
+        let data = sample("3C", "3Φ");
+        let result = calculate_core(&data).unwrap();
+        let first = save_calculation("A동", &data, &result).unwrap();
+        let second = save_calculation(" B동 ", &data, &result).unwrap();
+        assert!(save_calculation("  ", &data, &result).is_err());
+
+        let all = list_calculations(None);
+        assert_eq!(all.iter().map(|r| r.id).collect::<Vec<_>>(), vec![second, first]);
+        assert!(all[0].timestamp.ends_with('Z'));
+        let b = list_calculations(Some("B동"));
+        assert_eq!(b.len(), 1);
+        assert_eq!((b[0].project.as_str(), b[0].input.size.as_str()), ("B동", "16"));
+        assert_eq!(b[0].result.allowable_current, result.allowable_current);
+
+        assert_eq!(delete_calculation(first), Ok(true));
+        assert_eq!(delete_calculation(first), Ok(false));
+        assert!(list_calculations(Some("A동")).is_empty());
+
+        set_history_db_path(None);
+        assert!(list_calculations(None).is_empty());
+        assert!(save_calculation("A동", &data, &result).is_err());
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn labels_follow_requested_language() {
+        assert_eq!(get_install_methods("ko")[0], ("A1".to_string(), "A1: 단열벽 속 전선관 (단심)".to_string()));
+        assert_eq!(get_install_methods("fr"), get_install_methods("ko"));
+        let en = get_install_methods("en");
+        assert_eq!(en.len(), InstallMethod::ALL.len());
+        assert!(en.iter().all(|(code, label)| label.starts_with(&format!("{}: ", code)) && label.is_ascii()));
+        assert_eq!(
+            get_install_methods_for_cores("1C", "en").iter().map(|(code, _)| code.as_str()).collect::<Vec<_>>(),
+            ["A1", "B1", "C", "D1", "E"]
+        );
+        assert!(get_install_methods_for_cores("5C", "en").is_empty());
+
+        assert_eq!(describe_install_method("D2", "ko").as_deref(), Some("지중 매설 직매"));
+        assert_eq!(describe_install_method("D2", "en").as_deref(), Some("Direct buried in ground"));
+        assert_eq!(describe_install_method("G", "en"), None);
+        assert_eq!(loaded_label(2, "1Φ", "ko"), "2부하(단상)");
+        assert_eq!(loaded_label(2, "1Φ", "en"), "2 loaded (single-phase)");
+        assert_eq!(core_label("4C", "en"), "4C (4-core)");
+        assert_eq!(core_label("1C", "jp"), "1C (단심)");
+
+        let ko = get_cable_types();
+        let en = get_cable_types_localized("en");
+        assert_eq!(ko.iter().map(|t| &t.code).collect::<Vec<_>>(), en.iter().map(|t| &t.code).collect::<Vec<_>>());
+        assert!(en.iter().all(|t| t.name.is_ascii() && t.description.is_ascii()));
+        assert_eq!(ko.iter().map(|t| &t.fire_rating).collect::<Vec<_>>(), en.iter().map(|t| &t.fire_rating).collect::<Vec<_>>());
+
+        let mut data = sample("2C", "1Φ");
+        data.lang = Some("en".to_string());
+        assert!(calculate_core(&data).unwrap().install_method_desc.starts_with("Conduit on wall (multi-core cable) / 2 loaded (single-phase)"));
+    }
+
+    #[test]
+    fn compare_allowable_current_across_install_methods() {
+        let rows = compare_install_methods(&sample("3C", "3Φ"));
+        assert_eq!(
+            rows.iter().map(|(code, _)| code.as_str()).collect::<Vec<_>>(),
+            ["A2", "B2", "C", "D1", "D2", "F"]
+        );
+        let current = |code: &str| rows.iter().find(|(c, _)| c == code).unwrap().1;
+        assert_eq!(current("B2"), 82.0);
+        assert_eq!(current("D1"), 77.0);
+        assert!(current("A2") < current("B2") && current("B2") < current("F"));
+
+        let single = compare_install_methods(&sample("1C", "3Φ"));
+        assert!(!single.is_empty());
+        assert!(single.iter().all(|(code, _)| InstallMethod::try_from(code.as_str()).unwrap().supports_cores("1C")));
+
+        let mut invalid = sample("3C", "3Φ");
+        invalid.size = "999".to_string();
+        assert!(compare_install_methods(&invalid).is_empty());
+    }
+
+    #[test]
+    fn custom_outer_diameter_overrides_builtin_table() {
+        let builtin = lookup_outer_diameter("CV", "16", "3C").unwrap();
+        assert_eq!(builtin, get_cable_outer_diameter("CV", "16", "3C").unwrap());
+        assert_eq!(lookup_outer_diameter("HFIX", "16", "3C"), None);
+
+        let mut data = sample("3C", "3Φ");
+        data.quantity = 3;
+        let base = calculate_core(&data).unwrap();
+        data.custom_outer_diameter = Some(builtin + 5.0);
+        let custom = calculate_core(&data).unwrap();
+        assert!(custom.total_area > base.total_area);
+        assert_eq!(custom.allowable_current, base.allowable_current);
+        // 인접 규격 대안은 내장 외경 기준
+        assert_eq!(custom.alternatives, base.alternatives);
+
+        for od in [0.0, -3.0, f64::NAN] {
+            data.custom_outer_diameter = Some(od);
+            assert_eq!(calculate_core(&data).unwrap_err(), localize_error(ERR_INVALID_OUTER_DIAMETER, "ko"));
+        }
+    }
+
+    #[test]
+    fn large_single_core_sizes_only_for_single_core_tray() {
+        // 630mm² 이상은 외경 테이블이 없어 제조사 외경 입력 필요
+        let mut data = sample("1C", "3Φ");
+        data.quantity = 3;
+        data.install_method = "E".to_string();
+        data.size = "630".to_string();
+        assert_eq!(calculate_core(&data).unwrap_err(), localize_error(ERR_UNSUPPORTED_SIZE, "ko"));
+        assert_eq!(lookup_outer_diameter("CV", "630", "1C"), None);
+        data.custom_outer_diameter = Some(45.0);
+        for (size, expected) in [("630", 1088.0), ("800", 1214.0), ("1000", 1349.0)] {
+            data.size = size.to_string();
+            assert_eq!(calculate_core(&data).unwrap().allowable_current, expected);
+        }
+        // 단심 트레이 값은 500mm² 다심 값에서 단조 증가
+        for loaded in [2, 3] {
+            let currents: Vec<f64> = ["500", "630", "800", "1000"]
+                .iter()
+                .map(|size| lookup_base_current(Size(size), Insulation::Xlpe, InstallMethod::E, loaded).unwrap().value())
+                .collect();
+            assert!(currents.windows(2).all(|w| w[0] < w[1]));
+        }
+
+        // IEC 미수록 조합(다른 공사방법·다심)은 미지원
+        assert!(lookup_base_current(Size("630"), Insulation::Xlpe, InstallMethod::B1, 3).is_err());
+        assert!(lookup_base_current(Size("630"), Insulation::Pvc, InstallMethod::F, 3).is_err());
+        data.install_method = "B1".to_string();
+        assert!(calculate_core(&data).is_err());
+        let mut multi = sample("3C", "3Φ");
+        multi.install_method = "F".to_string();
+        multi.size = "630".to_string();
+        multi.custom_outer_diameter = Some(80.0);
+        assert!(calculate_core(&multi).is_err());
+    }
+
+    #[test]
+    fn allowable_current_table_pinned_values() {
+        let table = get_allowable_current_table();
+        let value = |size: &'static str, insulation, method| table[&(Size(size), insulation, method)];
+        // (2부하, 3부하): IEC 60364-5-52 Table B.52.4 / B.52.5
+        assert_eq!(value("2.5", Insulation::Pvc, InstallMethod::B1), (24.0, 21.0));
+        assert_eq!(value("50", Insulation::Xlpe, InstallMethod::C), (209.0, 179.0));
+        assert_eq!(value("16", Insulation::Xlpe, InstallMethod::B2), (91.0, 82.0));
+        assert_eq!(value("1.5", Insulation::Pvc, InstallMethod::A1), (14.5, 13.5));
+        assert_eq!(value("240", Insulation::Xlpe, InstallMethod::D1), (434.0, 340.0));
+        assert_eq!(value("95", Insulation::Pvc, InstallMethod::D2), (239.0, 193.0));
+    }
+
+    /// 공사방법별 표 수록 규격 (630mm² 이상은 단심 트레이 E만)
+    fn tabulated_sizes(method: InstallMethod) -> Vec<&'static str> {
+        STANDARD_SIZES
+            .iter()
+            .copied()
+            .filter(|size| method == InstallMethod::E || Size(size).mm2() <= 500.0)
+            .collect()
+    }
+
+    #[test]
+    fn allowable_current_table_covers_every_combination() {
+        let table = get_allowable_current_table();
+        let mut expected = 0;
+        for insulation in Insulation::ALL {
+            for method in InstallMethod::ALL {
+                let sizes = tabulated_sizes(method);
+                let missing: Vec<&str> = sizes
+                    .iter()
+                    .copied()
+                    .filter(|size| !table.contains_key(&(Size(size), insulation, method)))
+                    .collect();
+                assert!(missing.is_empty(), "{} {}: {:?} 누락", insulation.as_str(), method.as_str(), missing);
+                expected += sizes.len();
+            }
+        }
+        assert_eq!(table.len(), expected);
+    }
+
+    #[test]
+    fn allowable_current_table_is_monotonic() {
+        let table = get_allowable_current_table();
+        for insulation in Insulation::ALL {
+            for method in InstallMethod::ALL {
+                let sizes = tabulated_sizes(method);
+                let row: Vec<(f64, f64)> = sizes.iter().map(|size| table[&(Size(size), insulation, method)]).collect();
+                for (size, (two, three)) in sizes.iter().zip(&row) {
+                    // 같은 규격에서 부하 도체가 많을수록 발열이 커지므로 2부하 ≥ 3부하
+                    assert!(two >= three, "{} {} {}mm²: 2부하 {} < 3부하 {}", insulation.as_str(), method.as_str(), size, two, three);
+                }
+                // 규격이 커질수록 허용전류 증가 (행 순서 오타 검출)
+                for (i, pair) in row.windows(2).enumerate() {
+                    assert!(
+                        pair[0].0 < pair[1].0 && pair[0].1 < pair[1].1,
+                        "{} {}: {}mm² → {}mm² 감소",
+                        insulation.as_str(),
+                        method.as_str(),
+                        sizes[i],
+                        sizes[i + 1]
+                    );
+                }
+            }
+        }
+    }
+
+    fn contains_hangul(text: &str) -> bool {
+        text.chars().any(|c| ('\u{AC00}'..='\u{D7A3}').contains(&c))
+    }
+
+    #[test]
+    fn install_method_desc_follows_input_language() {
+        let data = CableData {
+            quantity: 6,
+            conductor_material: Some("Al".to_string()),
+            termination_temp: Some(70),
+            ambient_temp: Some(40),
+            burial_depth_m: Some(1.0),
+            soil_resistivity: Some(1.5),
+            third_harmonic_percent: Some(20.0),
+            parallel_runs: 2,
+            arrangement: Some(Arrangement::Spaced),
+            ..sample("1C", "3Φ")
+        };
+        let korean = calculate_core(&data).unwrap().install_method_desc;
+        assert!(korean.contains("회로 수 산정: 올림"));
+        assert!(korean.contains("매설 깊이 무시 (공기 중 공사방법)"));
+
+        let english = CableData { lang: Some("en".to_string()), ..data };
+        let desc = calculate_core(&english).unwrap().install_method_desc;
+        assert!(!contains_hangul(&desc), "{}", desc);
+        assert!(desc.contains("Circuit count: round up / Arrangement: spaced"));
+        assert!(desc.contains("Terminal rating 60°C limit (factor 0.71)"));
+        assert!(desc.contains("Burial depth ignored (in-air method)"));
+        assert!(desc.contains("Parallel 2 runs"));
+
+        let neutral = CableData { neutral_loaded: true, ..sample("4C", "3Φ") };
+        assert!(calculate_core(&neutral).unwrap().install_method_desc.contains("중성선 부하 고려 적용 (계수"));
+        let neutral = CableData { lang: Some("en".to_string()), ..neutral };
+        let desc = calculate_core(&neutral).unwrap().install_method_desc;
+        assert!(!contains_hangul(&desc), "{}", desc);
+        assert!(desc.contains("Loaded neutral applied (factor"));
+        let desc = calculate_core(&CableData { cores: "3C".to_string(), ..neutral }).unwrap().install_method_desc;
+        assert!(desc.contains("Neutral load ignored (4C cables only)"));
+
+        let nec = CableData {
+            lang: Some("en".to_string()),
+            regulation: Some("NEC".to_string()),
+            ambient_temp: Some(40),
+            parallel_runs: 2,
+            ..sample("3C", "3Φ")
+        };
+        let desc = calculate_core(&nec).unwrap().install_method_desc;
+        assert!(!contains_hangul(&desc), "{}", desc);
+        assert!(desc.contains("Adjustment factor"));
+        assert_eq!(core_system_label("2C", "en"), Some("2C (1-phase 2-wire / DC +/-)"));
+        assert_eq!(core_system_label("4C", "ko"), Some("4C (3상 4선)"));
+        assert_eq!(core_system_label("5C", "ko"), None);
+    }
+
+    #[test]
+    fn result_cites_source_tables() {
+        assert_eq!(get_table_reference(Insulation::Xlpe, InstallMethod::B2, 3), "IEC 60364-5-52 Table B.52.5 / KEC 232");
+        assert_eq!(get_table_reference(Insulation::Pvc, InstallMethod::D1, 3), "IEC 60364-5-52 Table B.52.4 / KEC 232");
+        assert_eq!(get_table_reference(Insulation::Xlpe, InstallMethod::B2, 2), "IEC 60364-5-52 Table B.52.3 / KEC 232");
+        assert_eq!(get_table_reference(Insulation::Pvc, InstallMethod::C, 2), "IEC 60364-5-52 Table B.52.2 / KEC 232");
+        // E/F는 부하 도체 수와 무관하게 절연체별 표
+        assert_eq!(get_table_reference(Insulation::Xlpe, InstallMethod::F, 3), "IEC 60364-5-52 Table B.52.12 / KEC 232");
+        assert_eq!(get_table_reference(Insulation::Xlpe, InstallMethod::E, 2), "IEC 60364-5-52 Table B.52.12 / KEC 232");
+        assert_eq!(get_table_reference(Insulation::Pvc, InstallMethod::F, 2), "IEC 60364-5-52 Table B.52.10 / KEC 232");
+
+        let mut data = sample("3C", "3Φ");
+        let result = calculate_core(&data).unwrap();
+        assert_eq!(result.reference, "IEC 60364-5-52 Table B.52.5 / KEC 232");
+        assert!(result.install_method_desc.contains("집합계수 출처 Table B.52.17"));
+        assert_eq!(calculate_core(&sample("3C", "1Φ")).unwrap().reference, "IEC 60364-5-52 Table B.52.3 / KEC 232");
+        let english = CableData { lang: Some("en".to_string()), ..sample("3C", "3Φ") };
+        let desc = calculate_core(&english).unwrap().install_method_desc;
+        assert!(desc.contains("Grouping factor source Table B.52.17"));
+        assert!(!desc.contains("집합계수 출처"));
+
+        data.ambient_temp = Some(40);
+        assert!(calculate_core(&data).unwrap().install_method_desc.contains("Table B.52.14)"));
+        data.install_method = "D1".to_string();
+        assert!(calculate_core(&data).unwrap().install_method_desc.contains("Table B.52.15)"));
+
+        let mut nec = sample("3C", "3Φ");
+        nec.regulation = Some("NEC".to_string());
+        assert_eq!(calculate_core(&nec).unwrap().reference, "NEC 310.16");
+    }
+
+    #[test]
+    fn dc_system_uses_two_loaded_table_and_resistive_drop() {
+        // DC 2C: 교류 2부하 표 준용 (XLPE B2 16mm² 91A) + 근사 적용 표시
+        let data = sample("2C", "DC");
+        let result = calculate_core(&data).unwrap();
+        assert_eq!(result.allowable_current, 91.0);
+        assert!(result.install_method_desc.contains("2부하(직류)"));
+        assert!(result.install_method_desc.contains("DC 근사 적용"));
+        let three_core = sample("3C", "DC");
+        assert_eq!(calculate_core(&three_core).unwrap_err(), localize_error(ERR_CORES_IN_DC, error_lang(&three_core)));
+        let english = CableData { lang: Some("en".to_string()), ..data.clone() };
+        let desc = calculate_core(&english).unwrap().install_method_desc;
+        assert!(desc.contains("DC approximation (AC two-loaded-conductor ampacity applied)"));
+        assert!(!contains_hangul(&desc), "{}", desc);
+        let three_core = CableData { lang: Some("en".to_string()), ..three_core };
+        assert_eq!(calculate_core(&three_core).unwrap_err(), localize_error(ERR_CORES_IN_DC, "en"));
+
+        // ΔV = 2 × 50A × 0.1374Ω (역률 무시) = 13.74V
+        let mut input = VoltageDropInput {
+            length: 100.0,
+            current: Some(50.0),
+            voltage: 500.0,
+            power_factor: Some(0.9),
+            transformer: None,
+            installation_type: None,
+            is_branch: false,
+        };
+        let drop = calculate_voltage_drop(&data, &input).unwrap();
+        assert_eq!(drop.cable_drop, 13.74);
+        assert!(drop.formulas[0].starts_with("전압강하(직류) = 2 × 50A"));
+        input.transformer = Some(TransformerInfo { kva: 500.0, impedance_percent: 5.0, x_r_ratio: None });
+        assert_eq!(
+            calculate_voltage_drop(&data, &input).unwrap_err(),
+            localize_error(ERR_TRANSFORMER_IN_DC, error_lang(&data))
+        );
+        assert_eq!(
+            calculate_voltage_drop(&english, &input).unwrap_err(),
+            localize_error(ERR_TRANSFORMER_IN_DC, "en")
+        );
+
+        // P / V (역률 무시)
+        assert_eq!(power_to_current(10.0, 500.0, 0.8, "DC"), 20.0);
+    }
+
+    #[test]
+    fn fire_resistant_multi_core_prefers_tabulated_diameter() {
+        // 다심 대표값 테이블 (단심 배율 7.2 × 1.65 = 11.88mm가 아님)
+        assert_eq!(lookup_outer_diameter("FR-CV", "2.5", "2C"), Some(13.0));
+        assert_eq!(lookup_outer_diameter("TFR-8", "95", "4C"), Some(51.5));
+        // 테이블에 없는 규격은 95sq 테이블 값 × 단심 외경 비 (48.0 × 20.6 / 19.1)
+        assert!((lookup_outer_diameter("FR-CV", "120", "3C").unwrap() - 48.0 * 20.6 / 19.1).abs() < 1e-9);
+        // 다심 대표값은 카탈로그 미검증이므로 테이블 값이어도 근사로 표시
+        assert!(is_outer_diameter_approximated("FR-CV", "3C"));
+        assert!(is_outer_diameter_approximated("TFR-8", "2C"));
+        assert!(is_outer_diameter_approximated("CV", "3C"));
+        assert!(!is_outer_diameter_approximated("FR-CV", "1C"));
+        assert!(!is_outer_diameter_approximated("CV", "2C"));
+
+        // 2심 < 3심 < 4심, 규격 증가에 따라 외경 증가
+        let tables = outer_diameter_tables();
+        for (two, three, four) in [
+            (&tables.fr_cv_2c, &tables.fr_cv_3c, &tables.fr_cv_4c),
+            (&tables.tfr_8_2c, &tables.tfr_8_3c, &tables.tfr_8_4c),
+        ] {
+            let mut previous = 0.0;
+            for size in STANDARD_SIZES.iter().filter(|size| two.contains_key(*size)) {
+                assert!(two[size] < three[size] && three[size] < four[size], "{}", size);
+                assert!(two[size] > previous, "{}", size);
+                previous = two[size];
+            }
+        }
+        // 테이블 구간(~95sq)에서 근사 구간(120~300sq)으로 넘어가도 외경 단조 증가
+        for cable_type in ["FR-CV", "TFR-8"] {
+            for cores in ["2C", "3C", "4C"] {
+                let diameters: Vec<f64> = STANDARD_SIZES
+                    .iter()
+                    .filter_map(|size| lookup_outer_diameter(cable_type, size, cores))
+                    .collect();
+                let single_core = STANDARD_SIZES.iter().filter(|size| lookup_outer_diameter(cable_type, size, "1C").is_some()).count();
+                assert_eq!(diameters.len(), single_core, "{} {}", cable_type, cores);
+                assert!(diameters.windows(2).all(|w| w[0] < w[1]), "{} {}: {:?}", cable_type, cores, diameters);
+            }
+        }
+    }
+
+    #[test]
+    fn max_cables_in_conduit_follows_count_dependent_limit() {
+        // C28 내부 단면적 π × 13.3² = 555.7mm², HFIX 4sq 외경 4.6mm -> 16.62mm²
+        // 3본 이상 40%: 222.3 / 16.62 = 13.4 -> 13본
+        assert_eq!(max_cables_in_conduit("C28", "HFIX", "4", "1C", "ko"), Ok(13));
+        assert_eq!(max_cables_in_conduit("c28 ", "HFIX", "4", "1C", "ko"), Ok(13));
+        assert_eq!(max_cables_in_conduit("C28 (28mm)", "HFIX", "4", "1C", "ko"), Ok(13));
+        // CV 1C 35sq 외경 12.5mm (122.7mm²): 1본 53% 통과, 2본 31% (172.3mm²) 초과 -> 1본
+        assert_eq!(max_cables_in_conduit("C28", "CV", "35", "1C", "ko"), Ok(1));
+        // 단일 케이블이 관 수용 한계 초과
+        assert_eq!(max_cables_in_conduit("C28", "CV", "95", "3C", "ko"), Ok(0));
+
+        assert_eq!(
+            max_cables_in_conduit("C29", "HFIX", "4", "1C", "ko"),
+            Err(localize_error(ERR_UNKNOWN_CONDUIT, "ko"))
+        );
+        assert_eq!(
+            max_cables_in_conduit("C28", "HFIX", "4", "2C", "ko"),
+            Err(localize_error(ERR_UNSUPPORTED_SIZE, "ko"))
+        );
+        assert_eq!(
+            max_cables_in_conduit("C29", "HFIX", "4", "1C", "en"),
+            Err(localize_error(ERR_UNKNOWN_CONDUIT, "en"))
+        );
+    }
+
+    #[test]
+    fn power_loss_uses_temperature_corrected_resistance() {
+        // 동 16mm² 90°C: R = 1/58 / 16 × (1 + 0.00393 × 70) × 1000 = 1.374Ω/km
+        // 3상 100m 50A: 3 × 50² × 0.1374Ω = 1030.5W -> 8760h 9027.5kWh
+        let loss = calculate_power_loss("16", "Cu", "XLPE", 100.0, 50.0, "3Φ", 8760.0);
+        assert!((loss - 9027.5).abs() < 1.0, "{}", loss);
+        // 단상은 2선 손실
+        let single = calculate_power_loss("16", "Cu", "XLPE", 100.0, 50.0, "1Φ", 8760.0);
+        assert!((single / loss - 2.0 / 3.0).abs() < 1e-3);
+        // 알루미늄은 저항이 커서 손실 증가
+        assert!(calculate_power_loss("16", "Al", "XLPE", 100.0, 50.0, "3Φ", 8760.0) > loss);
+        assert_eq!(calculate_power_loss("16mm", "Cu", "XLPE", 100.0, 50.0, "3Φ", 8760.0), 0.0);
+        assert_eq!(calculate_power_loss("16", "Cu", "XLPE", 0.0, 50.0, "3Φ", 8760.0), 0.0);
+        assert_eq!(calculate_power_loss("16", "Cu", "EPR", 100.0, 50.0, "3Φ", 8760.0), 0.0);
+        // PVC는 도체 최고허용온도가 낮아(70°C) 저항·손실 감소
+        assert!(calculate_power_loss("16", "Cu", "PVC", 100.0, 50.0, "3Φ", 8760.0) < loss);
+
+        // 결과에는 길이·부하전류가 모두 있을 때만 채움 (CV는 XLPE 90°C)
+        let mut data = sample("3C", "3Φ");
+        assert_eq!(calculate_core(&data).unwrap().annual_loss_kwh, None);
+        data.length = 100.0;
+        assert_eq!(calculate_core(&data).unwrap().annual_loss_kwh, None);
+        data.load_current = Some(50.0);
+        assert_eq!(calculate_core(&data).unwrap().annual_loss_kwh, Some(loss));
+        // 병렬 런 2벌이면 손실 1/2
+        data.parallel_runs = 2;
+        let halved = calculate_core(&data).unwrap().annual_loss_kwh.unwrap();
+        assert!((halved - loss / 2.0).abs() < 0.1);
+        // 수량(별도 케이블)은 손실을 나누지 않음
+        data.quantity = 2;
+        assert_eq!(calculate_core(&data).unwrap().annual_loss_kwh, Some(halved));
+        // 1C 3가닥도 회로 손실 그대로 (병렬 런 1)
+        let single_core = CableData { quantity: 6, length: 100.0, load_current: Some(50.0), ..sample("1C", "3Φ") };
+        assert_eq!(calculate_core(&single_core).unwrap().annual_loss_kwh, Some(loss));
+    }
+
+    #[test]
+    fn bending_radius_scales_outer_diameter_by_cable_kind() {
+        assert_eq!(min_bending_radius("CV", 10.0, "1C"), 150.0);
+        assert_eq!(min_bending_radius("CV", 10.0, "3C"), 120.0);
+        assert_eq!(min_bending_radius("FR-CV", 10.0, "1C"), 200.0);
+        assert_eq!(min_bending_radius("FR-CV", 10.0, "4C"), 150.0);
+
+        // 결과는 내장 외경 기준 (CV 3C 16mm²: 20 × 1.15 = 23mm -> 276mm), 사용자 외경 지정 시 그 값
+        let data = sample("3C", "3Φ");
+        assert_eq!(calculate_core(&data).unwrap().min_bending_radius_mm, 276.0);
+        let custom = CableData { custom_outer_diameter: Some(25.0), ..data };
+        assert_eq!(calculate_core(&custom).unwrap().min_bending_radius_mm, 300.0);
+    }
+
+    #[test]
+    fn neutral_loaded_four_core_applies_reduction() {
+        // XLPE B2 16mm² 3부하 82A, 중성선 부하 시 × 0.86 = 70.5A
+        let four_core = sample("4C", "3Φ");
+        assert_eq!(calculate_core(&four_core).unwrap().allowable_current, 82.0);
+        let loaded = CableData { neutral_loaded: true, ..four_core.clone() };
+        let result = calculate_core(&loaded).unwrap();
+        assert_eq!(result.allowable_current, 70.5);
+        assert!(result.install_method_desc.contains("중성선 부하 고려 적용 (계수 0.86)"));
+        assert!(result.formulas[0].contains("0.86(중성선 부하)"));
+
+        // 3고조파 보정(15% 초과)이 이미 중성선 전류를 반영하면 중복 감소 없음
+        let harmonic = CableData { third_harmonic_percent: Some(25.0), ..loaded.clone() };
+        assert_eq!(calculate_core(&harmonic).unwrap().allowable_current, 70.5);
+
+        // 4C가 아니면 무시
+        let three_core = calculate_core(&CableData { neutral_loaded: true, ..sample("3C", "3Φ") }).unwrap();
+        assert_eq!(three_core.allowable_current, 82.0);
+        assert!(three_core.install_method_desc.contains("중성선 부하 무시"));
+
+        // 기본값 false (기존 입력 JSON 호환)
+        let parsed: CableData = serde_json::from_str(
+            r#"{"cable_type":"CV","cores":"4C","size":"16","quantity":1,"system":"3Φ","ground_wire":"없음","install_method":"B2"}"#,
+        )
+        .unwrap();
+        assert!(!parsed.neutral_loaded);
+    }
+}
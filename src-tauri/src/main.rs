@@ -1,601 +1,392 @@
 // Prevents additional console window on Windows in release
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+use kec_calculator::commands;
+use kec_calculator::history::{self, CalculationRecord};
+use kec_calculator::{
+    calculate_core, evaluate_suitability, AuditEntry, BoxDimensions, CableData, CableTypeInfo,
+    CalcError, CalculationPipeline, CalculationResult, CircuitDesignInput, CircuitDesignResult,
+    ComplianceResult, CrossValidationResult, FeederCapacityResult, InstallMethod, Insulation,
+    InterpolatedCurrent, NearestAvailable, PartialRecalculation, PipelineFailure,
+    PowerSizeRecommendation, ProjectFile, ProjectMetadata, Solution, SolutionConstraints,
+    SuitabilityScore, SystemChangePreview, VerticalCheck, VoltageDropInput, VoltageDropResult,
+    STANDARD_SIZES,
+};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use tauri::Manager;
 
-/// 전선 데이터 구조체
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CableData {
-    pub cable_type: String,      // 전선 종류
-    pub cores: String,           // 가닥수 (1C, 2C, 3C, 4C)
-    pub size: String,            // 규격 (mm²)
-    pub quantity: u32,           // 수량
-    pub system: String,          // 전압 방식 (1Φ, 3Φ)
-    pub ground_wire: String,     // 접지선 (없음, HFIX)
-    pub install_method: String,  // 공사방법 (A1, A2, B1, B2, C, D1, E, F)
+/// 메인 계산 함수 (Tauri 커맨드)
+#[tauri::command]
+fn calculate(data: CableData) -> Result<CalculationResult, CalcError> {
+    kec_calculator::calculate_result(&data)
 }
 
-/// 계산 결과 구조체
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CalculationResult {
-    pub total_area: f64,              // 총 단면적 (mm²)
-    pub conductor_area: f64,          // 도체 단면적 (mm²)
-    pub allowable_current: f64,       // 허용전류 (A)
-    pub recommended_conduit: String,  // 추천 전선관 크기
-    pub fill_rate: f64,               // 점유율 (%)
-    pub install_method_desc: String,  // 공사 방법 설명
+/// 파이프라인 계산 (실패 시 실패 단계·에러 키 포함)
+#[tauri::command]
+fn calculate_pipeline(data: CableData) -> Result<CalculationResult, PipelineFailure> {
+    CalculationPipeline::new(&data).run()
 }
 
-/// 전선 타입 정보
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CableTypeInfo {
-    pub code: String,
-    pub name: String,
-    pub description: String,
-    pub max_temp: u32,  // 최고 허용 온도 (°C)
-    pub insulation: String,  // 절연체 종류
-}
-
-/// KEC IEC 60364-5-52 기준 허용전류 데이터 (2부하/3부하 도체)
-/// Table B.52.4 (PVC 70°C), Table B.52.5 (XLPE 90°C)
-/// Table B.52.10/B.52.11 (E/F 케이블 트레이)
-/// Return: (2 loaded current, 3 loaded current)
-fn get_allowable_current_table() -> HashMap<(&'static str, &'static str, &'static str), (f64, f64)> {
-    let mut table = HashMap::new();
-    
-    // ============================================================
-    // PVC 절연 (70°C) - IEC 60364-5-52 Table B.52.4
-    // 주변온도 30°C (공기) / 20°C (지중) 기준
-    // ============================================================
-    
-    // A1: 단열벽 속 전선관 (단심) - Table B.52.4 Column 2/3
-    let pvc_a1 = [
-        ("1.5", 14.5, 13.5), ("2.5", 19.5, 18.0), ("4", 26.0, 24.0), ("6", 34.0, 31.0),
-        ("10", 46.0, 42.0), ("16", 61.0, 56.0), ("25", 80.0, 73.0), ("35", 99.0, 89.0),
-        ("50", 119.0, 108.0), ("70", 151.0, 136.0), ("95", 182.0, 164.0), ("120", 210.0, 188.0),
-        ("150", 240.0, 216.0), ("185", 273.0, 245.0), ("240", 321.0, 286.0), ("300", 367.0, 328.0),
-        ("400", 424.0, 379.0), ("500", 488.0, 436.0),
-    ];
-    for (size, c2, c3) in pvc_a1.iter() {
-        table.insert((*size, "PVC", "A1"), (*c2, *c3));
-    }
-    
-    // A2: 단열벽 속 전선관 (다심) - Table B.52.4 Column 4/5
-    let pvc_a2 = [
-        ("1.5", 14.0, 13.0), ("2.5", 18.5, 17.5), ("4", 25.0, 23.0), ("6", 32.0, 29.0),
-        ("10", 43.0, 39.0), ("16", 57.0, 52.0), ("25", 75.0, 68.0), ("35", 92.0, 83.0),
-        ("50", 110.0, 99.0), ("70", 139.0, 125.0), ("95", 167.0, 150.0), ("120", 192.0, 172.0),
-        ("150", 219.0, 196.0), ("185", 248.0, 223.0), ("240", 291.0, 261.0), ("300", 334.0, 298.0),
-        ("400", 386.0, 345.0), ("500", 444.0, 397.0),
-    ];
-    for (size, c2, c3) in pvc_a2.iter() {
-        table.insert((*size, "PVC", "A2"), (*c2, *c3));
-    }
+/// 입력 조합 종합 적합성 점수 (0~100, 감점 사유 포함)
+/// voltage_drop 입력이 있으면 전압강하, bend_radius_mm이 있으면 경로 굴곡 반경도 평가
+#[tauri::command]
+fn suitability_score(data: CableData, voltage_drop: Option<VoltageDropInput>, bend_radius_mm: Option<f64>) -> Result<SuitabilityScore, String> {
+    let result = calculate_core(&data)?;
+    let voltage_drop = voltage_drop
+        .map(|input| kec_calculator::calculate_voltage_drop(&data, &input))
+        .transpose()?;
+    let context = kec_calculator::SuitabilityContext { voltage_drop, bend_radius_mm };
+    Ok(evaluate_suitability(&data, &result, &context))
+}
 
-    // B1: 벽면 고정 전선관 (단심) - Table B.52.4 Column 6/7
-    let pvc_b1 = [
-        ("1.5", 17.5, 15.5), ("2.5", 24.0, 21.0), ("4", 32.0, 28.0), ("6", 41.0, 36.0),
-        ("10", 57.0, 50.0), ("16", 76.0, 68.0), ("25", 101.0, 89.0), ("35", 125.0, 110.0),
-        ("50", 151.0, 134.0), ("70", 192.0, 171.0), ("95", 232.0, 207.0), ("120", 269.0, 239.0),
-        ("150", 309.0, 275.0), ("185", 353.0, 314.0), ("240", 415.0, 369.0), ("300", 477.0, 423.0),
-        ("400", 555.0, 490.0), ("500", 642.0, 565.0),
-    ];
-    for (size, c2, c3) in pvc_b1.iter() {
-        table.insert((*size, "PVC", "B1"), (*c2, *c3));
-    }
+/// 수직 포설 구간 자중 검토 (클리트 간격·지지 개수 제안)
+#[tauri::command]
+fn check_vertical_run(data: CableData, vertical_length: f64) -> Result<VerticalCheck, String> {
+    kec_calculator::check_vertical_run(&data, vertical_length)
+}
 
-    // B2: 벽면 고정 전선관 (다심) - Table B.52.4 Column 8/9
-    let pvc_b2 = [
-        ("1.5", 16.5, 15.0), ("2.5", 23.0, 20.0), ("4", 30.0, 27.0), ("6", 38.0, 34.0),
-        ("10", 52.0, 46.0), ("16", 69.0, 62.0), ("25", 90.0, 80.0), ("35", 111.0, 99.0),
-        ("50", 133.0, 118.0), ("70", 168.0, 149.0), ("95", 201.0, 179.0), ("120", 232.0, 206.0),
-        ("150", 265.0, 236.0), ("185", 300.0, 268.0), ("240", 351.0, 313.0), ("300", 401.0, 358.0),
-        ("400", 464.0, 414.0), ("500", 533.0, 476.0),
-    ];
-    for (size, c2, c3) in pvc_b2.iter() {
-        table.insert((*size, "PVC", "B2"), (*c2, *c3));
-    }
+/// 계산 결과 목록을 마크다운 표로 내보내기
+#[tauri::command]
+fn export_results_markdown(rows: Vec<(CableData, CalculationResult)>) -> String {
+    kec_calculator::export_results_markdown(&rows)
+}
 
-    // C: 벽면 직접 고정 - Table B.52.4 Column 10/11
-    let pvc_c = [
-        ("1.5", 19.5, 17.5), ("2.5", 27.0, 24.0), ("4", 36.0, 32.0), ("6", 46.0, 41.0),
-        ("10", 63.0, 57.0), ("16", 85.0, 76.0), ("25", 112.0, 96.0), ("35", 138.0, 119.0),
-        ("50", 168.0, 144.0), ("70", 213.0, 184.0), ("95", 258.0, 223.0), ("120", 299.0, 259.0),
-        ("150", 344.0, 299.0), ("185", 392.0, 341.0), ("240", 461.0, 403.0), ("300", 530.0, 464.0),
-        ("400", 614.0, 545.0), ("500", 707.0, 638.0),
-    ];
-    for (size, c2, c3) in pvc_c.iter() {
-        table.insert((*size, "PVC", "C"), (*c2, *c3));
-    }
+/// 계산 결과를 단일 HTML 계산서로 렌더링
+#[tauri::command]
+fn render_report_html(data: CableData, result: CalculationResult, meta: Option<ProjectMetadata>) -> String {
+    kec_calculator::render_report_html(&data, &result, meta.as_ref())
+}
 
-    // D1: 지중 덕트 - Table B.52.4 Column 12/13
-    let pvc_d1 = [
-        ("1.5", 22.0, 18.0), ("2.5", 29.0, 24.0), ("4", 37.0, 30.0), ("6", 46.0, 38.0),
-        ("10", 61.0, 50.0), ("16", 79.0, 64.0), ("25", 101.0, 82.0), ("35", 122.0, 98.0),
-        ("50", 144.0, 116.0), ("70", 178.0, 143.0), ("95", 211.0, 169.0), ("120", 240.0, 192.0),
-        ("150", 271.0, 217.0), ("185", 304.0, 243.0), ("240", 351.0, 280.0), ("300", 396.0, 316.0),
-        ("400", 454.0, 363.0), ("500", 513.0, 410.0),
-    ];
-    for (size, c2, c3) in pvc_d1.iter() {
-        table.insert((*size, "PVC", "D1"), (*c2, *c3));
-    }
+/// XLPE 허용전류의 PVC 대비 증가율 (%)
+#[tauri::command]
+fn insulation_current_ratio(size: String, method: String, loaded: u8, lang: Option<String>) -> Result<f64, String> {
+    kec_calculator::insulation_current_ratio(&size, &method, loaded, lang.as_deref().unwrap_or("ko"))
+}
 
-    // D2: 지중 직매 - Table B.52.4 Column 14/15
-    let pvc_d2 = [
-        ("1.5", 24.0, 19.0), ("2.5", 32.0, 24.0), ("4", 41.0, 33.0), ("6", 51.0, 41.0),
-        ("10", 67.0, 54.0), ("16", 87.0, 70.0), ("25", 112.0, 92.0), ("35", 136.0, 110.0),
-        ("50", 161.0, 130.0), ("70", 200.0, 162.0), ("95", 239.0, 193.0), ("120", 273.0, 220.0),
-        ("150", 310.0, 246.0), ("185", 349.0, 278.0), ("240", 404.0, 320.0), ("300", 458.0, 359.0),
-        ("400", 524.0, 414.0), ("500", 590.0, 467.0),
-    ];
-    for (size, c2, c3) in pvc_d2.iter() {
-        table.insert((*size, "PVC", "D2"), (*c2, *c3));
-    }
+/// 전체 규격 평균 XLPE/PVC 허용전류 증가율 (%)
+#[tauri::command]
+fn average_insulation_current_ratio(method: String, loaded: u8, lang: Option<String>) -> Result<f64, String> {
+    kec_calculator::average_insulation_current_ratio(&method, loaded, lang.as_deref().unwrap_or("ko"))
+}
 
-    // E: 케이블 트레이 다심 (자유 공기 중) - Table B.52.10
-    let pvc_e = [
-        ("1.5", 22.0, 18.5), ("2.5", 30.0, 25.0), ("4", 40.0, 34.0), ("6", 51.0, 43.0),
-        ("10", 70.0, 60.0), ("16", 94.0, 80.0), ("25", 119.0, 101.0), ("35", 148.0, 126.0),
-        ("50", 180.0, 153.0), ("70", 232.0, 196.0), ("95", 282.0, 238.0), ("120", 328.0, 276.0),
-        ("150", 379.0, 319.0), ("185", 434.0, 364.0), ("240", 514.0, 430.0), ("300", 593.0, 497.0),
-        ("400", 694.0, 592.0), ("500", 806.0, 706.0),
-    ];
-    for (size, c2, c3) in pvc_e.iter() {
-        table.insert((*size, "PVC", "E"), (*c2, *c3));
-    }
-    
-    // F: 케이블 트레이 단심 (접촉 배치) - Table B.52.11 (단심 Flat/Touching)
-    // 단심 케이블은 다심보다 10-15% 높은 허용전류
-    let pvc_f = [
-        ("1.5", 25.0, 21.0), ("2.5", 34.0, 28.0), ("4", 45.0, 38.0), ("6", 58.0, 48.0),
-        ("10", 79.0, 67.0), ("16", 105.0, 89.0), ("25", 133.0, 113.0), ("35", 166.0, 141.0),
-        ("50", 201.0, 171.0), ("70", 259.0, 219.0), ("95", 315.0, 266.0), ("120", 367.0, 309.0),
-        ("150", 424.0, 357.0), ("185", 486.0, 408.0), ("240", 575.0, 482.0), ("300", 664.0, 557.0),
-        ("400", 777.0, 664.0), ("500", 903.0, 791.0),
-    ];
-    for (size, c2, c3) in pvc_f.iter() {
-        table.insert((*size, "PVC", "F"), (*c2, *c3));
-    }
+/// 캐시를 거치는 계산 (데이터 체크섬 + 입력값 기준)
+#[tauri::command]
+fn calculate_cached(data: CableData) -> Result<CalculationResult, String> {
+    kec_calculator::calculate_cached(&data)
+}
 
-    // ============================================================
-    // XLPE 절연 (90°C) - IEC 60364-5-52 Table B.52.5
-    // 주변온도 30°C (공기) / 20°C (지중) 기준
-    // ============================================================
-
-    // A1: 단열벽 속 전선관 (단심) - Table B.52.5 Column 2/3
-    let xlpe_a1 = [
-        ("1.5", 19.5, 17.0), ("2.5", 26.0, 23.0), ("4", 35.0, 31.0), ("6", 45.0, 40.0),
-        ("10", 61.0, 54.0), ("16", 81.0, 73.0), ("25", 106.0, 95.0), ("35", 131.0, 117.0),
-        ("50", 158.0, 141.0), ("70", 200.0, 179.0), ("95", 241.0, 216.0), ("120", 278.0, 249.0),
-        ("150", 318.0, 285.0), ("185", 362.0, 324.0), ("240", 424.0, 380.0), ("300", 486.0, 435.0),
-        ("400", 561.0, 503.0), ("500", 645.0, 578.0),
-    ];
-    for (size, c2, c3) in xlpe_a1.iter() {
-        table.insert((*size, "XLPE", "A1"), (*c2, *c3));
-    }
-    
-    // A2: 단열벽 속 전선관 (다심) - Table B.52.5 Column 4/5
-    let xlpe_a2 = [
-        ("1.5", 18.5, 16.5), ("2.5", 25.0, 22.0), ("4", 33.0, 30.0), ("6", 42.0, 38.0),
-        ("10", 57.0, 51.0), ("16", 76.0, 68.0), ("25", 99.0, 89.0), ("35", 121.0, 109.0),
-        ("50", 145.0, 130.0), ("70", 183.0, 164.0), ("95", 220.0, 197.0), ("120", 253.0, 227.0),
-        ("150", 290.0, 259.0), ("185", 329.0, 295.0), ("240", 386.0, 346.0), ("300", 442.0, 396.0),
-        ("400", 511.0, 458.0), ("500", 587.0, 526.0),
-    ];
-    for (size, c2, c3) in xlpe_a2.iter() {
-        table.insert((*size, "XLPE", "A2"), (*c2, *c3));
-    }
+/// 사용자 허용전류 테이블 로드 (JSON), 결과 캐시 자동 무효화
+#[tauri::command]
+fn load_custom_current_table(json: String, lang: Option<String>) -> Result<usize, String> {
+    kec_calculator::load_custom_current_table(&json, lang.as_deref().unwrap_or("ko"))
+}
 
-    // B1: 벽면 고정 전선관 (단심) - Table B.52.5 Column 6/7
-    let xlpe_b1 = [
-        ("1.5", 23.0, 20.0), ("2.5", 31.0, 28.0), ("4", 42.0, 37.0), ("6", 54.0, 48.0),
-        ("10", 75.0, 66.0), ("16", 100.0, 88.0), ("25", 133.0, 117.0), ("35", 164.0, 144.0),
-        ("50", 198.0, 175.0), ("70", 253.0, 222.0), ("95", 306.0, 269.0), ("120", 354.0, 312.0),
-        ("150", 407.0, 358.0), ("185", 464.0, 408.0), ("240", 546.0, 481.0), ("300", 628.0, 553.0),
-        ("400", 732.0, 644.0), ("500", 846.0, 745.0),
-    ];
-    for (size, c2, c3) in xlpe_b1.iter() {
-        table.insert((*size, "XLPE", "B1"), (*c2, *c3));
-    }
+/// 내장 허용전류 테이블로 복귀, 결과 캐시 자동 무효화
+#[tauri::command]
+fn reset_current_table() {
+    kec_calculator::reset_current_table()
+}
 
-    // B2: 벽면 고정 전선관 (다심) - Table B.52.5 Column 8/9
-    let xlpe_b2 = [
-        ("1.5", 22.0, 19.5), ("2.5", 30.0, 27.0), ("4", 40.0, 35.0), ("6", 51.0, 45.0),
-        ("10", 69.0, 62.0), ("16", 91.0, 82.0), ("25", 119.0, 107.0), ("35", 146.0, 131.0),
-        ("50", 175.0, 158.0), ("70", 221.0, 200.0), ("95", 265.0, 240.0), ("120", 305.0, 276.0),
-        ("150", 349.0, 316.0), ("185", 395.0, 358.0), ("240", 462.0, 419.0), ("300", 528.0, 479.0),
-        ("400", 609.0, 553.0), ("500", 698.0, 635.0),
-    ];
-    for (size, c2, c3) in xlpe_b2.iter() {
-        table.insert((*size, "XLPE", "B2"), (*c2, *c3));
-    }
+/// 현재 계산 데이터 체크섬
+#[tauri::command]
+fn get_data_checksum() -> String {
+    kec_calculator::get_data_checksum()
+}
 
-    // C: 벽면 직접 고정 - Table B.52.5 Column 10/11
-    let xlpe_c = [
-        ("1.5", 24.0, 22.0), ("2.5", 33.0, 30.0), ("4", 45.0, 40.0), ("6", 58.0, 52.0),
-        ("10", 80.0, 71.0), ("16", 107.0, 96.0), ("25", 138.0, 119.0), ("35", 171.0, 147.0),
-        ("50", 209.0, 179.0), ("70", 269.0, 229.0), ("95", 328.0, 278.0), ("120", 382.0, 322.0),
-        ("150", 441.0, 371.0), ("185", 506.0, 424.0), ("240", 599.0, 500.0), ("300", 693.0, 576.0),
-        ("400", 812.0, 673.0), ("500", 942.0, 778.0),
-    ];
-    for (size, c2, c3) in xlpe_c.iter() {
-        table.insert((*size, "XLPE", "C"), (*c2, *c3));
-    }
+/// 계산 결과 캐시 수동 무효화 (삭제된 항목 수 반환)
+#[tauri::command]
+fn invalidate_cache() -> usize {
+    kec_calculator::invalidate_cache()
+}
 
-    // D1: 지중 덕트 - Table B.52.5 Column 12/13
-    let xlpe_d1 = [
-        ("1.5", 28.0, 22.0), ("2.5", 36.0, 29.0), ("4", 46.0, 37.0), ("6", 57.0, 46.0),
-        ("10", 75.0, 60.0), ("16", 97.0, 77.0), ("25", 123.0, 99.0), ("35", 149.0, 119.0),
-        ("50", 176.0, 140.0), ("70", 218.0, 173.0), ("95", 259.0, 204.0), ("120", 295.0, 233.0),
-        ("150", 334.0, 263.0), ("185", 376.0, 295.0), ("240", 434.0, 340.0), ("300", 492.0, 384.0),
-        ("400", 565.0, 441.0), ("500", 641.0, 499.0),
-    ];
-    for (size, c2, c3) in xlpe_d1.iter() {
-        table.insert((*size, "XLPE", "D1"), (*c2, *c3));
-    }
+/// 입력 변경분에 따른 부분 재계산 (수행/생략한 계산 포함)
+#[tauri::command]
+fn recalculate_partial(old_data: CableData, old_result: CalculationResult, new_data: CableData) -> Result<PartialRecalculation, String> {
+    kec_calculator::recalculate_partial(&old_data, &old_result, &new_data)
+}
 
-    // D2: 지중 직매 - Table B.52.5 Column 14/15
-    let xlpe_d2 = [
-        ("1.5", 31.0, 24.0), ("2.5", 41.0, 31.0), ("4", 52.0, 40.0), ("6", 65.0, 50.0),
-        ("10", 85.0, 66.0), ("16", 110.0, 85.0), ("25", 141.0, 109.0), ("35", 170.0, 132.0),
-        ("50", 202.0, 156.0), ("70", 251.0, 193.0), ("95", 300.0, 229.0), ("120", 343.0, 261.0),
-        ("150", 390.0, 296.0), ("185", 440.0, 333.0), ("240", 510.0, 385.0), ("300", 578.0, 436.0),
-        ("400", 664.0, 500.0), ("500", 753.0, 566.0),
-    ];
-    for (size, c2, c3) in xlpe_d2.iter() {
-        table.insert((*size, "XLPE", "D2"), (*c2, *c3));
-    }
+/// 전압강하 계산 (변압기 임피던스 포함 옵션, 기여분 분리)
+#[tauri::command]
+fn calculate_voltage_drop(data: CableData, input: VoltageDropInput) -> Result<VoltageDropResult, String> {
+    kec_calculator::calculate_voltage_drop(&data, &input)
+}
 
-    // E: 케이블 트레이 다심 (자유 공기 중) - Table B.52.12
-    let xlpe_e = [
-        ("1.5", 26.0, 23.0), ("2.5", 36.0, 32.0), ("4", 49.0, 42.0), ("6", 63.0, 54.0),
-        ("10", 86.0, 75.0), ("16", 115.0, 100.0), ("25", 149.0, 127.0), ("35", 185.0, 158.0),
-        ("50", 225.0, 192.0), ("70", 289.0, 246.0), ("95", 352.0, 298.0), ("120", 410.0, 346.0),
-        ("150", 473.0, 399.0), ("185", 542.0, 456.0), ("240", 641.0, 538.0), ("300", 741.0, 621.0),
-        ("400", 868.0, 742.0), ("500", 1008.0, 887.0),
-    ];
-    for (size, c2, c3) in xlpe_e.iter() {
-        table.insert((*size, "XLPE", "E"), (*c2, *c3));
-    }
-    
-    // F: 케이블 트레이 단심 (접촉 배치) - Table B.52.13 (단심 Touching/Trefoil)
-    let xlpe_f = [
-        ("1.5", 29.0, 25.0), ("2.5", 40.0, 35.0), ("4", 55.0, 47.0), ("6", 71.0, 60.0),
-        ("10", 96.0, 83.0), ("16", 128.0, 111.0), ("25", 166.0, 141.0), ("35", 206.0, 176.0),
-        ("50", 251.0, 214.0), ("70", 323.0, 274.0), ("95", 393.0, 332.0), ("120", 458.0, 386.0),
-        ("150", 529.0, 445.0), ("185", 606.0, 509.0), ("240", 717.0, 601.0), ("300", 829.0, 694.0),
-        ("400", 971.0, 828.0), ("500", 1127.0, 990.0),
-    ];
-    for (size, c2, c3) in xlpe_f.iter() {
-        table.insert((*size, "XLPE", "F"), (*c2, *c3));
-    }
+/// 여러 전선 종류의 허용전류 일괄 조회 (입력 순서 유지)
+#[tauri::command]
+fn bulk_current_by_type(types: Vec<String>, size: String, method: String, system: String, lang: Option<String>) -> Vec<(String, Result<f64, String>)> {
+    kec_calculator::bulk_current_by_type(&types, &size, &method, &system, lang.as_deref().unwrap_or("ko"))
+}
 
-    table
-}
-
-/// 전선 종류별 외경 데이터 (mm) - KEC 기준 제조사 규격
-/// TFR-CV: 0.6/1kV 가교폴리에틸렌 절연 난연 PVC 시스 케이블
-fn get_cable_outer_diameter(cable_type: &str, size: &str, cores: &str) -> Option<f64> {
-    // TFR-CV 케이블 외경 (dcord.com 기준)
-    let tfr_cv_1c: HashMap<&str, f64> = [
-        ("1.5", 6.3), ("2.5", 6.7), ("4", 7.2), ("6", 7.8),
-        ("10", 9.4), ("16", 10.0), ("25", 12.0), ("35", 13.0),
-        ("50", 14.5), ("70", 16.0), ("95", 18.5), ("120", 20.0),
-        ("150", 22.0), ("185", 24.0), ("240", 27.0), ("300", 30.0),
-        ("400", 34.0), ("500", 37.0),
-    ].iter().cloned().collect();
-
-    let tfr_cv_2c: HashMap<&str, f64> = [
-        ("1.5", 11.0), ("2.5", 12.0), ("4", 13.0), ("6", 14.0),
-        ("10", 18.0), ("16", 21.0), ("25", 25.0), ("35", 29.0),
-        ("50", 34.0), ("70", 39.0), ("95", 44.0), ("120", 50.0),
-        ("150", 55.0), ("185", 61.0), ("240", 67.0), ("300", 75.0),
-    ].iter().cloned().collect();
-
-    // 3C 외경은 2C의 약 1.15배
-    // 4C 외경은 2C의 약 1.25배
-
-    // HFIX 전선 외경 (nexans, daeshincable 기준)
-    let hfix_1c: HashMap<&str, f64> = [
-        ("1.5", 3.3), ("2.5", 4.0), ("4", 4.6), ("6", 5.2),
-        ("10", 6.5), ("16", 8.0), ("25", 10.1), ("35", 11.3),
-        ("50", 13.2), ("70", 15.5), ("95", 18.0), ("120", 20.0),
-        ("150", 22.5), ("185", 25.0), ("240", 28.5), ("300", 32.0),
-    ].iter().cloned().collect();
-
-    // CV 케이블 (TFR-CV와 유사하나 약간 작음)
-    let cv_1c: HashMap<&str, f64> = [
-        ("1.5", 6.0), ("2.5", 6.4), ("4", 6.9), ("6", 7.5),
-        ("10", 9.0), ("16", 9.6), ("25", 11.5), ("35", 12.5),
-        ("50", 14.0), ("70", 15.5), ("95", 18.0), ("120", 19.5),
-        ("150", 21.5), ("185", 23.5), ("240", 26.5), ("300", 29.5),
-        ("400", 33.0), ("500", 36.0),
-    ].iter().cloned().collect();
-
-    let cv_2c: HashMap<&str, f64> = [
-        ("1.5", 10.5), ("2.5", 11.5), ("4", 12.5), ("6", 13.5),
-        ("10", 17.0), ("16", 20.0), ("25", 24.0), ("35", 28.0),
-        ("50", 33.0), ("70", 38.0), ("95", 43.0), ("120", 49.0),
-        ("150", 54.0), ("185", 60.0), ("240", 66.0), ("300", 74.0),
-    ].iter().cloned().collect();
-
-    // FR-CV (내화 케이블) - TFR-CV보다 약간 큼
-    let fr_cv_1c: HashMap<&str, f64> = [
-        ("1.5", 6.8), ("2.5", 7.2), ("4", 7.7), ("6", 8.3),
-        ("10", 10.0), ("16", 10.6), ("25", 12.6), ("35", 13.6),
-        ("50", 15.1), ("70", 16.6), ("95", 19.1), ("120", 20.6),
-        ("150", 22.6), ("185", 24.6), ("240", 27.6), ("300", 30.6),
-        ("400", 34.6), ("500", 37.6),
-    ].iter().cloned().collect();
-
-    // TFR-8 (내열 케이블)
-    let tfr_8_1c: HashMap<&str, f64> = [
-        ("1.5", 6.5), ("2.5", 6.9), ("4", 7.4), ("6", 8.0),
-        ("10", 9.6), ("16", 10.2), ("25", 12.2), ("35", 13.2),
-        ("50", 14.7), ("70", 16.2), ("95", 18.7), ("120", 20.2),
-        ("150", 22.2), ("185", 24.2), ("240", 27.2), ("300", 30.2),
-    ].iter().cloned().collect();
-
-    match (cable_type, cores) {
-        ("HFIX", "1C") => hfix_1c.get(size).copied(),
-        ("HFIX", _) => None, // HFIX는 단심만 존재
-        
-        ("TFR-CV", "1C") => tfr_cv_1c.get(size).copied(),
-        ("TFR-CV", "2C") => tfr_cv_2c.get(size).copied(),
-        ("TFR-CV", "3C") => tfr_cv_2c.get(size).map(|d| d * 1.15),
-        ("TFR-CV", "4C") => tfr_cv_2c.get(size).map(|d| d * 1.25),
-        
-        ("CV", "1C") => cv_1c.get(size).copied(),
-        ("CV", "2C") => cv_2c.get(size).copied(),
-        ("CV", "3C") => cv_2c.get(size).map(|d| d * 1.15),
-        ("CV", "4C") => cv_2c.get(size).map(|d| d * 1.25),
-
-        ("FR-CV", "1C") => fr_cv_1c.get(size).copied(),
-        ("FR-CV", "2C") => fr_cv_1c.get(size).map(|d| d * 1.65),
-        ("FR-CV", "3C") => fr_cv_1c.get(size).map(|d| d * 1.9),
-        ("FR-CV", "4C") => fr_cv_1c.get(size).map(|d| d * 2.1),
-
-        ("TFR-8", "1C") => tfr_8_1c.get(size).copied(),
-        ("TFR-8", "2C") => tfr_8_1c.get(size).map(|d| d * 1.65),
-        ("TFR-8", "3C") => tfr_8_1c.get(size).map(|d| d * 1.9),
-        ("TFR-8", "4C") => tfr_8_1c.get(size).map(|d| d * 2.1),
-
-        _ => None,
-    }
+/// 규격 입력 자동완성/오타 제안
+#[tauri::command]
+fn suggest_sizes(partial: String) -> Vec<String> {
+    kec_calculator::suggest_sizes(&partial)
 }
 
-/// 전선 단면적 계산 (외경 기준, 원형)
-fn calculate_cable_area(outer_diameter: f64) -> f64 {
-    std::f64::consts::PI * (outer_diameter / 2.0).powi(2)
-}
-
-/// 전선관 내경 데이터 (mm) - 후강전선관 기준
-fn get_conduit_data() -> Vec<(&'static str, f64)> {
-    vec![
-        ("C16 (16mm)", 15.8),
-        ("C22 (22mm)", 21.0),
-        ("C28 (28mm)", 26.6),
-        ("C36 (36mm)", 35.0),
-        ("C42 (42mm)", 41.0),
-        ("C54 (54mm)", 53.0),
-        ("C70 (70mm)", 69.0),
-        ("C82 (82mm)", 80.0),
-        ("C92 (92mm)", 89.0),
-        ("C104 (104mm)", 101.0),
-    ]
-}
-
-/// KEC 232.2 기준 추천 전선관 크기 계산 (33% 점유율)
-fn recommend_conduit(total_area: f64) -> (String, f64) {
-    let conduits = get_conduit_data();
-    let max_fill_rate = 0.33; // KEC 232.2: 1/3 (33%) 이하
-
-    for (name, inner_diameter) in conduits {
-        let conduit_area = std::f64::consts::PI * (inner_diameter / 2.0).powi(2);
-        let available_area = conduit_area * max_fill_rate;
-        
-        if available_area >= total_area {
-            let actual_fill = (total_area / conduit_area) * 100.0;
-            return (name.to_string(), actual_fill);
-        }
-    }
+/// 심선 색상·용도 (UI 결선도용)
+#[tauri::command]
+fn get_core_colors(cable_type: String, cores: String) -> Vec<(String, String)> {
+    kec_calculator::get_core_colors(&cable_type, &cores)
+}
 
-    ("C104 이상 검토 필요".to_string(), 100.0)
+/// 프로젝트 전체를 .kecproj 파일로 저장
+#[tauri::command]
+fn export_project_file(path: String, metadata: ProjectMetadata, circuits: Vec<CableData>, settings: HashMap<String, String>) -> Result<(), String> {
+    let json = kec_calculator::export_project_file(metadata, circuits, settings)?;
+    std::fs::write(&path, json).map_err(|e| format!("프로젝트 파일을 저장할 수 없습니다: {} ({})", path, e))
 }
 
-/// 전선 종류에 따른 절연체 반환
-fn get_insulation_type(cable_type: &str) -> &'static str {
-    match cable_type {
-        "HFIX" => "XLPE",     // 저독성 가교 폴리올레핀 (90°C)
-        "CV" | "TFR-CV" | "FR-CV" | "TFR-8" => "XLPE",  // 가교 폴리에틸렌 (90°C)
-        _ => "PVC",          // 비닐 (70°C)
-    }
+/// .kecproj 파일에서 프로젝트 복원 (내장 데이터셋과 버전이 다르면 에러)
+#[tauri::command]
+fn import_project_file(path: String, lang: Option<String>) -> Result<ProjectFile, String> {
+    let json = std::fs::read_to_string(&path).map_err(|e| format!("프로젝트 파일을 읽을 수 없습니다: {} ({})", path, e))?;
+    kec_calculator::import_project_file(&json, lang.as_deref().unwrap_or("ko"))
 }
 
-/// 집합 보정 계수 (KEC Table B.52.17)
-fn get_grouping_factor(num_circuits: u32) -> f64 {
-    match num_circuits {
-        0 | 1 => 1.00,
-        2 => 0.80,
-        3 => 0.70,
-        4 => 0.65,
-        5 => 0.60,
-        6 => 0.57,
-        7 => 0.54,
-        8 => 0.52,
-        9 => 0.50,
-        10..=12 => 0.45,
-        13..=16 => 0.41,
-        17..=20 => 0.38,
-        _ => 0.38, // 20회로 초과 시 0.38 적용 (보수적 접근)
-    }
+/// 케이블 간 공극을 반영한 실효 점유 면적 ((외경, 개수) 목록)
+#[tauri::command]
+fn effective_occupied_area(cables: Vec<(f64, u32)>) -> f64 {
+    kec_calculator::effective_occupied_area(&cables)
 }
 
-/// 공사방법 설명
-fn get_install_method_description(method: &str) -> String {
-    match method {
-        "A1" => "단열벽 속 전선관 (절연전선/단심 케이블)".to_string(),
-        "A2" => "단열벽 속 전선관 (다심 케이블)".to_string(),
-        "B1" => "벽면 고정 전선관 (절연전선/단심 케이블)".to_string(),
-        "B2" => "벽면 고정 전선관 (다심 케이블)".to_string(),
-        "C" => "벽면/천정 직접 고정 (공기 중)".to_string(),
-        "D1" => "지중 매설 덕트".to_string(),
-        "D2" => "지중 매설 직매".to_string(),
-        "E" => "케이블 트레이 (천공형, 단심)".to_string(),
-        "F" => "케이블 트레이 (천공형, 다심)".to_string(),
-        _ => "기타".to_string(),
-    }
+/// 계산 결과 필드별 단위
+#[tauri::command]
+fn result_field_units() -> HashMap<String, String> {
+    kec_calculator::result_field_units()
 }
 
-/// 메인 계산 함수 (Tauri 커맨드)
+/// 설치 구역별 난연/내화 적합성 판정 (부적합 시 대체 종류 제안)
 #[tauri::command]
-fn calculate(data: CableData) -> Result<CalculationResult, String> {
-    // 외경 계산
-    let outer_diameter = get_cable_outer_diameter(&data.cable_type, &data.size, &data.cores)
-        .ok_or("지원하지 않는 전선 규격입니다.")?;
-    
-    // 단위 케이블 단면적 (외경 기준)
-    let single_cable_area = calculate_cable_area(outer_diameter);
-    
-    // 총 단면적 (수량 적용) - 전선관 채움률 계산용
-    let mut total_area = single_cable_area * data.quantity as f64;
-    
-    // 도체 단면적 계산
-    let conductor_area: f64 = data.size.parse::<f64>().unwrap_or(0.0) * data.quantity as f64;
-
-    // 접지선 단면적 추가 (HFIX)
-    if data.ground_wire == "HFIX" {
-        // 접지선 규격 (주 전선의 약 50%)
-        let ground_size = match data.size.as_str() {
-            "1.5" | "2.5" => "1.5",
-            "4" | "6" => "2.5",
-            "10" | "16" => "6",
-            "25" | "35" => "16",
-            "50" | "70" => "25",
-            "95" | "120" => "35",
-            "150" | "185" => "70",
-            _ => "95",
-        };
-        if let Some(ground_od) = get_cable_outer_diameter("HFIX", ground_size, "1C") {
-            total_area += calculate_cable_area(ground_od);
-        }
-    }
+fn check_fire_compliance(cable_type: String, zone: String, lang: Option<String>) -> Result<ComplianceResult, String> {
+    kec_calculator::check_fire_compliance(&cable_type, &zone, lang.as_deref().unwrap_or("ko"))
+}
 
-    // 절연체 종류 결정
-    let insulation = get_insulation_type(&data.cable_type);
-    
-    // 공사방법 결정
-    let install_method = if data.install_method.is_empty() {
-        match data.cores.as_str() {
-            "1C" => "B1",
-            _ => "B2",
-        }
-    } else {
-        &data.install_method
-    };
+/// 간선 용량 검토 (분기 부하 합 × 수용률 vs 간선 허용전류)
+#[tauri::command]
+fn check_feeder_capacity(feeder: CableData, branches: Vec<f64>) -> Result<FeederCapacityResult, String> {
+    kec_calculator::check_feeder_capacity(&feeder, &branches)
+}
 
-    // 허용전류 테이블에서 값 조회 (2부하/3부하 분리)
-    let current_table = get_allowable_current_table();
-    let current_values = current_table
-        .get(&(data.size.as_str(), insulation, install_method))
-        .ok_or("허용전류 데이터를 찾을 수 없습니다.")?;
-
-    // 시스템 및 심선 수에 따른 부하 도체 수 판단
-    // 1Φ (단상) -> 2 Loaded (2가닥 부하)
-    // 3Φ (3상) -> 3 Loaded (3가닥 부하)
-    let (base_current, loaded_label) = match data.system.as_str() {
-        "1Φ" => (current_values.0, "2부하(단상)"),
-        "3Φ" => (current_values.1, "3부하(3상)"),
-        _ => (current_values.0, "2부하(기본)"),
-    };
+/// 감사 로그 조회 (from~to 날짜 범위, "YYYY-MM-DD", 빈 문자열은 제한 없음)
+#[tauri::command]
+fn get_audit_log(from: String, to: String) -> Vec<AuditEntry> {
+    kec_calculator::get_audit_log(&from, &to)
+}
 
-    // 집합 보정 계수 (Grouping Factor) 계산
-    // 1C(단심) 케이블인 경우, 회로 수 계산:
-    // 1Φ: 2가닥 = 1회로
-    // 3Φ: 3가닥 = 1회로
-    let num_circuits = if data.cores == "1C" {
-        let cables_per_circuit = if data.system == "1Φ" { 2 } else { 3 };
-        // 올림 계산 (남는 케이블이 있으면 회로로 간주)
-        (data.quantity + cables_per_circuit - 1) / cables_per_circuit
-    } else {
-        // 다심 케이블은 수량 자체가 회로 수
-        data.quantity
-    };
-    
-    let grouping_factor = get_grouping_factor(num_circuits);
-
-    // 심선 수 감소계수 (기존 코드는 이걸로 3상 변환을 시도했으나, 이제 표준 테이블 사용)
-    // 그러나 "1C"가 아닌 "2C/3C/4C" 케이블 자체의 열적 특성은 이미 테이블에 반영됨 (2/3 loaded)
-    // 단, 4C 케이블의 경우 KEC에서 3부하 도체로 간주하므로 추가 감소 없음 (중성선 부하 제외 가정)
-    // 따라서 별도의 심선 수 감소계수는 삭제하고, Grouping Factor와 Loaded Table로 대체함.
-
-    // 최종 허용전류 계산
-    // 허용전류 = 기본값 * 집합보정계수 * (온도보정계수 1.0 가정)
-    let allowable_current = base_current * grouping_factor;
-
-    // 추천 전선관 계산
-    let (recommended_conduit, fill_rate) = recommend_conduit(total_area);
-
-    // 공사방법 설명
-    let install_method_desc = format!(
-        "{} / {} / 집합계수: {:.2} ({}회로)",
-        get_install_method_description(install_method),
-        loaded_label,
-        grouping_factor,
-        num_circuits
-    );
-
-    Ok(CalculationResult {
-        total_area: (total_area * 100.0).round() / 100.0,
-        conductor_area: (conductor_area * 100.0).round() / 100.0,
-        allowable_current: (allowable_current * 10.0).round() / 10.0,
-        recommended_conduit,
-        fill_rate: (fill_rate * 10.0).round() / 10.0,
-        install_method_desc,
-    })
-}
-
-/// 전선 종류 목록 반환 (KEC 기준)
-#[tauri::command]
-fn get_cable_types() -> Vec<CableTypeInfo> {
-    vec![
-        CableTypeInfo {
-            code: "HFIX".to_string(),
-            name: "HFIX (저독성 난연 전선)".to_string(),
-            description: "KS C 3341, 저독성 난연 폴리올레핀 절연".to_string(),
-            max_temp: 90,
-            insulation: "XLPE".to_string(),
-        },
-        CableTypeInfo {
-            code: "TFR-CV".to_string(),
-            name: "TFR-CV (난연 트레이용)".to_string(),
-            description: "0.6/1kV 가교폴리에틸렌 절연 난연 PVC 시스".to_string(),
-            max_temp: 90,
-            insulation: "XLPE".to_string(),
-        },
-        CableTypeInfo {
-            code: "CV".to_string(),
-            name: "CV (일반 전력 케이블)".to_string(),
-            description: "0.6/1kV 가교폴리에틸렌 절연 비닐 시스".to_string(),
-            max_temp: 90,
-            insulation: "XLPE".to_string(),
-        },
-        CableTypeInfo {
-            code: "FR-CV".to_string(),
-            name: "FR-CV (내화 케이블)".to_string(),
-            description: "0.6/1kV 내화 가교폴리에틸렌 절연".to_string(),
-            max_temp: 90,
-            insulation: "XLPE".to_string(),
-        },
-        CableTypeInfo {
-            code: "TFR-8".to_string(),
-            name: "TFR-8 (내열 케이블)".to_string(),
-            description: "0.6/1kV 내열 가교폴리에틸렌 절연".to_string(),
-            max_temp: 90,
-            insulation: "XLPE".to_string(),
-        },
-    ]
+/// 규격별 최대 허용 단락 전류 (kA, I = k·S/√t, duration 미지정 시 1초)
+#[tauri::command]
+fn max_short_circuit_current(size: String, insulation: String, material: String, duration_s: Option<f64>, lang: Option<String>) -> Result<f64, String> {
+    kec_calculator::max_short_circuit_current(&size, &insulation, &material, duration_s, lang.as_deref().unwrap_or("ko"))
+}
+
+/// 공사방법별 허용전류를 기준 방법 대비 %로 비교 (기준 방법 = 100)
+#[tauri::command]
+fn method_current_ratios(size: String, insulation: String, loaded: u8, base_method: String, lang: Option<String>) -> Result<Vec<(String, f64)>, String> {
+    kec_calculator::method_current_ratios(&size, &insulation, loaded, &base_method, lang.as_deref().unwrap_or("ko"))
+}
+
+/// 설비 유형·회로 구분별 전압강하 허용치 (%, KEC 232.3.9)
+#[tauri::command]
+fn get_voltage_drop_limit(installation_type: String, is_branch: bool, length_over_100m: bool, lang: Option<String>) -> Result<f64, String> {
+    kec_calculator::get_voltage_drop_limit(&installation_type, is_branch, length_over_100m, lang.as_deref().unwrap_or("ko"))
+}
+
+/// 용도·전압 방식별 최소 규격 (mm²)
+#[tauri::command]
+fn get_minimum_size(usage: String, system: String, lang: Option<String>) -> Result<String, String> {
+    kec_calculator::get_minimum_size(&usage, &system, lang.as_deref().unwrap_or("ko")).map(String::from)
+}
+
+/// 허용전류 데이터가 없는 조합의 유사 조합 제안 (절연, 공사방법, 규격)
+#[tauri::command]
+fn suggest_nearest_available(size: String, insulation: String, method: String, lang: Option<String>) -> NearestAvailable {
+    kec_calculator::suggest_nearest_available(&size, &insulation, &method, lang.as_deref().unwrap_or("ko"))
+}
+
+/// 전압 방식(1Φ/3Φ) 변경 시 허용전류·추천 규격 변화 미리보기
+#[tauri::command]
+fn preview_system_change(data: CableData) -> Result<SystemChangePreview, String> {
+    kec_calculator::preview_system_change(&data)
+}
+
+/// 인입 케이블·방향 기준 최소 풀박스 치수 추천
+#[tauri::command]
+fn recommend_pull_box(cables: Vec<CableData>, entry_type: String) -> Result<BoxDimensions, String> {
+    kec_calculator::recommend_pull_box(&cables, &entry_type)
+}
+
+/// 실제 전류에 대한 규격별 부하율 표 (규격, 허용전류, 부하율 %)
+#[tauri::command]
+fn load_ratio_table(cable_type: String, method: String, system: String, current: f64) -> Vec<(String, f64, f64)> {
+    kec_calculator::load_ratio_table(&cable_type, &method, &system, current)
+}
+
+/// 같은 입력의 KEC·IEC 계산 결과 교차 검증 (항목별 차이, 10% 이상 강조)
+#[tauri::command]
+fn cross_validate_regulations(data: CableData) -> Result<CrossValidationResult, String> {
+    kec_calculator::cross_validate_regulations(&data)
+}
+
+/// 종류·전압 방식·목표 전류를 만족하는 규격·공사방법 조합 탐색
+#[tauri::command]
+fn explore_solutions(cable_type: String, system: String, target_current: f64, constraints: SolutionConstraints) -> Vec<Solution> {
+    kec_calculator::explore_solutions(&cable_type, &system, target_current, &constraints)
+}
+
+/// 부하 조건으로 규격·차단기·전선관 통합 선정 (허용전류·보호협조·전압강하·단락용량)
+#[tauri::command]
+fn design_circuit(input: CircuitDesignInput) -> Result<CircuitDesignResult, String> {
+    kec_calculator::design_circuit(&input)
+}
+
+/// 부하전류로 최소 전선 규격 역산 (보정계수 적용 후 허용전류 기준)
+#[tauri::command]
+fn recommend_cable_size(
+    load_current: f64,
+    cable_type: String,
+    cores: String,
+    system: String,
+    install_method: String,
+    ambient_temp: i32,
+    lang: Option<String>,
+) -> Result<CableData, String> {
+    kec_calculator::recommend_cable_size(load_current, &cable_type, &cores, &system, &install_method, ambient_temp, lang.as_deref().unwrap_or("ko"))
+}
+
+/// 단락전류(kA)·동작시간(초) 기준 도체 단락 열적 허용 검증 (I²t ≤ k²S²)
+#[tauri::command]
+fn check_short_circuit_withstand(
+    size: String,
+    material: String,
+    insulation: String,
+    fault_current: f64,
+    clearing_time: f64,
+    lang: Option<String>,
+) -> Result<bool, String> {
+    kec_calculator::check_short_circuit_withstand(&size, &material, &insulation, fault_current, clearing_time, lang.as_deref().unwrap_or("ko"))
+}
+
+/// 계산 결과를 CSV 파일로 저장 (UTF-8 BOM, 엑셀 호환)
+#[tauri::command]
+fn export_results_csv(items: Vec<CableData>, results: Vec<CalculationResult>, path: String) -> Result<(), String> {
+    let csv = kec_calculator::export_results_csv(&items, &results)?;
+    std::fs::write(&path, csv).map_err(|e| format!("CSV 파일을 저장할 수 없습니다: {} ({})", path, e))
+}
+
+/// 비표준 규격의 기본 허용전류 추정 (표준 규격 사이 로그-로그 보간, 참고용)
+#[tauri::command]
+fn get_allowable_current_interpolated(size_mm2: f64, insulation: String, method: String, loaded: u8) -> Option<InterpolatedCurrent> {
+    kec_calculator::get_allowable_current_interpolated(size_mm2, &insulation, &method, loaded)
+}
+
+/// 부하 용량(kW)·역률로 최소 전선 규격 역산 (역률 미지정 시 0.8, 계산 근거 전류 포함)
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+fn recommend_cable_size_from_power(
+    power_kw: f64,
+    voltage: f64,
+    power_factor: Option<f64>,
+    cable_type: String,
+    cores: String,
+    system: String,
+    install_method: String,
+    ambient_temp: i32,
+    lang: Option<String>,
+) -> Result<PowerSizeRecommendation, String> {
+    kec_calculator::recommend_cable_size_from_power(power_kw, voltage, power_factor, &cable_type, &cores, &system, &install_method, ambient_temp, lang.as_deref().unwrap_or("ko"))
+}
+
+/// 부하전류·허용전류로 보호 협조(IB ≤ IN ≤ IZ, I2 ≤ 1.45·IZ) 최소 차단기 정격 추천
+/// breaker_type 미지정 시 주택용 배선차단기
+#[tauri::command]
+fn recommend_breaker(
+    load_current: f64,
+    allowable_current: f64,
+    breaker_type: Option<kec_calculator::BreakerType>,
+    lang: Option<String>,
+) -> Result<u32, String> {
+    kec_calculator::recommend_breaker(load_current, allowable_current, breaker_type.unwrap_or_default(), lang.as_deref().unwrap_or("ko"))
+}
+
+/// 계산 입력·결과를 프로젝트별 이력 DB에 저장 (새 레코드 ID 반환)
+#[tauri::command]
+fn save_calculation(project: String, data: CableData, result: CalculationResult) -> Result<i64, String> {
+    history::save_calculation(&project, &data, &result)
+}
+
+/// 저장된 계산 이력 조회 (최신순, 프로젝트명 지정 시 해당 프로젝트만)
+#[tauri::command]
+fn list_calculations(project: Option<String>) -> Vec<CalculationRecord> {
+    history::list_calculations(project.as_deref())
+}
+
+/// 저장된 계산 이력 1건 삭제 (없는 ID면 false)
+#[tauri::command]
+fn delete_calculation(id: i64) -> Result<bool, String> {
+    history::delete_calculation(id)
+}
+
+/// 가닥수가 지원하는 공사방법별 보정 후 허용전류 비교 (공사방법 코드, 허용전류 A)
+#[tauri::command]
+fn compare_install_methods(data: CableData) -> Vec<(String, f64)> {
+    kec_calculator::compare_install_methods(&data)
+}
+
+/// 내장 케이블 외경 조회 (mm, 사용자 지정 외경 덮어쓰기 전 확인용)
+#[tauri::command]
+fn lookup_outer_diameter(cable_type: String, size: String, cores: String) -> Option<f64> {
+    kec_calculator::lookup_outer_diameter(&cable_type, &size, &cores)
+}
+
+/// 절연체·공사방법·부하 도체 수 조합의 허용전류 출처 표 (알 수 없는 코드면 None)
+#[tauri::command]
+fn get_table_reference(insulation: String, method: String, loaded_conductors: u8) -> Option<String> {
+    let insulation = Insulation::try_from(insulation.trim()).ok()?;
+    let method = InstallMethod::try_from(method.trim()).ok()?;
+    Some(kec_calculator::get_table_reference(insulation, method, loaded_conductors).to_string())
+}
+
+/// 공사방법의 허용전류 표 기준 주변온도 (°C, 알 수 없는 코드면 None)
+#[tauri::command]
+fn get_base_ambient_temp(method: String) -> Option<i32> {
+    InstallMethod::try_from(method.trim()).ok().map(kec_calculator::base_ambient_temp)
+}
+
+/// 전선관에 넣을 수 있는 같은 케이블의 최대 본수 (본수별 점유율 상한 적용, lang 미지정 시 ko)
+#[tauri::command]
+fn max_cables_in_conduit(
+    conduit_name: String,
+    cable_type: String,
+    size: String,
+    cores: String,
+    lang: Option<String>,
+) -> Result<u32, String> {
+    kec_calculator::max_cables_in_conduit(&conduit_name, &cable_type, &size, &cores, lang.as_deref().unwrap_or("ko"))
+}
+
+/// 연간 도체 전력손실 (kWh, 절연체(PVC/XLPE)의 도체 최고허용온도 저항 기준)
+#[tauri::command]
+fn calculate_power_loss(size: String, material: String, insulation: String, length_m: f64, current: f64, system: String, hours_per_year: f64) -> f64 {
+    kec_calculator::calculate_power_loss(&size, &material, &insulation, length_m, current, &system, hours_per_year)
+}
+
+/// 케이블 최소 굽힘 반경 (mm, 외경 × 종류별 배수)
+#[tauri::command]
+fn min_bending_radius(cable_type: String, outer_diameter: f64, cores: String) -> f64 {
+    kec_calculator::min_bending_radius(&cable_type, outer_diameter, &cores)
+}
+
+/// 포설 경로 견인 장력 (직선 W·μ·L, 굴곡 캡스턴 식 e^(μθ), 상향 수직 W·L)
+#[tauri::command]
+fn calculate_pulling_tension(data: CableData, sections: Vec<kec_calculator::PullSection>, friction: Option<f64>) -> Result<kec_calculator::PullingTension, String> {
+    kec_calculator::calculate_pulling_tension(&data, &sections, friction)
+}
+
+/// 전선 종류 목록 반환 (KEC 기준, lang: ko/en, 그 외는 ko)
+#[tauri::command]
+fn get_cable_types(lang: String) -> Vec<CableTypeInfo> {
+    kec_calculator::get_cable_types_localized(&lang)
 }
 
 /// 전선 종류별 지원 옵션 구조체
@@ -603,12 +394,13 @@ fn get_cable_types() -> Vec<CableTypeInfo> {
 pub struct CableTypeOptions {
     pub cores: Vec<(String, String)>,           // (코드, 표시명)
     pub sizes: Vec<String>,                      // 규격 목록
+    pub single_core_sizes: Vec<String>,          // 단심(1C)에서만 추가되는 규격 (단심 트레이 전용)
     pub install_methods: Vec<(String, String)>, // (코드, 표시명)
 }
 
-/// 전선 종류별 지원 옵션 반환 (필터링 데이터)
+/// 전선 종류별 지원 옵션 반환 (필터링 데이터, lang: ko/en)
 #[tauri::command]
-fn get_cable_options(cable_type: String) -> CableTypeOptions {
+fn get_cable_options(cable_type: String, lang: String) -> CableTypeOptions {
     // 기본 규격 목록
     let sizes_standard = vec![
         "1.5", "2.5", "4", "6", "10", "16", "25", "35",
@@ -620,154 +412,178 @@ fn get_cable_options(cable_type: String) -> CableTypeOptions {
         "50", "70", "95", "120", "150", "185", "240", "300", "400", "500",
     ].into_iter().map(String::from).collect::<Vec<_>>();
 
-    // 단심 전용 공사방법
-    let methods_single = vec![
-        ("A1".to_string(), "A1: 단열벽 속 전선관 (단심)".to_string()),
-        ("B1".to_string(), "B1: 벽면 고정 전선관 (단심)".to_string()),
-        ("C".to_string(), "C: 벽면/천정 직접 고정".to_string()),
-        ("D1".to_string(), "D1: 지중 매설 덕트".to_string()),
-        ("E".to_string(), "E: 케이블 트레이 (단심)".to_string()),
-    ];
-
-    // 전체 공사방법 (단심/다심 모두 지원)
-    let methods_all = vec![
-        ("A1".to_string(), "A1: 단열벽 속 전선관 (단심)".to_string()),
-        ("A2".to_string(), "A2: 단열벽 속 전선관 (다심)".to_string()),
-        ("B1".to_string(), "B1: 벽면 고정 전선관 (단심)".to_string()),
-        ("B2".to_string(), "B2: 벽면 고정 전선관 (다심)".to_string()),
-        ("C".to_string(), "C: 벽면/천정 직접 고정".to_string()),
-        ("D1".to_string(), "D1: 지중 매설 덕트".to_string()),
-        ("D2".to_string(), "D2: 지중 매설 직매".to_string()),
-        ("E".to_string(), "E: 케이블 트레이 (단심)".to_string()),
-        ("F".to_string(), "F: 케이블 트레이 (다심)".to_string()),
-    ];
+    // IEC는 630mm² 이상을 단심만 수록
+    let sizes_single_core = vec!["630", "800", "1000"].into_iter().map(String::from).collect::<Vec<_>>();
+
+    let cores = |codes: &[&str]| -> Vec<(String, String)> {
+        codes.iter().map(|code| (code.to_string(), kec_calculator::core_label(code, &lang))).collect()
+    };
 
     match cable_type.as_str() {
         "HFIX" => CableTypeOptions {
-            cores: vec![("1C".to_string(), "1C (단심)".to_string())],
+            cores: cores(&["1C"]),
             sizes: sizes_standard,
-            install_methods: methods_single,
+            single_core_sizes: vec![],
+            // 단심 전용 공사방법
+            install_methods: kec_calculator::get_install_methods_for_cores("1C", &lang),
         },
         "TFR-CV" | "CV" => CableTypeOptions {
-            cores: vec![
-                ("1C".to_string(), "1C (단심)".to_string()),
-                ("2C".to_string(), "2C (2심)".to_string()),
-                ("3C".to_string(), "3C (3심)".to_string()),
-                ("4C".to_string(), "4C (4심)".to_string()),
-            ],
+            cores: cores(&["1C", "2C", "3C", "4C"]),
             sizes: sizes_extended,
-            install_methods: methods_all,
+            single_core_sizes: sizes_single_core,
+            install_methods: kec_calculator::get_install_methods(&lang),
         },
         "FR-CV" | "TFR-8" => CableTypeOptions {
-            cores: vec![
-                ("1C".to_string(), "1C (단심)".to_string()),
-                ("2C".to_string(), "2C (2심)".to_string()),
-                ("3C".to_string(), "3C (3심)".to_string()),
-                ("4C".to_string(), "4C (4심)".to_string()),
-            ],
+            cores: cores(&["1C", "2C", "3C", "4C"]),
             sizes: sizes_standard,
-            install_methods: methods_all,
+            single_core_sizes: vec![],
+            install_methods: kec_calculator::get_install_methods(&lang),
         },
         _ => CableTypeOptions {
             cores: vec![],
             sizes: vec![],
+            single_core_sizes: vec![],
             install_methods: vec![],
         },
     }
 }
 
-/// 가닥수에 따른 공사방법 필터링
-#[tauri::command]
-fn get_install_methods_for_cores(cores: String) -> Vec<(String, String)> {
-    match cores.as_str() {
-        "1C" => vec![
-            ("A1".to_string(), "A1: 단열벽 속 전선관 (단심)".to_string()),
-            ("B1".to_string(), "B1: 벽면 고정 전선관 (단심)".to_string()),
-            ("C".to_string(), "C: 벽면/천정 직접 고정".to_string()),
-            ("D1".to_string(), "D1: 지중 매설 덕트".to_string()),
-            ("E".to_string(), "E: 케이블 트레이 (단심)".to_string()),
-        ],
-        "2C" | "3C" | "4C" => vec![
-            ("A2".to_string(), "A2: 단열벽 속 전선관 (다심)".to_string()),
-            ("B2".to_string(), "B2: 벽면 고정 전선관 (다심)".to_string()),
-            ("C".to_string(), "C: 벽면/천정 직접 고정".to_string()),
-            ("D1".to_string(), "D1: 지중 매설 덕트".to_string()),
-            ("D2".to_string(), "D2: 지중 매설 직매".to_string()),
-            ("F".to_string(), "F: 케이블 트레이 (다심)".to_string()),
-        ],
-        _ => vec![],
-    }
+/// 가닥수에 따른 공사방법 필터링 (lang: ko/en)
+#[tauri::command]
+fn get_install_methods_for_cores(cores: String, lang: String) -> Vec<(String, String)> {
+    kec_calculator::get_install_methods_for_cores(&cores, &lang)
+}
+
+/// 공사방법 코드 설명 (lang: ko/en, 알 수 없는 코드면 None)
+#[tauri::command]
+fn get_install_method_description(method: String, lang: String) -> Option<String> {
+    kec_calculator::describe_install_method(&method, &lang)
 }
 
 /// 전압 방식에 따른 적합한 심선 수 반환
 /// 단상 (1Φ): 1C (단심 독립), 2C (단상 2선), 3C (단상 3선)
 /// 3상 (3Φ): 3C (3상 3선), 4C (3상 4선)
 #[tauri::command]
-fn get_cores_for_system(system: String, available_cores: Vec<String>) -> Vec<(String, String)> {
+fn get_cores_for_system(system: String, available_cores: Vec<String>, lang: String) -> Vec<(String, String)> {
     let allowed_cores: Vec<&str> = match system.as_str() {
         "1Φ" => vec!["1C", "2C", "3C"],  // 단상: 단심, 2선, 3선
         "3Φ" => vec!["1C", "3C", "4C"],  // 3상: 단심, 3선, 4선
+        "DC" => vec!["1C", "2C"],        // 직류: 단심, 2선 (+, -)
         _ => vec!["1C", "2C", "3C", "4C"],
     };
 
-    let core_names = [
-        ("1C", "1C (단심)"),
-        ("2C", "2C (단상 2선)"),
-        ("3C", "3C (단상 3선 / 3상 3선)"),
-        ("4C", "4C (3상 4선)"),
-    ];
-
-    core_names
+    ["1C", "2C", "3C", "4C"]
         .iter()
-        .filter(|(code, _)| allowed_cores.contains(code) && available_cores.contains(&code.to_string()))
-        .map(|(code, name)| (code.to_string(), name.to_string()))
+        .filter(|code| allowed_cores.contains(code) && available_cores.contains(&code.to_string()))
+        .filter_map(|code| kec_calculator::core_system_label(code, &lang).map(|name| (code.to_string(), name.to_string())))
         .collect()
 }
 
 /// 전선 규격 목록 반환
 #[tauri::command]
 fn get_cable_sizes() -> Vec<String> {
-    vec![
-        "1.5", "2.5", "4", "6", "10", "16", "25", "35",
-        "50", "70", "95", "120", "150", "185", "240", "300", "400", "500",
-    ].into_iter().map(String::from).collect()
+    STANDARD_SIZES.iter().map(|s| s.to_string()).collect()
 }
 
-/// 가닥수 목록 반환
+/// 가닥수 목록 반환 (lang: ko/en)
 #[tauri::command]
-fn get_core_options() -> Vec<(String, String)> {
-    vec![
-        ("1C".to_string(), "1C (단심)".to_string()),
-        ("2C".to_string(), "2C (2심)".to_string()),
-        ("3C".to_string(), "3C (3심)".to_string()),
-        ("4C".to_string(), "4C (4심)".to_string()),
-    ]
+fn get_core_options(lang: String) -> Vec<(String, String)> {
+    ["1C", "2C", "3C", "4C"]
+        .iter()
+        .map(|code| (code.to_string(), kec_calculator::core_label(code, &lang)))
+        .collect()
 }
 
-/// 공사방법 목록 반환 (KEC 기준)
+/// 공사방법 목록 반환 (KEC 기준, lang: ko/en)
 #[tauri::command]
-fn get_install_methods() -> Vec<(String, String)> {
-    vec![
-        ("A1".to_string(), "A1: 단열벽 속 전선관 (단심)".to_string()),
-        ("A2".to_string(), "A2: 단열벽 속 전선관 (다심)".to_string()),
-        ("B1".to_string(), "B1: 벽면 고정 전선관 (단심)".to_string()),
-        ("B2".to_string(), "B2: 벽면 고정 전선관 (다심)".to_string()),
-        ("C".to_string(), "C: 벽면/천정 직접 고정".to_string()),
-        ("D1".to_string(), "D1: 지중 매설 덕트".to_string()),
-        ("D2".to_string(), "D2: 지중 매설 직매".to_string()),
-        ("E".to_string(), "E: 케이블 트레이 (단심)".to_string()),
-        ("F".to_string(), "F: 케이블 트레이 (다심)".to_string()),
-    ]
+fn get_install_methods(lang: String) -> Vec<(String, String)> {
+    kec_calculator::get_install_methods(&lang)
+}
+
+/// 로그 초기화: 기본은 warn 이상만 출력, 개발 시 RUST_LOG=trace로 계산 상세 추적
+fn init_tracing() {
+    let filter = tracing_subscriber::EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("warn"));
+    tracing_subscriber::fmt().with_env_filter(filter).init();
 }
 
 fn main() {
+    init_tracing();
     tauri::Builder::default()
+        .setup(|app| {
+            let dir = app.path().app_data_dir()?;
+            kec_calculator::set_audit_log_path(Some(dir.join(kec_calculator::AUDIT_LOG_FILE)));
+            history::set_history_db_path(Some(dir.join(history::HISTORY_DB_FILE)));
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             calculate,
+            calculate_pipeline,
+            suitability_score,
+            commands::sensitivity_analysis,
+            check_vertical_run,
+            export_results_markdown,
+            render_report_html,
+            insulation_current_ratio,
+            average_insulation_current_ratio,
+            calculate_cached,
+            load_custom_current_table,
+            reset_current_table,
+            get_data_checksum,
+            invalidate_cache,
+            commands::optimize_tray_layout,
+            recalculate_partial,
+            calculate_voltage_drop,
+            bulk_current_by_type,
+            suggest_sizes,
+            get_core_colors,
+            export_project_file,
+            import_project_file,
+            commands::size_vs_power_factor,
+            effective_occupied_area,
+            result_field_units,
+            check_fire_compliance,
+            check_feeder_capacity,
+            get_audit_log,
+            commands::distribute_cables_to_conduits,
+            max_short_circuit_current,
+            method_current_ratios,
+            commands::data_coverage_report,
+            get_voltage_drop_limit,
+            commands::calculate_batch_streaming,
+            get_minimum_size,
+            suggest_nearest_available,
+            preview_system_change,
+            recommend_pull_box,
+            load_ratio_table,
+            cross_validate_regulations,
+            explore_solutions,
+            design_circuit,
+            recommend_cable_size,
+            check_short_circuit_withstand,
+            commands::calculate_batch,
+            commands::calculate_batch_with_summary,
+            export_results_csv,
+            commands::calculate_candidates,
+            get_allowable_current_interpolated,
+            recommend_cable_size_from_power,
+            recommend_breaker,
+            save_calculation,
+            list_calculations,
+            delete_calculation,
+            compare_install_methods,
+            lookup_outer_diameter,
+            get_table_reference,
+            get_base_ambient_temp,
+            max_cables_in_conduit,
+            calculate_power_loss,
+            min_bending_radius,
+            calculate_pulling_tension,
             get_cable_types,
             get_cable_options,
             get_cores_for_system,
             get_install_methods_for_cores,
+            get_install_method_description,
             get_cable_sizes,
             get_core_options,
             get_install_methods
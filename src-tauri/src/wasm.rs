@@ -0,0 +1,50 @@
+//! WASM 바인딩 (`wasm32` 타깃 전용)
+//!
+//! 웹 버전에서 Tauri 없이 계산 엔진을 직접 호출하기 위한 JS 노출 함수.
+//! 입출력은 `serde-wasm-bindgen`으로 변환하며, 에러는 지역화된 메시지 문자열로 반환
+
+use crate::{CableData, VoltageDropInput};
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+/// 결과 직렬화 (HashMap은 JS Map이 아닌 일반 객체로 변환)
+fn to_js<T: Serialize>(value: &T) -> Result<JsValue, JsValue> {
+    value
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .map_err(JsValue::from)
+}
+
+/// 메인 계산 (`calculate_core`)
+#[wasm_bindgen]
+pub fn calculate(data: JsValue) -> Result<JsValue, JsValue> {
+    let data: CableData = serde_wasm_bindgen::from_value(data)?;
+    let result = crate::calculate_core(&data).map_err(|e| JsValue::from_str(&e))?;
+    to_js(&result)
+}
+
+/// 전압강하 계산
+#[wasm_bindgen(js_name = calculateVoltageDrop)]
+pub fn calculate_voltage_drop(data: JsValue, input: JsValue) -> Result<JsValue, JsValue> {
+    let data: CableData = serde_wasm_bindgen::from_value(data)?;
+    let input: VoltageDropInput = serde_wasm_bindgen::from_value(input)?;
+    let result = crate::calculate_voltage_drop(&data, &input).map_err(|e| JsValue::from_str(&e))?;
+    to_js(&result)
+}
+
+/// 전선 종류 목록
+#[wasm_bindgen(js_name = getCableTypes)]
+pub fn get_cable_types() -> Result<JsValue, JsValue> {
+    to_js(&crate::get_cable_types())
+}
+
+/// 규격별 부하율 표 ([규격, 허용전류, 부하율 %] 배열)
+#[wasm_bindgen(js_name = loadRatioTable)]
+pub fn load_ratio_table(cable_type: &str, method: &str, system: &str, current: f64) -> Result<JsValue, JsValue> {
+    to_js(&crate::load_ratio_table(cable_type, method, system, current))
+}
+
+/// 활성 데이터 체크섬
+#[wasm_bindgen(js_name = getDataChecksum)]
+pub fn get_data_checksum() -> String {
+    crate::get_data_checksum()
+}
@@ -0,0 +1,92 @@
+//! 비동기 Tauri 커맨드(kec_calculator::commands)의 결과·에러·직렬화 검증
+//!
+//! 실제 커맨드를 Tauri 비동기 런타임에서 실행해 동기 계산 함수와 결과가 같고
+//! JSON 왕복 후에도 유지되는지 확인 (GUI 기능 빌드에서만 실행)
+#![cfg(feature = "gui")]
+
+use kec_calculator::commands;
+use kec_calculator::{
+    calculate_core, data_coverage_report, distribute_cables_to_conduits, optimize_tray_layout, sensitivity_analysis,
+    CableData, CalculationResult, TrayLayoutPlan,
+};
+use tauri::async_runtime::block_on;
+
+fn cable(cores: &str, system: &str, quantity: u32) -> CableData {
+    CableData {
+        cable_type: "CV".to_string(),
+        cores: cores.to_string(),
+        size: "16".to_string(),
+        quantity,
+        system: system.to_string(),
+        ground_wire: "없음".to_string(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn command_results_match_sync_results() {
+    let data = cable("3C", "3Φ", 2);
+    let sync = sensitivity_analysis(&data, &[30, 40], &[1, 2]).unwrap();
+    let command = block_on(commands::sensitivity_analysis(data, vec![30, 40], vec![1, 2])).unwrap();
+    assert_eq!(sync, command);
+
+    let cables = vec![cable("3C", "3Φ", 1), cable("1C", "3Φ", 3)];
+    let sync = serde_json::to_string(&optimize_tray_layout(&cables, &[300.0, 600.0]).unwrap()).unwrap();
+    let command = block_on(commands::optimize_tray_layout(cables, vec![300.0, 600.0])).unwrap();
+    let round_trip: TrayLayoutPlan = serde_json::from_str(&serde_json::to_string(&command).unwrap()).unwrap();
+    assert_eq!(serde_json::to_string(&round_trip).unwrap(), sync);
+
+    let report = block_on(commands::data_coverage_report()).unwrap();
+    assert_eq!(report.total, data_coverage_report().total);
+}
+
+#[test]
+fn command_results_carry_expected_values() {
+    // CV 3C 16mm² 2조 B2: 82A × 0.80(2회로) = 65.6A
+    let data = cable("3C", "3Φ", 2);
+    let results = block_on(commands::calculate_batch(vec![data.clone(), cable("2C", "3Φ", 1)])).unwrap();
+    assert_eq!(results.len(), 2);
+    let result: CalculationResult =
+        serde_json::from_str(&serde_json::to_string(results[0].as_ref().unwrap()).unwrap()).unwrap();
+    assert_eq!(result.allowable_current, 65.6);
+    assert_eq!(result.recommended_raceway.name, "C70 (70mm)");
+    // 2본 점유율 상한 31% 이내 최소 전선관
+    assert_eq!(result.fill_rate, 22.2);
+    // 실패 항목도 입력 순서 그대로 에러로 남음
+    assert!(results[1].is_err());
+
+    let summary = block_on(commands::calculate_batch_with_summary(vec![data.clone()])).unwrap();
+    assert_eq!(serde_json::to_value(&summary).unwrap()["results"][0]["Ok"]["allowable_current"], 65.6);
+
+    let candidates = block_on(commands::calculate_candidates(data)).unwrap();
+    assert!(candidates.iter().any(|c| c.size_label.starts_with("16 mm²")));
+
+    // 30kW 3Φ 380V: 역률이 낮을수록 필요 전류·규격 증가
+    let by_pf = block_on(commands::size_vs_power_factor(
+        30.0,
+        vec![0.9, 0.7],
+        "3Φ".to_string(),
+        380.0,
+        "CV".to_string(),
+        "B2".to_string(),
+        None,
+    ))
+    .unwrap();
+    assert_eq!(by_pf.len(), 2);
+    assert!(by_pf[1].1 > by_pf[0].1);
+}
+
+#[test]
+fn command_errors_propagate_unchanged() {
+    let invalid = cable("2C", "3Φ", 1);
+    let expected = sensitivity_analysis(&invalid, &[30], &[1]).unwrap_err();
+    assert_eq!(block_on(commands::sensitivity_analysis(invalid, vec![30], vec![1])).unwrap_err(), expected);
+    assert!(calculate_core(&cable("2C", "3Φ", 1)).is_err());
+
+    let cables = vec![cable("3C", "3Φ", 1)];
+    let expected = distribute_cables_to_conduits(&cables, 1.5, false).unwrap_err();
+    let err = block_on(commands::distribute_cables_to_conduits(cables, 1.5, None)).unwrap_err();
+    assert_eq!(err, expected);
+    // 에러 메시지는 JSON 문자열로 그대로 직렬화됨
+    assert_eq!(serde_json::from_str::<String>(&serde_json::to_string(&err).unwrap()).unwrap(), err);
+}
@@ -0,0 +1,40 @@
+//! WASM 바인딩 결과가 네이티브 계산과 동일한지 검증
+//!
+//! `wasm-pack test --node -- --no-default-features --test wasm`으로 실행 (네이티브 빌드에서는 비어 있음)
+#![cfg(target_arch = "wasm32")]
+
+use kec_calculator::{calculate_core, CableData, CalculationResult};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn sample() -> CableData {
+    CableData {
+        cable_type: "CV".to_string(),
+        cores: "3C".to_string(),
+        size: "16".to_string(),
+        quantity: 2,
+        system: "3Φ".to_string(),
+        ground_wire: "없음".to_string(),
+        install_method: "B2".to_string(),
+        load_current: Some(50.0),
+        length: 80.0,
+        ..Default::default()
+    }
+}
+
+#[wasm_bindgen_test]
+fn calculate_matches_native() {
+    let data = sample();
+    let native = calculate_core(&data).unwrap();
+    let js = kec_calculator::wasm::calculate(serde_wasm_bindgen::to_value(&data).unwrap()).unwrap();
+    let mut wasm: CalculationResult = serde_wasm_bindgen::from_value(js).unwrap();
+    wasm.data_provenance.calculated_at = native.data_provenance.calculated_at.clone();
+    assert_eq!(serde_json::to_value(&wasm).unwrap(), serde_json::to_value(&native).unwrap());
+}
+
+#[wasm_bindgen_test]
+fn calculate_error_is_localized_message() {
+    let data = CableData { size: "999".to_string(), ..sample() };
+    let native = calculate_core(&data).unwrap_err();
+    let js = kec_calculator::wasm::calculate(serde_wasm_bindgen::to_value(&data).unwrap()).unwrap_err();
+    assert_eq!(js.as_string().unwrap(), native);
+}
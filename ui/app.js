@@ -27,7 +27,7 @@ let currentCableOptions = null;
 async function init() {
     try {
         // 전선 종류 목록 로드
-        const cableTypes = await invoke('get_cable_types');
+        const cableTypes = await invoke('get_cable_types', { lang: 'ko' });
         cableTypes.forEach(type => {
             const option = document.createElement('option');
             option.value = type.code;
@@ -102,17 +102,10 @@ async function onCableTypeChange() {
 
     try {
         // Rust 백엔드에서 해당 전선 종류의 옵션 가져오기
-        currentCableOptions = await invoke('get_cable_options', { cableType: selectedType });
+        currentCableOptions = await invoke('get_cable_options', { cableType: selectedType, lang: 'ko' });
 
         // 규격 옵션 설정 (전선 종류에 따라)
-        resetSelect(elements.size, '선택하세요');
-        currentCableOptions.sizes.forEach(size => {
-            const option = document.createElement('option');
-            option.value = size;
-            option.textContent = `${size} mm²`;
-            elements.size.appendChild(option);
-        });
-        elements.size.disabled = false;
+        populateSizes(currentCableOptions.sizes);
 
         // 전압 방식에 따른 가닥수 필터링
         await onSystemChange();
@@ -132,7 +125,8 @@ async function onSystemChange() {
         // Rust 백엔드에서 전압 방식에 맞는 가닥수 가져오기
         const cores = await invoke('get_cores_for_system', {
             system: system,
-            availableCores: availableCores
+            availableCores: availableCores,
+            lang: 'ko'
         });
 
         // 가닥수 옵션 갱신
@@ -165,6 +159,14 @@ async function onSystemChange() {
 async function onCoresChange() {
     const cores = elements.cores.value;
 
+    // 단심 전용 규격(630mm² 이상)은 1C에서만 표시
+    if (currentCableOptions) {
+        const sizes = cores === '1C'
+            ? currentCableOptions.sizes.concat(currentCableOptions.single_core_sizes)
+            : currentCableOptions.sizes;
+        populateSizes(sizes);
+    }
+
     if (!cores) {
         resetSelect(elements.installMethod, '가닥수를 먼저 선택하세요');
         elements.installMethod.disabled = true;
@@ -173,7 +175,7 @@ async function onCoresChange() {
 
     try {
         // Rust 백엔드에서 가닥수에 맞는 공사방법 가져오기
-        const methods = await invoke('get_install_methods_for_cores', { cores: cores });
+        const methods = await invoke('get_install_methods_for_cores', { cores: cores, lang: 'ko' });
 
         // 공사방법 옵션 갱신
         resetSelect(elements.installMethod, '선택하세요');
@@ -198,6 +200,22 @@ async function onCoresChange() {
     calculate();
 }
 
+// 규격 셀렉트 채우기 (이전 선택이 목록에 있으면 유지)
+function populateSizes(sizes) {
+    const previous = elements.size.value;
+    resetSelect(elements.size, '선택하세요');
+    sizes.forEach(size => {
+        const option = document.createElement('option');
+        option.value = size;
+        option.textContent = `${size} mm²`;
+        elements.size.appendChild(option);
+    });
+    if (sizes.includes(previous)) {
+        elements.size.value = previous;
+    }
+    elements.size.disabled = false;
+}
+
 // 셀렉트 초기화 헬퍼
 function resetSelect(selectEl, placeholder) {
     selectEl.innerHTML = `<option value="">${placeholder}</option>`;
@@ -273,7 +291,13 @@ function updateResults(result) {
     elements.installMethodDesc.textContent = result.install_method_desc;
 
     // 추천 전선관
-    elements.conduitSize.textContent = result.recommended_conduit;
+    const conduit = result.recommended_raceway;
+    elements.conduitSize.textContent = conduit.status === '정상'
+        ? conduit.name
+        : `${conduit.name} (${conduit.status})`;
+    // 면적은 통과하나 원형 배열이 불가해 재추천된 경우 경고
+    elements.conduitSize.title = conduit.geometry_note || '';
+    elements.conduitSize.classList.toggle('warning', Boolean(conduit.geometry_note));
     elements.fillRate.textContent = formatNumber(result.fill_rate, 1);
 
     // 점유율 바 애니메이션
@@ -296,7 +320,9 @@ function updateResults(result) {
 // 에러 표시
 function showError(error) {
     elements.allowableCurrent.textContent = '-';
-    elements.installMethodDesc.textContent = typeof error === 'string' ? error : '계산 오류';
+    // calculate는 {code, message} 형태의 구조화 에러 반환
+    const message = typeof error === 'string' ? error : error?.message;
+    elements.installMethodDesc.textContent = message || '계산 오류';
 }
 
 // 결과 초기화